@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use actix_web::cookie::Cookie;
+use actix_web::{test, web, App};
+use sorrow_i18n::actix::{I18n, Locale, Localized};
+use sorrow_i18n::InternationalCore;
+
+async fn welcome(msgs: Localized) -> String {
+    msgs.t("data.name")
+}
+
+async fn locale(Locale(locale): Locale) -> String {
+    locale
+}
+
+// The middleware resolves the locale of the request, handlers receive the data.
+#[actix_web::main]
+async fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = Arc::new(InternationalCore::new(manifest));
+    let app = test::init_service(
+        App::new()
+            .wrap(I18n::new(core).query("lang").cookie("lang"))
+            .route("/", web::get().to(welcome))
+            .route("/locale", web::get().to(locale)),
+    ).await;
+
+    // Accept-Language
+    let request = test::TestRequest::get().uri("/").insert_header(("Accept-Language", "ru-RU, en;q=0.8")).to_request();
+    assert_eq!("Тест", test::call_and_read_body(&app, request).await);
+
+    // The query parameter wins, the unknown locale is skipped.
+    let request = test::TestRequest::get().uri("/?lang=en").insert_header(("Accept-Language", "ru")).to_request();
+    assert_eq!("Test", test::call_and_read_body(&app, request).await);
+    let request = test::TestRequest::get().uri("/locale?lang=de").cookie(Cookie::new("lang", "ru")).to_request();
+    assert_eq!("ru", test::call_and_read_body(&app, request).await);
+}
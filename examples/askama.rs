@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use askama::Template;
+use sorrow_i18n::InternationalCore;
+use sorrow_i18n::askama::filters;
+
+// Translated by the installed core.
+#[derive(Template)]
+#[template(source = "<h1>{{ \"data.name\"|t(locale) }}</h1>", ext = "html")]
+struct Hello {
+    locale: String,
+}
+
+// Translated by the core of the template.
+#[derive(Template)]
+#[template(source = "<h1>{{ \"data.name\"|t_in(core, locale) }}</h1>", ext = "html")]
+struct Injected<'a> {
+    core: Arc<InternationalCore>,
+    locale: &'a str,
+}
+
+fn main() {
+    // Without the core, the key is returned.
+    assert_eq!("<h1>data.name</h1>", Hello { locale: "en".to_string() }.render().unwrap());
+
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = Arc::new(InternationalCore::new(manifest));
+    sorrow_i18n::askama::install(Arc::clone(&core));
+    assert_eq!("<h1>Test</h1>", Hello { locale: "en".to_string() }.render().unwrap());
+    assert_eq!("<h1>Тест</h1>", Hello { locale: "ru".to_string() }.render().unwrap());
+
+    assert_eq!("<h1>Тест</h1>", Injected { core, locale: "ru" }.render().unwrap());
+}
@@ -0,0 +1,38 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use sorrow_i18n::{GetData, InternationalCore};
+
+// Editors with the "safe write" (vim, VSCode) replace the file by rename, the changes are reloaded too.
+fn main() {
+    // Prepare folder
+    let folder = std::env::temp_dir().join("sorrow_i18n_atomic_save");
+    std::fs::create_dir_all(&folder).unwrap();
+    let path = folder.join("I18N_EN.yaml");
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Test\"\n").unwrap();
+
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+
+    // Write the temporary file and rename it to the target.
+    let temp = folder.join("I18N_EN.yaml.tmp");
+    std::fs::write(&temp, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Saved\"\n").unwrap();
+    std::fs::rename(&temp, &path).unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Saved", en.get_or_default("data.name"));
+
+    // Once more, the new file is watched as well.
+    std::fs::write(&temp, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Saved again\"\n").unwrap();
+    std::fs::rename(&temp, &path).unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Saved again", en.get_or_default("data.name"));
+
+    // Other files of the folder are not reloaded into the locale.
+    std::fs::write(folder.join("notes.txt"), "name: Other").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Saved again", en.get_or_default("data.name"));
+
+    drop(core);
+    std::fs::remove_dir_all(&folder).unwrap();
+}
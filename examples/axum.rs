@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use axum::body::{to_bytes, Body};
+use axum::http::Request;
+use axum::routing::get;
+use axum::Router;
+use sorrow_i18n::axum::{Locale, Localized};
+use sorrow_i18n::{GetData, InternationalCore};
+use tower::ServiceExt;
+
+async fn welcome(msgs: Localized) -> String {
+    msgs.get_or_default("data.name")
+}
+
+async fn locale(Locale(locale): Locale) -> String {
+    locale
+}
+
+async fn call(app: &Router, uri: &str, accept_language: &str) -> String {
+    let request = Request::get(uri).header("Accept-Language", accept_language).body(Body::empty()).unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    String::from_utf8(to_bytes(response.into_body(), 1024).await.unwrap().to_vec()).unwrap()
+}
+
+// Handlers receive the data by the locale of the request.
+#[tokio::main]
+async fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = Arc::new(InternationalCore::new(manifest));
+    let app = Router::new()
+        .route("/", get(welcome))
+        .route("/locale", get(locale))
+        .with_state(core);
+
+    assert_eq!("Тест", call(&app, "/", "ru-RU, en;q=0.8").await);
+    assert_eq!("Test", call(&app, "/", "de, en-GB;q=0.7, ru;q=0.5").await);
+    // The region is kept, the data falls back to the language.
+    assert_eq!("en-GB", call(&app, "/locale", "de, en-GB;q=0.7, ru;q=0.5").await);
+}
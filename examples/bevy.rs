@@ -0,0 +1,40 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use sorrow_i18n::bevy::{I18n, I18nPlugin, LocalizedText};
+use sorrow_i18n::InternationalCore;
+
+// UI texts follow the current locale and the reloaded catalogs.
+fn main() {
+    let folder = std::env::temp_dir().join("sorrow_i18n_bevy");
+    std::fs::create_dir_all(&folder).unwrap();
+    let en = folder.join("I18N_EN.yaml");
+    std::fs::write(&en, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  start: \"Start\"\n").unwrap();
+    std::fs::write(folder.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\ndata:\n  start: \"Старт\"\n").unwrap();
+
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    let mut app = App::new();
+    app.add_plugins(I18nPlugin::new(core, "en"));
+    let button = app.world_mut().spawn((Text::default(), LocalizedText::new("data.start"))).id();
+
+    app.update();
+    assert_eq!("Start", app.world().get::<Text>(button).unwrap().0);
+
+    // Switch the locale
+    app.world_mut().resource_mut::<I18n>().locale = "ru".to_string();
+    app.update();
+    assert_eq!("Старт", app.world().get::<Text>(button).unwrap().0);
+
+    // Hot reload
+    app.world_mut().resource_mut::<I18n>().locale = "en".to_string();
+    app.update();
+    assert_eq!("Start", app.world().get::<Text>(button).unwrap().0);
+    std::fs::write(&en, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  start: \"Play\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    app.update();
+    assert_eq!("Play", app.world().get::<Text>(button).unwrap().0);
+
+    drop(app);
+    std::fs::remove_dir_all(&folder).unwrap();
+}
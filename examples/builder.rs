@@ -0,0 +1,20 @@
+use sorrow_i18n::{GetData, Holder, InternationalCore};
+
+// Catalogs assembled in code, e.g. in tests.
+fn main() {
+    let core = InternationalCore::builder()
+        .insert("EN", "data.name", "Test")
+        .insert("EN", "data.greeting", "Hello, {name}!")
+        .locale("RU", [("data.name", "Тест")])
+        .build();
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!("Hello, Bob!", en.get_with_args("data.greeting", &[("name", &"Bob")]));
+    assert_eq!("Тест", core.get_by_locale("ru").unwrap().get_or_default("data.name"));
+
+    // From holders
+    let mut core = InternationalCore::from_holders(vec![Holder::from_pairs("EN", [("data.name", "Test")])]).unwrap();
+    core.add_locale("DE", Holder::from_pairs("DE", [("data.name", "Prüfung")])).unwrap();
+    assert_eq!("Prüfung", core.get_by_locale("DE").unwrap().get_or_default("data.name"));
+    assert!(InternationalCore::from_holders(vec![Holder::from_pairs("EN", [("a", "b")]), Holder::from_pairs("en", [("a", "c")])]).is_err());
+}
@@ -0,0 +1,34 @@
+use sorrow_i18n::{GetData, InternationalCore};
+
+// Keys from the sources with inconsistent casing.
+fn main() {
+    let folder = std::env::temp_dir().join("sorrow_i18n_case_insensitive");
+    std::fs::create_dir_all(&folder).unwrap();
+    let en_path = folder.join("I18N_EN.yaml");
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\ndata:\n  Name: \"Test\"\n  Menu:\n    Open: \"Open\"\n").unwrap();
+
+    let mut core = InternationalCore::new(folder.to_str().unwrap());
+    assert_eq!(None, core.get_by_locale("EN").unwrap().get("data.name"));
+
+    core.case_insensitive_keys(true);
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!("Test", en.get_or_default("DATA.NAME"));
+    assert_eq!(vec!["data.menu.open"], en.keys_with_prefix("Data.Menu."));
+    let state = core.get_by_locale_state("EN").unwrap();
+    assert_eq!("Open", state.get_or_default("data.Menu.OPEN"));
+
+    // The reloaded keys are lowercased too.
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\ndata:\n  NAME: \"Reloaded\"\n").unwrap();
+    core.reload().unwrap();
+    assert_eq!("Reloaded", en.get_or_default("Data.Name"));
+    assert_eq!(vec!["data.name"], en.keys());
+
+    // Case-sensitive again, the original keys of the reloaded file are restored.
+    core.case_insensitive_keys(false);
+    assert_eq!("Reloaded", en.get_or_default("data.NAME"));
+    assert_eq!(None, en.get("data.name"));
+    assert_eq!(vec!["data.NAME"], en.keys());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,26 @@
+use sorrow_i18n::codegen::{generate_keys, keys_source};
+
+// Usually called from build.rs, see the module documentation.
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let source = keys_source(&manifest).unwrap();
+    println!("{}", source);
+    assert!(source.contains("pub const DATA_NAME: &str = \"data.name\";"));
+    assert!(source.contains("pub const DATA_PLACE_OTHER: &str = \"data.place.other\";"));
+
+    // Namespaced keys are generated into the module.
+    let folder = std::env::temp_dir().join("sorrow_i18n_codegen");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  title: \"Shop\"\n").unwrap();
+    std::fs::write(folder.join("CHECKOUT_EN.yaml"), "kind: I18N\nlocale: EN\nnamespace: checkout\ndata:\n  title: \"Checkout\"\n").unwrap();
+    let out = folder.join("i18n_keys.rs");
+    generate_keys(folder.to_str().unwrap(), &out).unwrap();
+    let source = std::fs::read_to_string(&out).unwrap();
+    assert!(source.contains("pub mod checkout {\n    /// `data.title`\n    pub const DATA_TITLE: &str = \"data.title\";\n}"));
+
+    // Keys with the same constant name
+    std::fs::write(folder.join("I18N_DE.yaml"), "kind: I18N\nlocale: DE\ndata:\n  title-x: \"A\"\n  title_x: \"B\"\n").unwrap();
+    assert!(keys_source(folder.to_str().unwrap()).is_err());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
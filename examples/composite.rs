@@ -0,0 +1,84 @@
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+
+use sorrow_i18n::composite::CompositeProvider;
+use sorrow_i18n::{ChangeListener, Error, GetData, InternationalCore, Messages, WatchProvider};
+
+// Layer with fixed data.
+struct EmbeddedProvider {
+    data: Arc<Messages>,
+}
+
+impl WatchProvider for EmbeddedProvider {
+    fn watch(&mut self) -> Result<(), Error> {
+        self.data.update(|data| {
+            data.insert("data.name".to_string(), "Embedded test".into());
+            data.insert("data.embedded".to_string(), "Embedded".into());
+        });
+        Ok(())
+    }
+
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
+        self.data = data;
+        Ok(())
+    }
+}
+
+// Layer refreshed by remote updates.
+struct RemoteProvider {
+    data: Arc<Messages>,
+    updates: Arc<Mutex<Receiver<(String, String)>>>,
+    listener: Option<ChangeListener>,
+}
+
+impl WatchProvider for RemoteProvider {
+    fn watch(&mut self) -> Result<(), Error> {
+        let data = Arc::clone(&self.data);
+        let updates = Arc::clone(&self.updates);
+        let listener = self.listener.clone();
+        std::thread::spawn(move || {
+            while let Ok((key, value)) = updates.lock().unwrap().recv() {
+                data.insert(key, value);
+                if let Some(listener) = &listener {
+                    listener();
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
+        self.data = data;
+        Ok(())
+    }
+
+    fn set_listener(&mut self, listener: ChangeListener) {
+        self.listener = Some(listener);
+    }
+}
+
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let mut core = InternationalCore::new(manifest);
+
+    let (sender, updates) = channel();
+    let remote = RemoteProvider { data: Default::default(), updates: Arc::new(Mutex::new(updates)), listener: None };
+    let provider = CompositeProvider::new()
+        .layer(Box::new(EmbeddedProvider { data: Default::default() }))
+        .layer(Box::new(remote));
+    core.add_provider("EN", Box::new(provider)).unwrap();
+
+    let en = core.get_by_locale("EN").unwrap();
+    // Embedded overrides file data, file data is kept.
+    assert_eq!("Embedded test", en.get_or_default("data.name"));
+    assert_eq!("Embedded", en.get_or_default("data.embedded"));
+    assert_eq!("Hello, {name}!", en.get_or_default("data.greeting"));
+
+    // Remote overrides embedded after refresh.
+    sender.send(("data.name".to_string(), "Remote test".to_string())).unwrap();
+    sleep(Duration::from_millis(100));
+    assert_eq!("Remote test", en.get_or_default("data.name"));
+    assert_eq!("Embedded", en.get_or_default("data.embedded"));
+}
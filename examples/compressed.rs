@@ -0,0 +1,16 @@
+use include_dir::{include_dir, Dir};
+use sorrow_i18n::{GetData, InternationalCore};
+
+// Files compressed by gzip (I18N_EN.yaml.gz) and zstd (I18N_RU.yaml.zst) are decompressed at init.
+const PROJECT_DIR: Dir = include_dir!("resources/compressed");
+
+fn main() {
+    // Init core
+    let core = InternationalCore::try_from_dir(PROJECT_DIR).unwrap();
+
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+
+    let ru = core.get_by_locale("RU").unwrap();
+    assert_eq!("Тест", ru.get_or_default("data.name"));
+}
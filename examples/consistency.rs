@@ -0,0 +1,34 @@
+use sorrow_i18n::InternationalCore;
+
+// Finding the keys which are not translated to all locales.
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = InternationalCore::new(manifest);
+    let report = core.check_consistency();
+    assert!(!report.is_consistent());
+    assert!(report.missing_in("EN").is_empty());
+    assert!(report.missing_in("RU").contains(&"data.greeting"));
+    assert_eq!(report.missing_in("EE"), report.missing_in("RU"));
+    // Keys of EN only
+    assert_eq!(report.unique["en"], report.missing["ru"]);
+    for (locale, keys) in report.missing.iter() {
+        println!("{} is missing {} keys: {:?}", locale, keys.len(), keys);
+    }
+
+    // Translation progress against EN
+    let coverage = core.coverage("EN").unwrap();
+    for (locale, c) in coverage.locales.iter() {
+        println!("{}: {:.0}% of {} keys, missing: {:?}", locale, c.percent(), c.total, c.missing);
+    }
+    assert_eq!(report.missing["ru"], coverage.locales["ru"].missing);
+    assert!(coverage.percent("RU").unwrap() < 100.0);
+    assert!(coverage.percent("EN").is_none());
+    assert!(core.coverage("DE").is_err());
+
+    let core = InternationalCore::builder()
+        .insert("EN", "data.name", "Test")
+        .insert("RU", "data.name", "Тест")
+        .build();
+    assert!(core.check_consistency().is_consistent());
+    assert!(core.namespace("checkout").check_consistency().is_consistent());
+}
@@ -0,0 +1,26 @@
+use sorrow_i18n::{context_key, GetData, Holder, InternationalCore};
+
+// The same word is translated differently by the context, analogue of gettext `msgctxt`.
+fn main() {
+    let data = r#"
+kind: I18N
+locale: DE
+data:
+  open: "Öffnen"
+  open@menu: "Öffnen…"
+  open@door: "Aufschließen"
+"#;
+    let holder = Holder::parse(data).unwrap();
+    let mut core = InternationalCore::from_holders(vec![holder]).unwrap();
+    core.on_missing(|_, key| Some(format!("<{}>", key)));
+    let de = core.get_by_locale("DE").unwrap();
+
+    assert_eq!(Some("Öffnen…".to_string()), de.get_ctx("data.open", "menu"));
+    assert_eq!("Aufschließen", de.get_ctx_or_default("data.open", "door"));
+    // The message in the context is stored by the key `key@context`.
+    assert_eq!(Some("Öffnen…".to_string()), de.get(context_key("data.open", "menu")));
+    // Without the message in the context, the message of the key is used.
+    assert_eq!(Some("Öffnen".to_string()), de.get_ctx("data.open", "file"));
+    assert_eq!(None, de.get_ctx("data.close", "menu"));
+    assert_eq!("<data.close>", de.get_ctx_or_default("data.close", "menu"));
+}
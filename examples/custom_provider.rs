@@ -1,6 +1,5 @@
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
-use sorrow_i18n::{Error, GetData, InternationalCore, WatchProvider};
+use std::sync::Arc;
+use sorrow_i18n::{Error, GetData, InternationalCore, Messages, WatchProvider};
 
 fn main() {
     // Init core
@@ -13,7 +12,7 @@ fn main() {
     // We get data by EE locale
     // This method returns a mutable reference to the value (internally).
     let ee_opt = core.get_by_locale("EE");
-    assert_eq!(true, ee_opt.is_some());
+    assert!(ee_opt.is_some());
     let ee = ee_opt.unwrap();
 
     // Key exists in file
@@ -26,35 +25,39 @@ fn main() {
 }
 
 pub struct CustomProvider {
-    data: Arc<RwLock<HashMap<String, String>>>,
+    data: Arc<Messages>,
 }
 
 impl CustomProvider {
     pub fn new() -> Self {
         CustomProvider {
-            data: Arc::new(RwLock::new(HashMap::new()))
+            data: Arc::new(Messages::default())
         }
     }
 }
 
+impl Default for CustomProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WatchProvider for CustomProvider {
     fn watch(&mut self) -> Result<(), sorrow_i18n::Error> {
         println!("Accepted custom provider");
-        let data = self.data.write();
-        let mut un = data.unwrap();
         println!("Current I18N_EE.yml data holder...");
-        un.iter().for_each(|kv| {
+        self.data.load().iter().for_each(|kv| {
             println!("Key: {}, Value: {}", kv.0, kv.1);
         });
         println!("Add Hello key and value World");
-        un.insert("Hello".to_string(), "World".to_string());
-        un.iter().for_each(|kv| {
+        self.data.insert("Hello", "World");
+        self.data.load().iter().for_each(|kv| {
             println!("Key: {}, Value: {}", kv.0, kv.1);
         });
         Ok(())
     }
 
-    fn set_data(&mut self, data: Arc<RwLock<HashMap<String, String>>>) -> Result<(), Error> {
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
         self.data = data;
         println!("Data has been set");
         Ok(())
@@ -0,0 +1,36 @@
+use sorrow_i18n::{InternationalCore, Localize};
+
+#[derive(Localize)]
+#[localize(prefix = "data.errors")]
+enum ErrorKind {
+    NotFound,
+    #[localize(key = "data.errors.denied")]
+    PermissionDenied,
+    Timeout(#[allow(dead_code)] u64),
+}
+
+#[derive(Localize)]
+enum OrderStatus {
+    InDelivery,
+}
+
+fn main() {
+    // Prepare folder
+    let folder = std::env::temp_dir().join("sorrow_i18n_derive");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  errors:\n    not_found: \"Not found\"\n    denied: \"Permission denied\"\n  order_status:\n    in_delivery: \"In delivery\"\n").unwrap();
+    std::fs::write(folder.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\ndata:\n  errors:\n    not_found: \"Не найдено\"\n").unwrap();
+
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    assert_eq!("data.errors.not_found", ErrorKind::NotFound.key());
+    assert_eq!("Not found", ErrorKind::NotFound.display(&core, "EN"));
+    assert_eq!("Не найдено", ErrorKind::NotFound.display(&core, "RU"));
+    assert_eq!("Permission denied", ErrorKind::PermissionDenied.display(&core, "EN"));
+    // Key is not found
+    assert_eq!("data.errors.timeout", ErrorKind::Timeout(30).display(&core, "EN"));
+    // Default prefix
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("In delivery", OrderStatus::InDelivery.display_by(&en));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
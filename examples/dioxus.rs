@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+use sorrow_i18n::dioxus::{use_i18n, use_i18n_provider, I18n};
+use sorrow_i18n::InternationalCore;
+
+#[allow(non_snake_case)]
+fn Greeting() -> Element {
+    let i18n = use_i18n();
+    rsx! {
+        button { onclick: move |_| i18n.set_locale("ru"), {i18n.t("data.name")} }
+    }
+}
+
+#[allow(non_snake_case)]
+fn App() -> Element {
+    use_i18n_provider(|| {
+        let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+        Arc::new(InternationalCore::new(manifest))
+    }, "en");
+    rsx! { Greeting {} }
+}
+
+// Components are re-rendered when the locale is switched.
+fn main() {
+    let mut vdom = VirtualDom::new(App);
+    vdom.rebuild_in_place();
+    assert_eq!("<button>Test</button>", dioxus_ssr::render(&vdom));
+
+    let i18n = vdom.in_runtime(|| ScopeId::APP.consume_context::<I18n>()).unwrap();
+    assert_eq!("Test", vdom.in_runtime(|| i18n.t("data.name")));
+    vdom.in_runtime(|| i18n.set_locale("ru"));
+    vdom.process_events();
+    vdom.render_immediate(&mut NoOpMutations);
+    assert_eq!("<button>Тест</button>", dioxus_ssr::render(&vdom));
+}
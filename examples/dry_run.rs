@@ -0,0 +1,34 @@
+use sorrow_i18n::{Error, Holder, InternationalCore};
+
+// Pre-deploy check of the locale files, without the holders and the watchers.
+fn main() {
+    // The folder of the project is valid.
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    assert!(InternationalCore::check(&manifest).is_empty());
+    Holder::check(format!("{}/I18N_EN.yaml", &manifest)).unwrap();
+
+    // Prepare folder with the problems
+    let folder = std::env::temp_dir().join("sorrow_i18n_dry_run");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  name: \"Test\"\n").unwrap();
+    std::fs::write(folder.join("I18N_EN_COPY.yaml"), "kind: I18N\nlocale: EN\ndata:\n  name: \"Copy\"\n").unwrap();
+    std::fs::write(folder.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\ndata: [\n").unwrap();
+    std::fs::write(folder.join("I18N_DE.yaml"), "kind: LOCALE\nlocale: DE\ndata:\n  name: \"Test\"\n").unwrap();
+    std::fs::write(folder.join("notes.txt"), "Skipped").unwrap();
+
+    // All problems at once, in order of the file paths.
+    let errors = InternationalCore::check(folder.to_str().unwrap());
+    assert_eq!(3, errors.len());
+    assert!(matches!(&errors[0], Error::InvalidHeader { path } if path.ends_with("I18N_DE.yaml")));
+    assert!(matches!(&errors[1], Error::DuplicateLocale { locale } if locale == "en"));
+    assert!(matches!(&errors[2], Error::InvalidStructure { path, .. } if path.ends_with("I18N_RU.yaml")));
+
+    // The single file
+    assert!(matches!(Holder::check(folder.join("I18N_RU.yaml").to_str().unwrap()), Err(Error::InvalidStructure { .. })));
+    assert!(matches!(Holder::check(folder.join("notes.txt").to_str().unwrap()), Err(Error::NotSupportedFileExtension { .. })));
+
+    // Missing folder
+    assert!(matches!(InternationalCore::check(folder.join("missing").to_str().unwrap()).as_slice(), [Error::IoError { .. }]));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use sorrow_i18n::InternationalCore;
+
+// Translating and switching the locale of egui context, without a window.
+fn main() {
+    let core = InternationalCore::builder()
+        .insert("EN", "data.start", "Start")
+        .insert("RU", "data.start", "Старт")
+        .build();
+    let ctx = egui::Context::default();
+    assert_eq!("data.start", sorrow_i18n::egui::tr(&ctx, "data.start"));
+
+    sorrow_i18n::egui::install(&ctx, Arc::new(core), "en");
+    let mut texts = vec![];
+    let mut changed = false;
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            changed = sorrow_i18n::egui::language_picker(ui).changed();
+            texts.push(sorrow_i18n::egui::tr(ctx, "data.start"));
+        });
+    });
+    assert!(!changed);
+    assert_eq!(vec!["Start"], texts);
+
+    sorrow_i18n::egui::set_locale(&ctx, "ru");
+    assert_eq!(Some("ru".to_string()), sorrow_i18n::egui::locale(&ctx));
+    assert_eq!("Старт", sorrow_i18n::egui::tr(&ctx, "data.start"));
+    assert_eq!("data.unknown", sorrow_i18n::egui::tr(&ctx, "data.unknown"));
+}
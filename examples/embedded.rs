@@ -0,0 +1,27 @@
+use sorrow_i18n::embedded::{Catalog, Catalogs};
+
+// Static catalogs, the same code works without std (default-features = false).
+static EN: Catalog = Catalog::new("en", &[
+    ("data.name", "Test"),
+    ("data.greeting", "Hello, {name}!"),
+    ("data.place.one", "{n}st"),
+    ("data.place.two", "{n}nd"),
+    ("data.place.few", "{n}rd"),
+    ("data.place.other", "{n}th"),
+]);
+static RU: Catalog = Catalog::new("RU", &[("data.name", "Тест")]);
+static CATALOGS: Catalogs = Catalogs::new(&[EN, RU]);
+
+fn main() {
+    let en = CATALOGS.get_by_locale("en-GB").unwrap();
+    assert_eq!("en", en.locale());
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!("Hello, Bob!", en.get_with_args("data.greeting", &[("name", &"Bob")]));
+    assert_eq!("22nd", en.get_ordinal("data.place", 22));
+    assert_eq!("data.title", en.get_or_default("data.title"));
+
+    let ru = CATALOGS.get_by_locale("ru").unwrap();
+    assert_eq!(Some("Тест"), ru.get("data.name"));
+    assert!(CATALOGS.get_by_locale("de").is_none());
+    assert_eq!(vec!["en", "ru"], CATALOGS.locales().collect::<Vec<String>>());
+}
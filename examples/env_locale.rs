@@ -0,0 +1,22 @@
+use sorrow_i18n::{locale, GetData, InternationalCore};
+
+// CLI application, the locale is taken from LC_ALL, LC_MESSAGES or LANG.
+fn main() {
+    std::env::set_var("LC_ALL", "");
+    std::env::set_var("LC_MESSAGES", "ru_RU.UTF-8");
+    std::env::set_var("LANG", "en_US.UTF-8");
+    assert_eq!(Some("ru-RU".to_string()), locale::env_locale());
+
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = InternationalCore::new(manifest);
+    locale::prefer_env_locale(true);
+    // ru-RU falls back to ru
+    assert_eq!("Тест", core.get_current_locale().unwrap().get_or_default("data.name"));
+
+    // The scoped locale takes precedence.
+    assert_eq!("Test", locale::with_locale("EN", || core.get_current_locale().unwrap().get_or_default("data.name")));
+
+    // C locale has no language
+    std::env::set_var("LC_ALL", "C");
+    assert_eq!(None, locale::env_locale());
+}
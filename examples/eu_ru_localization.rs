@@ -1,18 +1,18 @@
-use sorrow_i18n::{GetData, InternationalCore};
+use sorrow_i18n::{GetData, InternationalCore, KeyId};
 
 fn main() {
     // Init core
     let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
-    let core = InternationalCore::new(manifest);
+    let mut core = InternationalCore::new(manifest);
 
     // We get EN locale
     // This method returns a mutable reference to the value (internally).
     let eu = core.get_by_locale("EN");
-    assert_eq!(true, eu.is_some());
+    assert!(eu.is_some());
 
     // We get RU locale
     let ru = core.get_by_locale("RU");
-    assert_eq!(true, ru.is_some());
+    assert!(ru.is_some());
     let eu_un = eu.unwrap();
     let ru_un = ru.unwrap();
 
@@ -20,31 +20,65 @@ fn main() {
     let eu_name = eu_un.get("data.name");
     let ru_name = ru_un.get("data.name");
 
-    assert_eq!(true, eu_name.is_some());
+    assert!(eu_name.is_some());
     assert_eq!("Test", eu_name.unwrap());
 
-    assert_eq!(true, ru_name.is_some());
+    assert!(ru_name.is_some());
     assert_eq!("Тест", ru_name.unwrap());
 
     // We return the key, because it does not exist.
     assert_eq!("data.modify", eu_un.get_or_default("data.modify"));
 
+    // Shared value, the string is not copied from the holder.
+    let shared = eu_un.get_arc("data.name").unwrap();
+    assert_eq!("Test", &*shared);
+    // Or read the value in place.
+    assert_eq!(Some(4), eu_un.with("data.name", |name| name.len()));
+    assert_eq!(None, eu_un.with("data.modify", |name| name.len()));
+
+    // Interned key, ids are shared by locales, the lookup does not hash the key.
+    let name = KeyId::new("data.name");
+    assert_eq!(Some("Test".into()), eu_un.get_by_id(name));
+    assert_eq!(Some("Тест".into()), ru_un.get_by_id(name));
+    assert_eq!(None, eu_un.get_by_id(KeyId::new("data.modify")));
+
     // Flattened representation of keys
     let representation = eu_un.get("data.representation.yes");
-    assert_eq!(true, representation.is_some());
+    assert!(representation.is_some());
     assert_eq!("No!", representation.unwrap());
     let currency = eu_un.get("data.currency.a");
-    assert_eq!(true, currency.is_some());
+    assert!(currency.is_some());
     assert_eq!("No definition", currency.unwrap());
     let data_block = eu_un.get("data.data.block");
-    assert_eq!(true, data_block.is_some());
+    assert!(data_block.is_some());
     assert_eq!("test1", data_block.unwrap());
     let data_wok = eu_un.get("data.data.wok");
-    assert_eq!(true, data_wok.is_some());
+    assert!(data_wok.is_some());
     assert_eq!("test2", data_wok.unwrap());
 
     // Key vector
     let keys = ru_un.keys();
     assert_eq!(1usize, keys.len());
-    assert_eq!("data.name", keys.get(0).unwrap());
-}
\ No newline at end of file
+    assert_eq!("data.name", keys.first().unwrap());
+
+    // Locales are normalized, casing and separator differences don't matter.
+    let en_lower = core.get_by_locale("en").unwrap();
+    assert_eq!("Test", en_lower.get_or_default("data.name"));
+    assert_eq!("en", en_lower.locale());
+
+    // Errors while loading can be handled instead of panic.
+    let err = InternationalCore::try_new("not_found_folder").err().unwrap();
+    // The underlying IO error is kept as source.
+    assert!(std::error::Error::source(&err).is_some());
+
+    // Available locales and descriptions, e.g. for a language picker.
+    assert_eq!(vec!["ee", "en", "ru"], core.locales());
+    assert_eq!(Some("test ru".to_string()), core.locale_description("RU"));
+
+    // Remove locale at runtime, the RU file is not watched anymore.
+    core.remove_locale("RU").unwrap();
+    assert!(core.get_by_locale("RU").is_none());
+    assert!(core.remove_locale("RU").is_err());
+    // Data received earlier keeps the last values.
+    assert_eq!("Тест", ru_un.get_or_default("data.name"));
+}
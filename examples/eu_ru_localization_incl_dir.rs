@@ -11,11 +11,11 @@ fn main() {
     // Getting EN locale
     // This method returns a mutable reference to the value (internally).
     let eu = core.get_by_locale("EN");
-    assert_eq!(true, eu.is_some());
+    assert!(eu.is_some());
 
     // Getting RU locale
     let ru = core.get_by_locale("RU");
-    assert_eq!(true, ru.is_some());
+    assert!(ru.is_some());
     let eu_un = eu.unwrap();
     let ru_un = ru.unwrap();
 
@@ -23,10 +23,10 @@ fn main() {
     let eu_name = eu_un.get("data.name");
     let ru_name = ru_un.get("data.name");
 
-    assert_eq!(true, eu_name.is_some());
+    assert!(eu_name.is_some());
     assert_eq!("Test", eu_name.unwrap());
 
-    assert_eq!(true, ru_name.is_some());
+    assert!(ru_name.is_some());
     assert_eq!("Тест", ru_name.unwrap());
 
     // We return the key, because it does not exist.
@@ -35,5 +35,5 @@ fn main() {
     // Key vector
     let keys = ru_un.keys();
     assert_eq!(1usize, keys.len());
-    assert_eq!("data.name", keys.get(0).unwrap());
+    assert_eq!("data.name", keys.first().unwrap());
 }
\ No newline at end of file
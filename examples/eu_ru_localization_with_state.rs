@@ -7,10 +7,10 @@ fn main() {
 
     // This method returns an unmodifiable reference to the value (internally).
     let eu = core.get_by_locale_state("EN");
-    assert_eq!(true, eu.is_some());
+    assert!(eu.is_some());
 
     let ru = core.get_by_locale_state("RU");
-    assert_eq!(true, ru.is_some());
+    assert!(ru.is_some());
     let eu_un = eu.unwrap();
     let ru_un = ru.unwrap();
 
@@ -19,17 +19,21 @@ fn main() {
     let eu_name = eu_un.get("data.name");
     let ru_name = ru_un.get("data.name");
 
-    assert_eq!(true, eu_name.is_some());
+    assert!(eu_name.is_some());
     assert_eq!("Test", eu_name.unwrap());
 
-    assert_eq!(true, ru_name.is_some());
+    assert!(ru_name.is_some());
     assert_eq!("Тест", ru_name.unwrap());
 
     // We return the key, because it does not exist.
     assert_eq!("data.modify", eu_un.get_or_default("data.modify"));
 
+    // Borrowed value, the string is not copied.
+    let borrowed = eu_un.get_cow("data.name").unwrap();
+    assert!(matches!(borrowed, std::borrow::Cow::Borrowed("Test")));
+
     // Key vector
     let keys = ru_un.keys();
     assert_eq!(1usize, keys.len());
-    assert_eq!("data.name", keys.get(0).unwrap());
+    assert_eq!("data.name", keys.first().unwrap());
 }
\ No newline at end of file
@@ -0,0 +1,39 @@
+use sorrow_i18n::{BundleFormat, GetData, Holder, InternationalCore};
+
+// Editing the catalog programmatically and writing it back.
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru/I18N_EN.yaml");
+    let holder = Holder::new(manifest).unwrap();
+    holder.messages().insert("data.farewell", "Bye, {name}!");
+
+    let folder = std::env::temp_dir().join("sorrow_i18n_export");
+    std::fs::create_dir_all(&folder).unwrap();
+    let path = folder.join("I18N_EN.yaml");
+    holder.write_yaml(&path).unwrap();
+
+    // The written file is loaded to the same messages.
+    let written = Holder::new(path.to_str().unwrap()).unwrap();
+    assert_eq!(holder.messages().to_map(), written.messages().to_map());
+    assert_eq!(Some("test en"), written.description());
+
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    assert_eq!("Bye, {name}!", core.get_by_locale("en").unwrap().get_or_default("data.farewell"));
+    assert_eq!("No!", core.get_by_locale("en").unwrap().get_or_default("data.representation.yes"));
+
+    // All locales in one document
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = InternationalCore::new(manifest);
+    let mut yaml = Vec::new();
+    core.export_bundle(&mut yaml, BundleFormat::Yaml).unwrap();
+    let bundle: Vec<serde_yaml::Value> = serde_yaml::from_slice(&yaml).unwrap();
+    let locales = bundle.iter().map(|s| s["locale"].as_str().unwrap()).collect::<Vec<&str>>();
+    assert_eq!(vec!["ee", "en", "ru"], locales);
+    assert_eq!("Тест", bundle[2]["data"]["name"].as_str().unwrap());
+
+    let mut json = Vec::new();
+    core.export_bundle(&mut json, BundleFormat::Json).unwrap();
+    let bundle: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!("Test", bundle[1]["data"]["name"]);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
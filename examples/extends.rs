@@ -0,0 +1,26 @@
+use sorrow_i18n::{GetData, InternationalCore};
+
+// en-AU contains only the deltas, the rest is inherited from en-GB.
+fn main() {
+    let folder = std::env::temp_dir().join("sorrow_i18n_extends");
+    std::fs::create_dir_all(&folder).unwrap();
+    let gb = folder.join("I18N_EN_GB.yaml");
+    std::fs::write(&gb, "kind: I18N\nlocale: en-GB\ndata:\n  colour: \"Colour\"\n  greeting: \"Hello\"\n").unwrap();
+    std::fs::write(folder.join("I18N_EN_AU.yaml"), "kind: I18N\nlocale: en-AU\nextends: en-GB\ndata:\n  greeting: \"G'day\"\n").unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: en\ndata:\n  colour: \"Color\"\n  title: \"Title\"\n").unwrap();
+
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    let au = core.get_by_locale("en-AU").unwrap();
+    assert_eq!("G'day", au.get_or_default("data.greeting"));
+    // The parent is searched before the language holder `en`.
+    assert_eq!("Colour", au.get_or_default("data.colour"));
+    assert_eq!("Title", au.get_or_default("data.title"));
+    assert_eq!(Some("Colour".to_string()), core.get_by_locale_state("en-AU").unwrap().get("data.colour"));
+
+    // The reload of the parent is seen by the child.
+    std::fs::write(&gb, "kind: I18N\nlocale: en-GB\ndata:\n  colour: \"Colour!\"\n  greeting: \"Hello\"\n").unwrap();
+    core.reload_locale("en-GB").unwrap();
+    assert_eq!("Colour!", au.get_or_default("data.colour"));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,37 @@
+use sorrow_i18n::{GetData, InternationalCore};
+
+fn main() {
+    // Init core
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let mut core = InternationalCore::new(manifest);
+
+    // Without fallback chain the key is returned.
+    let ee = core.get_by_locale("EE").unwrap();
+    assert_eq!("data.greeting", ee.get_or_default("data.greeting"));
+
+    // EE -> RU -> EN
+    core.set_fallback_chain("EE", &["RU", "EN"]);
+    let ee = core.get_by_locale("EE").unwrap();
+    // Key exists in EE
+    assert_eq!("Helly belly", ee.get_or_default("data.name"));
+    // Key exists only in EN
+    assert_eq!("Hello, {name}!", ee.get_or_default("data.greeting"));
+    // Key does not exist in the chain
+    assert_eq!("data.not_found_me", ee.get_or_default("data.not_found_me"));
+
+    // The same for the state.
+    let ee_state = core.get_by_locale_state("EE").unwrap();
+    assert_eq!("Helly belly", ee_state.get_or_default("data.name"));
+    assert_eq!("Hello, {name}!", ee_state.get_or_default("data.greeting"));
+
+    // Region-to-language fallback, en-GB does not exist, but en does.
+    let en_gb = core.get_by_locale("en-GB").unwrap();
+    assert_eq!("Test", en_gb.get_or_default("data.name"));
+    assert_eq!("en-GB", en_gb.locale());
+
+    // The locale does not exist, but the default chain does.
+    assert!(core.get_by_locale("fr-CA").is_none());
+    core.set_default_fallback_chain(&["EN"]);
+    let fr = core.get_by_locale("fr-CA").unwrap();
+    assert_eq!("Test", fr.get_or_default("data.name"));
+}
@@ -1,12 +1,12 @@
 use std::thread::sleep;
 use std::time::Duration;
-use sorrow_i18n::{GetData, InternationalCore};
+use sorrow_i18n::{FileProvider, GetData, InternationalCore};
 
 // A visual demonstration of changing the file and changing values in the holders.
 fn main() {
     // Init core
     let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
-    let core = InternationalCore::new(manifest.clone());
+    let mut core = InternationalCore::new(manifest.clone());
 
     // This method returns a mutable reference to the value (internally).
     let ru_locale = core.get_by_locale("RU").unwrap();
@@ -34,4 +34,26 @@ fn main() {
     // We check if everything is correct.
     name = ru_locale.get_or_default("data.name");
     assert_eq!("Тест", name);
-}
\ No newline at end of file
+
+    // Events are collapsed by the debounce window, the file is reloaded once it is quiet.
+    core.add_provider("RU", Box::new(FileProvider::from_path(&ru_path).with_debounce(Duration::from_millis(300)))).unwrap();
+    data = data.replace("Тест", "Хей! Как ты?");
+    std::fs::write(&ru_path, data.as_bytes()).unwrap();
+    sleep(Duration::from_millis(50));
+    assert_eq!("Тест", ru_locale.get_or_default("data.name"));
+    sleep(Duration::from_millis(600));
+    assert_eq!("Хей! Как ты?", ru_locale.get_or_default("data.name"));
+
+    // Overwrite changes
+    data = data.replace("Хей! Как ты?", "Тест");
+    std::fs::write(&ru_path, data.as_bytes()).unwrap();
+    sleep(Duration::from_millis(600));
+    assert_eq!("Тест", ru_locale.get_or_default("data.name"));
+
+    // Dropped holders stop watching, the data taken before is not changed anymore.
+    drop(core);
+    std::fs::write(&ru_path, data.replace("Тест", "Хей! Как ты?").as_bytes()).unwrap();
+    sleep(Duration::from_millis(600));
+    assert_eq!("Тест", ru_locale.get_or_default("data.name"));
+    std::fs::write(&ru_path, data.as_bytes()).unwrap();
+}
@@ -0,0 +1,57 @@
+use std::thread::sleep;
+use std::time::Duration;
+use sorrow_i18n::{GetData, InternationalCore};
+
+// Several folders loaded in order, e.g. system folder and user config folder.
+fn main() {
+    // Prepare folders
+    let root = std::env::temp_dir().join("sorrow_i18n_folders");
+    let (system, user) = (root.join("system"), root.join("user"));
+    std::fs::create_dir_all(&system).unwrap();
+    std::fs::create_dir_all(&user).unwrap();
+    std::fs::write(system.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  name: \"Test\"\n  title: \"Title\"\n").unwrap();
+    std::fs::write(system.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\ndata:\n  name: \"Тест\"\n").unwrap();
+    let user_en = user.join("I18N_EN.yaml");
+    std::fs::write(&user_en, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"User test\"\n  greeting: \"Hi\"\n").unwrap();
+
+    // Init core, missing folder is skipped.
+    let folders = [&system, &user, &root.join("missing")].map(|folder| folder.to_string_lossy().to_string());
+    let core = InternationalCore::try_from_folders(folders).unwrap();
+
+    // Keys of the user folder override keys of the system folder, other keys are kept.
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("User test", en.get_or_default("data.name"));
+    assert_eq!("Title", en.get_or_default("data.title"));
+    assert_eq!("Тест", core.get_by_locale("RU").unwrap().get_or_default("data.name"));
+
+    // The user file is still watched.
+    std::fs::write(&user_en, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Changed\"\n").unwrap();
+    sleep(Duration::from_millis(600));
+    assert_eq!("Changed", en.get_or_default("data.name"));
+    assert_eq!("Title", en.get_or_default("data.title"));
+
+    // The key removed from the user file is taken from the system folder again.
+    std::fs::write(&user_en, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  other: \"Other\"\n").unwrap();
+    sleep(Duration::from_millis(600));
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!("Other", en.get_or_default("data.other"));
+    assert_eq!(None, en.get("data.greeting"));
+
+    drop(core);
+
+    // Overrides of the end user patch a few keys of the loaded core.
+    let overrides = root.join("overrides");
+    std::fs::create_dir_all(&overrides).unwrap();
+    std::fs::write(overrides.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\ndata:\n  title: \"Заголовок\"\n").unwrap();
+    let mut core = InternationalCore::new(system.to_str().unwrap());
+    core.add_overrides(overrides.to_str().unwrap()).unwrap();
+    let ru = core.get_by_locale("RU").unwrap();
+    assert_eq!("Тест", ru.get_or_default("data.name"));
+    assert_eq!("Заголовок", ru.get_or_default("data.title"));
+    assert_eq!("Test", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+    // Missing folder of overrides changes nothing.
+    core.add_overrides(root.join("missing").to_str().unwrap()).unwrap();
+
+    drop(core);
+    std::fs::remove_dir_all(&root).unwrap();
+}
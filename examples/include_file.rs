@@ -0,0 +1,15 @@
+use sorrow_i18n::{include_i18n, GetData, InternationalCore};
+
+// Single file embedded at compile time, without the folder of locales.
+fn main() {
+    let en = include_i18n!("../resources/en_ru/I18N_EN.yaml");
+    let ru = include_i18n!("../resources/en_ru/I18N_RU.yaml");
+
+    let core = InternationalCore::from_holders(vec![en, ru]).unwrap();
+    assert_eq!("Test", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+    assert_eq!("Тест", core.get_by_locale("RU").unwrap().get_or_default("data.name"));
+
+    // Embedded holders are not reloaded.
+    assert!(core.reload().is_ok());
+    assert_eq!("Test", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+}
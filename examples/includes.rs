@@ -0,0 +1,32 @@
+use sorrow_i18n::{Error, GetData, Holder, InternationalCore};
+
+// Brand names and URLs shared by the locales live in one file.
+fn main() {
+    let folder = std::env::temp_dir().join("sorrow_i18n_includes");
+    std::fs::create_dir_all(folder.join("shared")).unwrap();
+    std::fs::write(folder.join("shared/brand.yaml"), "kind: I18N_INCLUDE\ndata:\n  brand: \"Sorrow\"\n  url: \"https://example.com\"\n").unwrap();
+    std::fs::write(folder.join("common.yaml"), "kind: I18N_INCLUDE\ndata:\n  support: \"support@example.com\"\n").unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\nincludes: [shared/brand.yaml, common.yaml]\ndata:\n  name: \"Test\"\n").unwrap();
+    std::fs::write(folder.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\nincludes: [shared/brand.yaml]\ndata:\n  name: \"Тест\"\n  url: \"https://example.com/ru\"\n").unwrap();
+
+    // The included files are not the locales, the loader skips them.
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    assert_eq!(vec!["en", "ru"], core.locales());
+
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Sorrow", en.get_or_default("data.brand"));
+    assert_eq!("support@example.com", en.get_or_default("data.support"));
+    // The keys of the file override the included keys.
+    let ru = core.get_by_locale("RU").unwrap();
+    assert_eq!("Sorrow", ru.get_or_default("data.brand"));
+    assert_eq!("https://example.com/ru", ru.get_or_default("data.url"));
+
+    // The included keys are re-read with the file.
+    std::fs::write(folder.join("shared/brand.yaml"), "kind: I18N_INCLUDE\ndata:\n  brand: \"Sorrow I18N\"\n").unwrap();
+    core.reload_locale("RU").unwrap();
+    assert_eq!("Sorrow I18N", ru.get_or_default("data.brand"));
+
+    assert!(matches!(Holder::new(folder.join("common.yaml").to_str().unwrap()), Err(Error::IncludeFile { .. })));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
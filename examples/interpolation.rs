@@ -0,0 +1,39 @@
+use sorrow_i18n::{GetData, InternationalCore};
+use sorrow_i18n::format::EscapePolicy;
+
+fn main() {
+    // Init core
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let mut core = InternationalCore::new(manifest);
+
+    // data.greeting: "Hello, {name}!"
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Hello, Bob!", en.get_with_args("data.greeting", &[("name", &"Bob")]));
+
+    // Placeholders without argument are left as is.
+    assert_eq!("Hello, {name}!", en.get_with_args("data.greeting", &[]));
+
+    // The key does not exist, we return the key.
+    assert_eq!("data.not_found_me", en.get_with_args("data.not_found_me", &[("name", &"Bob")]));
+
+    // Messages are compiled on loading, the template can be kept and rendered many times.
+    let greeting = en.get_template("data.greeting").unwrap();
+    assert_eq!(vec!["name"], greeting.placeholders().collect::<Vec<&str>>());
+    assert_eq!("Hello, Alice!", greeting.render(&[("name", &"Alice")], en.format_options()));
+
+    // Enable bidi isolation, interpolated values are wrapped in FSI/PDI marks.
+    core.set_bidi_isolation(true);
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Hello, \u{2068}שלום\u{2069}!", en.get_with_args("data.greeting", &[("name", &"שלום")]));
+
+    // Escape arguments for HTML, the markup of the message stays intact.
+    // data.greeting_html: "<b>Hello</b>, {name}!"
+    core.set_bidi_isolation(false);
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("<b>Hello</b>, &lt;script&gt;!", en.get_html("data.greeting_html", &[("name", &"<script>")]));
+
+    // Or set the escape policy for all interpolated values.
+    core.set_escape_policy(EscapePolicy::Html);
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Hello, Tom &amp; Jerry!", en.get_with_args("data.greeting", &[("name", &"Tom & Jerry")]));
+}
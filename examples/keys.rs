@@ -0,0 +1,31 @@
+use sorrow_i18n::{GetData, InternationalCore};
+
+// Menu built by the section of the catalog.
+fn main() {
+    let mut core = InternationalCore::builder()
+        .locale("EN", [("menu.file", "File"), ("menu.edit", "Edit"), ("menu.view", "View"), ("title", "Editor")])
+        .locale("RU", [("menu.file", "Файл")])
+        .build();
+    let en = core.get_by_locale("EN").unwrap();
+
+    let menu = en.keys_with_prefix("menu.").iter().map(|key| en.get_or_default(key)).collect::<Vec<String>>();
+    assert_eq!(vec!["Edit", "File", "View"], menu);
+    assert!(en.keys_with_prefix("toolbar.").is_empty());
+
+    let mut pairs = en.iter().map(|(key, value)| format!("{}={}", key, value)).collect::<Vec<String>>();
+    pairs.sort();
+    assert_eq!(vec!["menu.edit=Edit", "menu.file=File", "menu.view=View", "title=Editor"], pairs);
+
+    // The whole section at once, the missing messages are taken from the fallback chain.
+    core.set_fallback_chain("RU", &["EN"]);
+    let ru = core.get_by_locale("RU").unwrap();
+    let menu = ru.get_all("menu.");
+    assert_eq!(3, menu.len());
+    assert_eq!("Файл", menu["menu.file"]);
+    assert_eq!("Edit", menu["menu.edit"]);
+
+    // Snapshot too
+    let state = core.get_by_locale_state("EN").unwrap();
+    assert_eq!(vec!["menu.edit", "menu.file", "menu.view"], state.keys_with_prefix("menu."));
+    assert_eq!("View", state.get_all("menu.")["menu.view"]);
+}
@@ -0,0 +1,39 @@
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+
+use sorrow_i18n::{GetData, InternationalCore};
+
+// Files are parsed on first access to the locale.
+fn main() {
+    // Prepare folder, the RU file is broken, but it is never used.
+    let folder = std::env::temp_dir().join("sorrow_i18n_lazy");
+    std::fs::create_dir_all(&folder).unwrap();
+    let en = folder.join("I18N_EN.yaml");
+    std::fs::write(&en, "kind: I18N\nlocale: EN\ndescription: test en\nprovider: FileProvider\ndata:\n  name: \"Test\"\n").unwrap();
+    std::fs::write(folder.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\ndata: [\n").unwrap();
+
+    // Only the folder is read.
+    let mut core = InternationalCore::lazy(folder.to_str().unwrap()).unwrap();
+    let errors = Arc::new(Mutex::new(vec![]));
+    let received = Arc::clone(&errors);
+    core.on_watch_error(move |e| received.lock().unwrap().push(e.to_string()));
+    assert_eq!(vec!["en", "ru"], core.locales());
+
+    // EN is parsed now and watched by its provider.
+    let data = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", data.get_or_default("data.name"));
+    assert_eq!(Some("test en".to_string()), core.locale_description("EN"));
+    std::fs::write(&en, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Changed\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Changed", data.get_or_default("data.name"));
+    assert!(errors.lock().unwrap().is_empty());
+
+    // The broken file is reported on first access, the locale is empty.
+    let ru = core.get_by_locale("RU").unwrap();
+    assert_eq!("data.name", ru.get_or_default("data.name"));
+    assert_eq!(1, errors.lock().unwrap().len());
+
+    drop(core);
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use leptos::prelude::*;
+use sorrow_i18n::leptos::{provide_i18n, use_i18n};
+use sorrow_i18n::InternationalCore;
+
+#[component]
+fn Greeting() -> impl IntoView {
+    let i18n = use_i18n();
+    view! {
+        <button on:click=move |_| i18n.set_locale("ru")>{move || i18n.t("data.name")}</button>
+    }
+}
+
+// Translations are resolved by the reactive locale, the same code runs on the server and in the browser.
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = Arc::new(InternationalCore::new(manifest));
+    let owner = Owner::new();
+    owner.set();
+
+    let i18n = provide_i18n(core, "en");
+    let title = Signal::derive(move || i18n.t("data.name"));
+    assert_eq!("Test", title.get());
+
+    // Server-side rendering
+    let html = view! { <Greeting /> }.to_html();
+    assert!(html.contains(">Test</button>"));
+
+    // The derived values are updated when the locale is switched.
+    i18n.set_locale("ru");
+    assert_eq!("Тест", title.get());
+    assert_eq!("ru", i18n.locale().get());
+}
@@ -0,0 +1,32 @@
+use sorrow_i18n::{GetData, Holder, InternationalCore};
+
+// Lists of messages, e.g. weekday names and onboarding bullets.
+fn main() {
+    let data = r#"
+kind: I18N
+locale: EN
+data:
+  weekdays: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"]
+  onboarding:
+    - title: "Welcome"
+      text: "Let's start."
+    - title: "Done"
+      text: "You are ready."
+"#;
+    let holder = Holder::parse(data).unwrap();
+    // Lists are written back as YAML sequences.
+    let yaml = holder.to_yaml().unwrap();
+    assert!(yaml.contains("- Monday"));
+    assert_eq!(Some("Sunday".to_string()), Holder::parse(&yaml).unwrap().messages().get("data.weekdays.6"));
+
+    let core = InternationalCore::from_holders(vec![holder]).unwrap();
+    let en = core.get_by_locale("EN").unwrap();
+
+    let weekdays = en.get_vec("data.weekdays").unwrap();
+    assert_eq!(7, weekdays.len());
+    assert_eq!("Monday", weekdays[0]);
+    // Indexed access
+    assert_eq!("Tuesday", en.get_or_default("data.weekdays.1"));
+    assert_eq!("You are ready.", en.get_or_default("data.onboarding.1.text"));
+    assert_eq!(None, en.get_vec("data.months"));
+}
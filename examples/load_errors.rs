@@ -0,0 +1,28 @@
+use sorrow_i18n::{Error, InternationalCore};
+
+// Files are loaded in parallel, all errors of the folder are returned.
+fn main() {
+    let folder = std::env::temp_dir().join("sorrow_i18n_load_errors");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("A_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  name: \"Test\"\n").unwrap();
+    std::fs::write(folder.join("B_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  name: \"Duplicate\"\n").unwrap();
+    std::fs::write(folder.join("C_RU.yaml"), "kind: I18N\nlocale: RU\ndata: [\n").unwrap();
+    std::fs::write(folder.join("readme.txt"), "Not a locale").unwrap();
+
+    match InternationalCore::try_new(folder.to_str().unwrap()) {
+        Err(Error::LoadErrors { errors }) => {
+            assert_eq!(2, errors.len());
+            // Files are ordered by path, the second EN file is the duplicate.
+            assert!(matches!(&errors[0], Error::DuplicateLocale { locale } if locale == "en"));
+            assert!(matches!(&errors[1], Error::InvalidStructure { path, .. } if path.ends_with("C_RU.yaml")));
+        }
+        _ => panic!("Errors are expected."),
+    }
+
+    // Single error is returned as is.
+    std::fs::remove_file(folder.join("B_EN.yaml")).unwrap();
+    let error = InternationalCore::try_new(folder.to_str().unwrap()).err().unwrap();
+    assert!(matches!(error, Error::InvalidStructure { .. }));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
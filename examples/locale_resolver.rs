@@ -0,0 +1,30 @@
+use std::cell::RefCell;
+
+use sorrow_i18n::{locale, GetData, InternationalCore};
+
+thread_local! {
+    // Locale of the profile of the logged user, e.g. the claim of the token.
+    static USER_LOCALE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let mut core = InternationalCore::new(manifest);
+    core.set_locale_resolver(|| USER_LOCALE.with(|l| l.borrow().clone()));
+
+    USER_LOCALE.with(|l| *l.borrow_mut() = Some("RU".to_string()));
+    assert_eq!("RU", core.current_locale());
+    assert_eq!("Тест", core.get_current_locale().unwrap().get_or_default("data.name"));
+
+    // The scoped locale takes precedence.
+    assert_eq!("Test", locale::with_locale("EN", || core.get_current_locale().unwrap().get_or_default("data.name")));
+
+    USER_LOCALE.with(|l| *l.borrow_mut() = Some("EN".to_string()));
+    assert_eq!("Test", core.get_current_locale_state().unwrap().get_or_default("data.name"));
+
+    // No user, the system locale is used.
+    USER_LOCALE.with(|l| *l.borrow_mut() = None);
+    let resolved = core.current_locale();
+    core.clear_locale_resolver();
+    assert_eq!(resolved, core.current_locale());
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use sorrow_i18n::{GetData, InternationalCore, ToLocalizedString};
+
+// Duration of the delivery, rendered by the locale of the message.
+struct Eta(Duration);
+
+impl ToLocalizedString for Eta {
+    fn to_localized_string(&self, locale: &str) -> String {
+        let minutes = self.0.as_secs() / 60;
+        match locale {
+            "ru" => format!("{} мин", minutes),
+            _ => format!("{} min", minutes),
+        }
+    }
+}
+
+fn main() {
+    let core = Arc::new(InternationalCore::builder()
+        .insert("EN", "data.eta", "{status}: arrives in {eta}, order #{id}")
+        .insert("EN", "data.status.shipped", "Shipped")
+        .insert("RU", "data.eta", "{status}: прибудет через {eta}, заказ №{id}")
+        .insert("RU", "data.status.shipped", "Отправлен")
+        .build());
+    let status = core.localize("data.status.shipped");
+    let eta = Eta(Duration::from_secs(300));
+    let args: [(&str, &dyn ToLocalizedString); 3] = [("status", &status), ("eta", &eta), ("id", &42)];
+
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Shipped: arrives in 5 min, order #42", en.get_with_localized_args("data.eta", &args));
+
+    let ru = core.get_by_locale_state("RU").unwrap();
+    assert_eq!("Отправлен: прибудет через 5 мин, заказ №42", ru.get_with_localized_args("data.eta", &args));
+}
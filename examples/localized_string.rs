@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use sorrow_i18n::{locale, InternationalCore, LocalizedString};
+
+// The menu is built before the language is chosen.
+struct MenuItem {
+    title: LocalizedString,
+}
+
+fn main() {
+    let core = Arc::new(InternationalCore::new("resources/en_ru"));
+    let menu = [MenuItem { title: core.localize("data.name") }, MenuItem { title: core.localize("data.not_found_me") }];
+
+    // Rendered in the current locale
+    let en = locale::with_locale("EN", || menu.iter().map(|item| item.title.to_string()).collect::<Vec<String>>());
+    assert_eq!(vec!["Test", "data.not_found_me"], en);
+    let ru = locale::with_locale("RU", || format!("{}", menu[0].title));
+    assert_eq!("Тест", ru);
+
+    // Explicit locale
+    assert_eq!("Тест", menu[0].title.in_locale("RU"));
+    assert_eq!("data.name", menu[0].title.key());
+}
@@ -1,9 +1,11 @@
-use sorrow_i18n::{i18n, init_i18n};
+use sorrow_i18n::{i18n, init_i18n, set_i18n_fallback_chain, t};
+use sorrow_i18n::feature_macro::{cores, get_current_locale, set_current_locale, DEFAULT_CORE};
+use sorrow_i18n::locale::with_locale;
 
 fn main() {
     // Init core
     let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
-    init_i18n!(manifest);
+    init_i18n!(manifest).unwrap();
 
     // Getting key data.name by locale RU
     let test = i18n!("RU", "data.name");
@@ -14,4 +16,41 @@ fn main() {
     let not_found_data = i18n!("RU", "data.not_found_me");
     println!("data not found: {}", &*not_found_data);
     assert_eq!("data.not_found_me", &*not_found_data);
-}
\ No newline at end of file
+
+    // Fallback chain, the key does not exist in RU, but exists in EN.
+    assert_eq!("data.greeting", &*i18n!("RU", "data.greeting"));
+    set_i18n_fallback_chain!("RU", &["EN"]);
+    assert_eq!("Hello, {name}!", &*i18n!("RU", "data.greeting"));
+
+    // Switching the current locale, i18n! without locale uses it.
+    set_current_locale("RU");
+    assert_eq!("RU", get_current_locale());
+    assert_eq!("Тест", &*i18n!("data.name"));
+    set_current_locale("EN");
+    assert_eq!("Test", &*i18n!("data.name"));
+
+    // Thread-local locale for the scope, e.g. request handler.
+    let name = with_locale("RU", || i18n!("data.name"));
+    assert_eq!("Тест", name);
+    assert_eq!("Test", &*i18n!("data.name"));
+
+    // t! uses the current locale, the arguments are interpolated.
+    assert_eq!("Test", t!("data.name"));
+    assert_eq!("Hello, Bob!", t!("data.greeting", name = "Bob"));
+    let count = 3;
+    assert_eq!("Hello, 3!", with_locale("RU", || t!("data.greeting", name = count)));
+
+    // Named core, e.g. the catalogs of the library.
+    let folder = std::env::temp_dir().join("sorrow_i18n_macro_ui");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  name: \"Widget\"\n  hello: \"Hi, {name}\"\n").unwrap();
+    init_i18n!("ui", folder.to_str().unwrap()).unwrap();
+    assert_eq!(vec![DEFAULT_CORE.to_string(), "ui".to_string()], cores());
+    assert_eq!("Widget", &*i18n!(core = "ui", "EN", "data.name"));
+    assert_eq!("Widget", t!(core = "ui", "data.name"));
+    assert_eq!("Hi, Bob", t!(core = "ui", "data.hello", name = "Bob"));
+    // The fallback chain of the default core is not shared.
+    assert_eq!("data.name", &*i18n!(core = "ui", "RU", "data.name"));
+    assert_eq!("Test", &*i18n!("data.name"));
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -1,11 +1,10 @@
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
-use sorrow_i18n::{WatchProvider, init_i18n, set_i18n_provider, i18n, Error};
+use std::sync::Arc;
+use sorrow_i18n::{WatchProvider, init_i18n, set_i18n_provider, i18n, Error, Messages};
 
 fn main() {
     // Init core
     let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
-    init_i18n!(manifest);
+    init_i18n!(manifest).unwrap();
 
     // Creating provider
     let provider = Box::new(CustomProvider::new());
@@ -30,28 +29,32 @@ fn main() {
 }
 
 pub struct CustomProvider {
-    data: Arc<RwLock<HashMap<String, String>>>,
+    data: Arc<Messages>,
 }
 
 impl CustomProvider {
     pub fn new() -> Self {
         CustomProvider {
-            data: Arc::new(RwLock::new(HashMap::new()))
+            data: Arc::new(Messages::default())
         }
     }
 }
 
+impl Default for CustomProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WatchProvider for CustomProvider {
     fn watch(&mut self) -> Result<(), sorrow_i18n::Error> {
         println!("Accepted custom provider");
-        let data = self.data.write();
-        let mut un = data.unwrap();
         println!("Add Hello key and value World");
-        un.insert("Hello".to_string(), "World".to_string());
+        self.data.insert("Hello", "World");
         Ok(())
     }
 
-    fn set_data(&mut self, data: Arc<RwLock<HashMap<String, String>>>) -> Result<(), Error> {
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
         self.data = data;
         Ok(())
     }
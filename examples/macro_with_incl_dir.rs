@@ -6,7 +6,7 @@ const PROJECT_DIR: Dir = include_dir!("resources/en_ru");
 
 fn main() {
     // Init core
-    init_i18n_static_dir!(PROJECT_DIR);
+    init_i18n_static_dir!(PROJECT_DIR).unwrap();
 
     // We getting data.name key by RU locale.
     let test = i18n!("RU", "data.name");
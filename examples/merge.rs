@@ -0,0 +1,36 @@
+use sorrow_i18n::{GetData, InternationalCore, MergePolicy};
+
+// Plugins ship their own locale folders, which are folded into the host core.
+fn main() {
+    // Prepare plugin folder
+    let folder = std::env::temp_dir().join("sorrow_i18n_merge");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  name: \"Plugin\"\n  plugin:\n    title: \"Plugin title\"\n").unwrap();
+    std::fs::write(folder.join("I18N_DE.yaml"), "kind: I18N\nlocale: DE\ndata:\n  name: \"Prüfung\"\n").unwrap();
+
+    // Init core
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let mut core = InternationalCore::new(manifest.clone());
+
+    // Conflict policy Error, data.name exists in both EN catalogs.
+    let plugin = InternationalCore::new(folder.to_str().unwrap());
+    assert!(core.merge(plugin, MergePolicy::Error).is_err());
+    assert!(core.get_by_locale("DE").is_none());
+
+    // Keep the values of the host core.
+    let plugin = InternationalCore::new(folder.to_str().unwrap());
+    core.merge(plugin, MergePolicy::KeepExisting).unwrap();
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!("Plugin title", en.get_or_default("data.plugin.title"));
+    // New locale has been added
+    assert_eq!("Prüfung", core.get_by_locale("DE").unwrap().get_or_default("data.name"));
+
+    // Overwrite by the plugin values.
+    let mut core = InternationalCore::new(manifest);
+    let plugin = InternationalCore::new(folder.to_str().unwrap());
+    core.merge(plugin, MergePolicy::Overwrite).unwrap();
+    assert_eq!("Plugin", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
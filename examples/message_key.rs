@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use sorrow_i18n::{locale, InternationalCore, MessageKey};
+
+// Messages of the library, there is no core yet.
+mod messages {
+    use sorrow_i18n::MessageKey;
+
+    pub const NAME: MessageKey = MessageKey::new("data.name");
+    pub const MISSING: MessageKey = MessageKey::new("data.not_found_me");
+}
+
+fn main() {
+    let core = Arc::new(InternationalCore::new("resources/en_ru"));
+
+    assert_eq!("Тест", messages::NAME.get(&core, "RU"));
+    assert_eq!("Test", locale::with_locale("EN", || messages::NAME.get_current(&core)));
+    let name = messages::NAME.localize(&core);
+    assert_eq!("Тест", locale::with_locale("RU", || name.to_string()));
+
+    // The key is not in the catalogs, the lookup panics in debug builds.
+    assert!(!messages::MISSING.exists(&core));
+    let key: MessageKey = messages::MISSING;
+    assert_eq!("data.not_found_me", key.key());
+}
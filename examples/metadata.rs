@@ -0,0 +1,47 @@
+use sorrow_i18n::{Entry, GetData, Holder, InternationalCore};
+
+// Notes for translators are kept with the messages and survive the export.
+fn main() {
+    let data = r#"
+kind: I18N
+locale: EN
+data:
+  title: "Settings"
+  save:
+    value: "Save"
+    comment: "Button of the settings form"
+    context: "verb"
+    max_len: 12
+  # Nested map, not the message with notes: `other` is not the field of the entry.
+  value:
+    value: "Value"
+    other: "Other"
+  # Nested map too, the entry has at least one note.
+  label:
+    value: "Label"
+"#;
+    let holder = Holder::parse(data).unwrap();
+    let save = holder.get_entry("data.save").unwrap();
+    assert_eq!(Entry { value: "Save".to_string(), comment: Some("Button of the settings form".to_string()), context: Some("verb".to_string()), max_len: Some(12) }, save);
+    assert_eq!(Entry { value: "Settings".to_string(), comment: None, context: None, max_len: None }, holder.get_entry("data.title").unwrap());
+    assert_eq!(Some("Value".to_string()), holder.messages().get("data.value.value"));
+    assert_eq!(Some("Label".to_string()), holder.messages().get("data.label.value"));
+    assert!(holder.get_entry("data.unknown").is_none());
+
+    // Round-trip, the notes are written back.
+    let yaml = holder.to_yaml().unwrap();
+    let written = Holder::parse(&yaml).unwrap();
+    assert_eq!(save, written.get_entry("data.save").unwrap());
+    assert_eq!(holder.messages().to_map(), written.messages().to_map());
+
+    // Lookups by the core, the value is the message.
+    let folder = std::env::temp_dir().join("sorrow_i18n_metadata");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), &yaml).unwrap();
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Save", en.get_or_default("data.save"));
+    assert_eq!(Some("verb".to_string()), en.get_entry("data.save").unwrap().context);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use metrics_util::debugging::DebuggingRecorder;
+use sorrow_i18n::metrics::{Metrics, MetricsRecorder};
+use sorrow_i18n::{Error, GetData, InternationalCore};
+
+/// Counters by locale.
+#[derive(Default)]
+struct Counters {
+    lookups: Mutex<HashMap<String, usize>>,
+    misses: Mutex<HashMap<String, usize>>,
+    reloads: Mutex<Vec<(String, bool)>>,
+}
+
+impl Metrics for Counters {
+    fn lookup(&self, locale: &str, found: bool) {
+        *self.lookups.lock().unwrap().entry(locale.to_string()).or_default() += 1;
+        if !found {
+            *self.misses.lock().unwrap().entry(locale.to_string()).or_default() += 1;
+        }
+    }
+
+    fn reload(&self, locale: &str, error: Option<&Error>) {
+        self.reloads.lock().unwrap().push((locale.to_string(), error.is_none()));
+    }
+}
+
+// Counting lookups, misses and reloads, e.g. to graph translation health.
+fn main() {
+    let folder = std::env::temp_dir().join("sorrow_i18n_metrics");
+    std::fs::create_dir_all(&folder).unwrap();
+    let en = folder.join("I18N_EN.yaml");
+    std::fs::write(&en, "kind: I18N\nlocale: EN\ndata:\n  name: \"Test\"\n").unwrap();
+
+    let mut core = InternationalCore::new(folder.to_str().unwrap());
+    let counters = Arc::new(Counters::default());
+    core.set_metrics(Arc::clone(&counters));
+
+    let data = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", data.get_or_default("data.name"));
+    assert_eq!("data.title", data.get_or_default("data.title"));
+    assert_eq!("data.title", core.get_by_locale_state("EN").unwrap().get_or_default("data.title"));
+    assert_eq!(Some(&3), counters.lookups.lock().unwrap().get("en"));
+    assert_eq!(Some(&2), counters.misses.lock().unwrap().get("en"));
+
+    core.reload().unwrap();
+    std::fs::write(&en, "kind: OTHER\n").unwrap();
+    assert!(core.reload().is_err());
+    assert_eq!(vec![("en".to_string(), true), ("en".to_string(), false)], *counters.reloads.lock().unwrap());
+
+    // Counters of the `metrics` crate
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    recorder.install().unwrap();
+    std::fs::write(&en, "kind: I18N\nlocale: EN\ndata:\n  name: \"Test\"\n").unwrap();
+    let mut core = InternationalCore::new(folder.to_str().unwrap());
+    core.set_metrics(MetricsRecorder);
+    let data = core.get_by_locale("EN").unwrap();
+    data.get_or_default("data.name");
+    data.get_or_default("data.title");
+    let counters = snapshotter.snapshot().into_vec().into_iter()
+        .map(|(key, _, _, value)| (key.key().name().to_string(), format!("{:?}", value)))
+        .collect::<HashMap<String, String>>();
+    assert_eq!("Counter(2)", counters["i18n_lookups_total"]);
+    assert_eq!("Counter(1)", counters["i18n_misses_total"]);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
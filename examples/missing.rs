@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use sorrow_i18n::{Error, GetData, InternationalCore, Localize, MissingKeyPolicy};
+
+/// Counts the warnings of the missing keys.
+struct Warnings(AtomicUsize);
+
+impl log::Log for Warnings {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() == log::Level::Warn
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) && record.args().to_string().contains("not found") {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static WARNINGS: Warnings = Warnings(AtomicUsize::new(0));
+
+/// Localized value with the key missing in the catalog.
+struct Title;
+
+impl Localize for Title {
+    fn key(&self) -> &'static str {
+        "data.title"
+    }
+}
+
+// Collecting untranslated keys, e.g. for QA.
+fn main() {
+    log::set_logger(&WARNINGS).unwrap();
+    log::set_max_level(log::LevelFilter::Warn);
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let mut core = InternationalCore::new(manifest);
+    let missing = Arc::new(Mutex::new(Vec::new()));
+    let collected = Arc::clone(&missing);
+    core.on_missing(move |locale, key| {
+        collected.lock().unwrap().push(format!("{}:{}", locale, key));
+        None
+    });
+
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!("data.title", en.get_or_default("data.title"));
+    assert_eq!("data.welcome", en.get_with_args("data.welcome", &[("name", &"Bob")]));
+    let ru = core.get_by_locale_state("RU").unwrap();
+    assert_eq!("data.title", ru.get_or_default("data.title"));
+    assert_eq!(vec!["en:data.title", "en:data.welcome", "ru:data.title"], *missing.lock().unwrap());
+
+    // Substitute the placeholder, so untranslated strings are visible in UI.
+    core.on_missing(|_, key| Some(format!("⚠ {}", key)));
+    assert_eq!("⚠ data.title", core.get_by_locale("EN").unwrap().get_or_default("data.title"));
+
+    // Strict lookup fails instead of returning the key.
+    assert_eq!("Test", core.try_get("EN", "data.name").unwrap());
+    match core.get_by_locale("EN").unwrap().try_get("data.title") {
+        Err(Error::MissingKey { locale, key }) => assert_eq!(("en", "data.title"), (locale.as_str(), key.as_str())),
+        _ => panic!("data.title must be missing"),
+    }
+    assert!(matches!(core.try_get("DE", "data.name"), Err(Error::MissingLocale { .. })));
+
+    // Each missing key is logged once, the handler is called on every miss.
+    core.log_missing_once(true);
+    let en = core.get_by_locale("EN").unwrap();
+    for _ in 0..100 {
+        assert_eq!("⚠ data.title", en.get_or_default("data.title"));
+    }
+    en.get_or_default("data.subtitle");
+    assert_eq!(2, WARNINGS.0.load(Ordering::SeqCst));
+
+    // Policy of the missing keys, used when the handler returns None.
+    let mut core = InternationalCore::new(format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru"));
+    core.set_missing_policy(MissingKeyPolicy::Empty);
+    assert_eq!("", core.get_by_locale("RU").unwrap().get_or_default("data.greeting"));
+    core.set_missing_policy(MissingKeyPolicy::Fallback("EN".to_string()));
+    let ru = core.get_by_locale("RU").unwrap();
+    assert_eq!("Hello, {name}!", ru.get_or_default("data.greeting"));
+    assert_eq!("data.title", ru.get_or_default("data.title"));
+    core.set_missing_policy(MissingKeyPolicy::Custom(|_, key| key.rsplit('.').next().unwrap_or(key).to_string()));
+    assert_eq!("title", core.get_by_locale("RU").unwrap().get_or_default("data.title"));
+    // The policy applies to the missing locales of the namespaces and of the localized values too.
+    assert_eq!("title", core.namespace("unknown").get_or_default("data.title"));
+    assert_eq!("title", Title.display(&core, "DE"));
+    core.on_missing(|_, _| Some("handler".to_string()));
+    assert_eq!("handler", core.get_by_locale("RU").unwrap().get_or_default("data.title"));
+    core.set_missing_policy(MissingKeyPolicy::Panic);
+    let ru = core.get_by_locale_state("RU").unwrap();
+    assert_eq!("handler", ru.get_or_default("data.title"));
+}
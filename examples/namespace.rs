@@ -0,0 +1,66 @@
+use sorrow_i18n::{GetData, InternationalCore, MergePolicy};
+
+// Modules ship files with the same keys, the namespace keeps them apart.
+fn main() {
+    // Prepare folder
+    let folder = std::env::temp_dir().join("sorrow_i18n_namespace");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  title: \"Shop\"\n").unwrap();
+    std::fs::write(folder.join("CHECKOUT_EN.yaml"), "kind: I18N\nlocale: EN\nnamespace: checkout\ndata:\n  title: \"Checkout\"\n").unwrap();
+    std::fs::write(folder.join("CHECKOUT_RU.yaml"), "kind: I18N\nlocale: RU\nnamespace: checkout\ndata:\n  title: \"Оформление заказа\"\n").unwrap();
+    std::fs::write(folder.join("CART_EN.yaml"), "kind: I18N\nlocale: EN\nnamespace: cart\ndata:\n  title: \"Cart\"\n").unwrap();
+
+    // Init core
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    assert_eq!(vec!["cart", "checkout"], core.namespaces());
+    // Namespaced holders are not mixed with the root ones.
+    assert_eq!(vec!["en"], core.locales());
+    assert_eq!("Shop", core.get_by_locale("EN").unwrap().get_or_default("data.title"));
+
+    // Scoped lookups
+    let checkout = core.namespace("checkout");
+    assert_eq!(vec!["en", "ru"], checkout.locales());
+    assert_eq!("Checkout", checkout.get_by_locale("EN").unwrap().get_or_default("data.title"));
+    assert_eq!("Оформление заказа", checkout.get_by_locale_state("RU").unwrap().get_or_default("data.title"));
+    assert_eq!("Cart", core.namespace("cart").get_by_locale("EN").unwrap().get_or_default("data.title"));
+    assert!(core.namespace("cart").get_by_locale("RU").is_none());
+    assert!(core.namespace("unknown").get_by_locale("EN").is_none());
+    assert_eq!("data.title", core.namespace("unknown").get_or_default("data.title"));
+
+    // Merge keeps namespaces apart too, no conflict with the root title.
+    let mut core = InternationalCore::new(format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru"));
+    let module = InternationalCore::new(folder.to_str().unwrap());
+    core.merge(module, MergePolicy::KeepExisting).unwrap();
+    assert_eq!("Checkout", core.namespace("checkout").get_by_locale("EN").unwrap().get_or_default("data.title"));
+
+    // The removed locale is removed from the namespaces too, the empty namespace is removed.
+    core.remove_locale("EN").unwrap();
+    assert!(core.get_by_locale("EN").is_none());
+    assert!(core.namespace("checkout").get_by_locale("EN").is_none());
+    assert_eq!(vec!["ru"], core.namespace("checkout").locales());
+    assert_eq!(vec!["checkout"], core.namespaces());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+
+    // Recursive scan, the namespace is taken from the subfolder.
+    let folder = std::env::temp_dir().join("sorrow_i18n_namespace_recursive");
+    std::fs::create_dir_all(folder.join("checkout")).unwrap();
+    std::fs::create_dir_all(folder.join("shop").join("cart")).unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  title: \"Shop\"\n").unwrap();
+    std::fs::write(folder.join("checkout").join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  title: \"Checkout\"\n").unwrap();
+    std::fs::write(folder.join("shop").join("cart").join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\ndata:\n  title: \"Cart\"\n").unwrap();
+    // The declared namespace is kept.
+    std::fs::write(folder.join("shop").join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\nnamespace: catalog\ndata:\n  title: \"Catalog\"\n").unwrap();
+
+    let core = InternationalCore::try_new_recursive(folder.to_str().unwrap()).unwrap();
+    assert_eq!(vec!["catalog", "checkout", "shop.cart"], core.namespaces());
+    assert_eq!("Shop", core.get_by_locale("EN").unwrap().get_or_default("data.title"));
+    assert_eq!("Checkout", core.namespace("checkout").get_by_locale("EN").unwrap().get_or_default("data.title"));
+    assert_eq!("Cart", core.namespace("shop.cart").get_by_locale("EN").unwrap().get_or_default("data.title"));
+    assert_eq!("Catalog", core.namespace("catalog").get_by_locale("EN").unwrap().get_or_default("data.title"));
+
+    // Subfolders are not scanned by default.
+    assert!(InternationalCore::new(folder.to_str().unwrap()).namespaces().is_empty());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,24 @@
+use sorrow_i18n::{GetData, InternationalCore};
+
+fn main() {
+    // Init core
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = InternationalCore::new(manifest);
+
+    // We get EN locale
+    let en = core.get_by_locale("EN").unwrap();
+
+    // Ordinal forms by english rules: data.place.one, data.place.two, data.place.few, data.place.other
+    assert_eq!("1st", en.get_ordinal("data.place", 1));
+    assert_eq!("2nd", en.get_ordinal("data.place", 2));
+    assert_eq!("3rd", en.get_ordinal("data.place", 3));
+    assert_eq!("4th", en.get_ordinal("data.place", 4));
+    assert_eq!("11th", en.get_ordinal("data.place", 11));
+    assert_eq!("12th", en.get_ordinal("data.place", 12));
+    assert_eq!("21st", en.get_ordinal("data.place", 21));
+    assert_eq!("113th", en.get_ordinal("data.place", 113));
+
+    // The key does not exist, we return the key.
+    let ru = core.get_by_locale_state("RU").unwrap();
+    assert_eq!("data.place", ru.get_ordinal("data.place", 1));
+}
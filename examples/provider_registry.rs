@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use sorrow_i18n::{Error, GetData, InternationalCore, Messages, WatchProvider};
+
+// Remote store of the messages by locale, e.g. Redis.
+type Store = Arc<Mutex<HashMap<String, HashMap<String, String>>>>;
+
+struct StoreProvider {
+    locale: String,
+    store: Store,
+}
+
+impl WatchProvider for StoreProvider {
+    fn watch(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
+        if let Some(messages) = self.store.lock().unwrap().get(&self.locale) {
+            messages.iter().for_each(|(k, v)| data.insert(k.as_str(), v.as_str()));
+        }
+        Ok(())
+    }
+}
+
+// The files select the provider by name, no add_provider call per locale.
+fn main() {
+    let folder = std::env::temp_dir().join("sorrow_i18n_provider_registry");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("I18N_EN.yaml"), "kind: I18N\nlocale: EN\nprovider: store\ndata:\n  name: \"Test\"\n").unwrap();
+    std::fs::write(folder.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\nprovider: StaticFileProvider\ndata:\n  name: \"Тест\"\n").unwrap();
+
+    let store: Store = Arc::new(Mutex::new(HashMap::from([
+        ("en".to_string(), HashMap::from([("data.title".to_string(), "Title".to_string())])),
+        ("ru".to_string(), HashMap::from([("data.title".to_string(), "Заголовок".to_string())])),
+    ])));
+
+    let mut core = InternationalCore::new(folder.to_str().unwrap());
+    // Not registered yet, the file is loaded as static.
+    assert_eq!(None, core.get_by_locale("EN").unwrap().get("data.title"));
+
+    let registered = Arc::clone(&store);
+    core.register_provider_factory("store", move |locale, _path| {
+        Ok(Box::new(StoreProvider { locale: locale.to_string(), store: Arc::clone(&registered) }))
+    }).unwrap();
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!("Title", en.get_or_default("data.title"));
+    // RU does not select the provider.
+    assert_eq!(None, core.get_by_locale("RU").unwrap().get("data.title"));
+
+    // Lazy holders create the provider on loading.
+    let mut lazy = InternationalCore::lazy(folder.to_str().unwrap()).unwrap();
+    let registered = Arc::clone(&store);
+    lazy.register_provider_factory("store", move |locale, _path| {
+        Ok(Box::new(StoreProvider { locale: locale.to_string(), store: Arc::clone(&registered) }))
+    }).unwrap();
+    assert_eq!("Title", lazy.get_by_locale("EN").unwrap().get_or_default("data.title"));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
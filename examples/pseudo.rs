@@ -0,0 +1,19 @@
+use sorrow_i18n::pseudo::{pseudo_holder, pseudo_localize, PSEUDO_LOCALE};
+use sorrow_i18n::{GetData, Holder, InternationalCore};
+
+// Pseudo-locale generated from EN, to spot truncated texts and hard-coded strings in UI.
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let mut core = InternationalCore::new(&manifest);
+    let en = Holder::new(format!("{}/I18N_EN.yaml", manifest)).unwrap();
+    core.add_locale(PSEUDO_LOCALE, pseudo_holder(&en, PSEUDO_LOCALE)).unwrap();
+
+    let pseudo = core.get_by_locale("en-XA").unwrap();
+    let name = pseudo.get_or_default("data.name");
+    println!("{}", name);
+    assert!(name.starts_with('[') && name.ends_with(']'));
+    assert_ne!(core.get_by_locale("EN").unwrap().get_or_default("data.name"), name);
+    // Placeholders and tags are kept
+    assert_eq!("[Ĥéļļö, Bob! ~~]", pseudo.get_with_args("data.greeting", &[("name", &"Bob")]));
+    assert_eq!("[<b>Ĥéļļö</b>, {name}! ~~]", pseudo_localize("<b>Hello</b>, {name}!"));
+}
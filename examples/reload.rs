@@ -0,0 +1,53 @@
+use sorrow_i18n::{GetData, Holder, InternationalCore};
+
+// Reloading files on demand, without file watching.
+fn main() {
+    // Prepare folder with static file (provider is not specified)
+    let folder = std::env::temp_dir().join("sorrow_i18n_reload");
+    std::fs::create_dir_all(&folder).unwrap();
+    let en_path = folder.join("I18N_EN.yaml");
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\ndata:\n  name: \"Test\"\n").unwrap();
+
+    // Init core
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+
+    // Change file, the file is not watched.
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\ndata:\n  name: \"Reloaded\"\n").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+
+    // Snapshot of the values
+    let mut state = core.get_by_locale_state("EN").unwrap();
+
+    // Reload one locale
+    core.reload_locale("EN").unwrap();
+    assert_eq!("Reloaded", en.get_or_default("data.name"));
+
+    // The snapshot keeps the values until refresh
+    assert_eq!("Test", state.get_or_default("data.name"));
+    assert!(state.refresh(&core));
+    assert_eq!("Reloaded", state.get_or_default("data.name"));
+
+    // Reload all locales
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\ndata:\n  name: \"Test\"\n").unwrap();
+    core.reload().unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+
+    // Invalid file, the previous data is kept.
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\ndata: [").unwrap();
+    assert!(core.reload().is_err());
+    assert_eq!("Test", en.get_or_default("data.name"));
+
+    // The locale does not exist.
+    assert!(core.reload_locale("FR").is_err());
+
+    // Holder without the core
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\ndata:\n  name: \"Test\"\n").unwrap();
+    let holder = Holder::new(en_path.to_str().unwrap()).unwrap();
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\ndata:\n  name: \"Reloaded\"\n").unwrap();
+    holder.reload().unwrap();
+    assert_eq!(Some("Reloaded".to_string()), holder.messages().get("data.name"));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,74 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use sorrow_i18n::InternationalCore;
+
+// Health endpoint reports whether the hot reload works.
+fn main() {
+    // Prepare folder
+    let folder = std::env::temp_dir().join("sorrow_i18n_reload_stats");
+    std::fs::create_dir_all(&folder).unwrap();
+    let path = folder.join("I18N_EN.yaml");
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Test\"\n").unwrap();
+
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    let stats = core.stats().remove(0);
+    assert_eq!("en", stats.locale);
+    assert_eq!(0, stats.reload.reloads);
+    assert!(stats.reload.last_reload.is_some());
+    assert!(stats.reload.parse_duration.is_some());
+    let loaded = stats.reload.last_reload.unwrap();
+
+    // Reloaded by the watcher
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Changed\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    let stats = core.stats().remove(0);
+    assert_eq!(1, stats.reload.reloads);
+    assert!(stats.reload.last_reload.unwrap() > loaded);
+
+    // Broken file, the reload is failed
+    std::fs::write(&path, "kind: I18N\nlocale: EN\ndata: [\n").unwrap();
+    sleep(Duration::from_millis(300));
+    let stats = core.stats().remove(0);
+    assert_eq!(1, stats.reload.reloads);
+    assert_eq!(1, stats.reload.errors);
+    assert!(stats.reload.last_error.is_some());
+
+    // Fixed file
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Fixed\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    core.reload().unwrap();
+    let stats = core.stats().remove(0);
+    assert_eq!(3, stats.reload.reloads);
+    assert_eq!(1, stats.reload.errors);
+    assert_eq!(None, stats.reload.last_error);
+
+    // Report of the health endpoint
+    let report = serde_json::to_value(core.stats()).unwrap();
+    assert_eq!(3, report[0]["reloads"]);
+    println!("{}", report);
+
+    // Layered holder, the statistics of the files of both folders are merged.
+    let overrides = folder.join("overrides");
+    std::fs::create_dir_all(&overrides).unwrap();
+    let override_path = overrides.join("I18N_EN.yaml");
+    std::fs::write(&override_path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  title: \"Title\"\n").unwrap();
+    let mut core = InternationalCore::new(folder.to_str().unwrap());
+    core.add_overrides(overrides.to_str().unwrap()).unwrap();
+    let stats = core.stats().remove(0);
+    assert!(stats.reload.last_reload.is_some());
+    assert_eq!((0, 0), (stats.reload.reloads, stats.reload.errors));
+
+    std::fs::write(&override_path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  title: \"Changed\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!(1, core.stats()[0].reload.reloads);
+
+    std::fs::write(&override_path, "kind: I18N\nlocale: EN\ndata: [\n").unwrap();
+    sleep(Duration::from_millis(300));
+    let stats = core.stats().remove(0);
+    assert_eq!((1, 1), (stats.reload.reloads, stats.reload.errors));
+    assert!(stats.reload.last_error.is_some());
+
+    drop(core);
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,22 @@
+use rust_embed::RustEmbed;
+use sorrow_i18n::{GetData, InternationalCore};
+
+// Init assets
+#[derive(RustEmbed)]
+#[folder = "resources/en_ru"]
+struct Locales;
+
+fn main() {
+    // Init core
+    let core = InternationalCore::from_embed::<Locales>();
+
+    // Getting EN and RU locales
+    let en = core.get_by_locale("EN").unwrap();
+    let ru = core.get_by_locale("RU").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!("Тест", ru.get_or_default("data.name"));
+
+    // Embedded holders can not be reloaded from the files.
+    assert!(core.reload().is_ok());
+    assert_eq!("Test", en.get_or_default("data.name"));
+}
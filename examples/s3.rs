@@ -0,0 +1,62 @@
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+
+use sorrow_i18n::s3::{Bucket, Credentials, Region, S3Provider};
+use sorrow_i18n::{GetData, InternationalCore};
+
+// Minimal S3-compatible endpoint, serves one object by path-style GET.
+fn serve(object: Arc<Mutex<String>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            let request = String::from_utf8_lossy(&request);
+            let response = if request.starts_with("GET /locales/I18N_EN.yaml") {
+                let body = object.lock().unwrap().clone();
+                format!("HTTP/1.1 200 OK\r\nETag: \"{}\"\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body.len(), body)
+            } else {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+            };
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    endpoint
+}
+
+fn main() {
+    let object = Arc::new(Mutex::new("kind: I18N\nlocale: EN\ndata:\n  name: \"Shared test\"\n".to_string()));
+    let endpoint = serve(Arc::clone(&object));
+    let region = Region::Custom { region: "us-east-1".to_string(), endpoint };
+    let credentials = Credentials::new(Some("access"), Some("secret"), None, None, None).unwrap();
+    let bucket = Bucket::new("locales", region, credentials).unwrap().with_path_style();
+
+    // Init core
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let mut core = InternationalCore::new(manifest);
+    let provider = S3Provider::new(bucket.clone(), "I18N_EN.yaml").interval(Duration::from_millis(50));
+    core.add_provider("EN", Box::new(provider)).unwrap();
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Shared test", en.get_or_default("data.name"));
+
+    // The catalog has been updated in the storage.
+    *object.lock().unwrap() = "kind: I18N\nlocale: EN\ndata:\n  name: \"Updated shared test\"\n".to_string();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Updated shared test", en.get_or_default("data.name"));
+
+    // Object does not exist
+    let provider = S3Provider::new(bucket, "I18N_RU.yaml");
+    assert!(core.add_provider("RU", Box::new(provider)).is_err());
+}
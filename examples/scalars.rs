@@ -0,0 +1,35 @@
+use sorrow_i18n::{GetData, Holder, InternationalCore};
+
+// Numbers and booleans are accepted without quotes.
+fn main() {
+    let data = r#"
+kind: I18N
+locale: EN
+data:
+  max_items: 3
+  ratio: 1.5
+  beta: true
+  title: "Shop"
+  versions:
+    2: "Second"
+  save:
+    value: 42
+    comment: "The answer"
+"#;
+    let holder = Holder::parse(data).unwrap();
+    let core = InternationalCore::from_holders(vec![holder]).unwrap();
+    let en = core.get_by_locale("EN").unwrap();
+
+    // Stored as text
+    assert_eq!("3", en.get_or_default("data.max_items"));
+    assert_eq!("true", en.get_or_default("data.beta"));
+    assert_eq!("Second", en.get_or_default("data.versions.2"));
+    assert_eq!("42", en.get_or_default("data.save"));
+
+    // Typed
+    assert_eq!(Some(3), en.get_as::<u32, _>("data.max_items"));
+    assert_eq!(Some(1.5), en.get_as::<f64, _>("data.ratio"));
+    assert_eq!(Some(true), en.get_as::<bool, _>("data.beta"));
+    assert_eq!(None, en.get_as::<u32, _>("data.title"));
+    assert_eq!(None, en.get_as::<u32, _>("data.unknown"));
+}
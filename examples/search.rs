@@ -0,0 +1,30 @@
+use sorrow_i18n::search::{Query, Scope};
+use sorrow_i18n::{Error, InternationalCore};
+
+// Finding the message seen on the screen, e.g. on the admin screen of the editors.
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = InternationalCore::new(manifest);
+
+    // By value, case-insensitive
+    let found = core.search(&Query::text("hello").within(Scope::Values));
+    let keys = found.iter().map(|m| format!("{}:{}", m.locale, m.key)).collect::<Vec<String>>();
+    assert_eq!(vec!["en:data.greeting", "en:data.greeting_html"], keys);
+
+    // By key
+    let found = core.search(&Query::text("data.name").within(Scope::Keys));
+    assert_eq!(vec!["ee", "en", "ru"], found.iter().map(|m| m.locale.as_str()).collect::<Vec<&str>>());
+    assert_eq!("Тест", found[2].value);
+
+    // By regular expression, the messages with placeholders
+    let query = Query::regex(r"\{\w+\}").unwrap().within(Scope::Values);
+    let found = core.search(&query);
+    assert!(found.iter().all(|m| m.value.contains('{')));
+    assert!(found.iter().any(|m| m.key == "data.greeting"));
+
+    // Single holder
+    let holder = sorrow_i18n::Holder::from_pairs("EN", [("menu.open", "Open"), ("menu.close", "Close")]);
+    assert_eq!("menu.close", holder.search(&Query::text("CLOSE"))[0].key);
+
+    assert!(matches!(Query::regex("("), Err(Error::InvalidPattern { .. })));
+}
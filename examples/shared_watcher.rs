@@ -0,0 +1,57 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use sorrow_i18n::{FileProvider, GetData, InternationalCore};
+
+// Many locales are watched by one watcher of the core, the events are dispatched to the holder of the file.
+fn main() {
+    // Prepare folders
+    let folder = std::env::temp_dir().join("sorrow_i18n_shared_watcher");
+    let other = std::env::temp_dir().join("sorrow_i18n_shared_watcher_other");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::create_dir_all(&other).unwrap();
+    for (locale, provider) in [("EN", "FileProvider"), ("RU", "FileProvider"), ("DE", "FileProvider"), ("FR", "other")] {
+        let data = format!("kind: I18N\nlocale: {}\nprovider: {}\ndata:\n  name: \"Test {}\"\n", locale, provider, locale);
+        std::fs::write(folder.join(format!("I18N_{}.yaml", locale)), data).unwrap();
+    }
+
+    let mut core = InternationalCore::new(folder.to_str().unwrap());
+    // One folder is watched for all files.
+    assert_eq!(vec![folder.canonicalize().unwrap()], core.folder_watcher().folders());
+
+    // Only the holder of the changed file is reloaded.
+    std::fs::write(folder.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\nprovider: FileProvider\ndata:\n  name: \"Тест\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Тест", core.get_by_locale("RU").unwrap().get_or_default("data.name"));
+    assert_eq!("Test EN", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+    assert_eq!("Test DE", core.get_by_locale("DE").unwrap().get_or_default("data.name"));
+
+    // The added provider is watched by the watcher of the core too.
+    let path = other.join("I18N_EN.yaml");
+    std::fs::write(&path, "kind: I18N\nlocale: EN\ndata:\n  name: \"Other\"\n").unwrap();
+    core.add_provider("EN", Box::new(FileProvider::from_path(path.to_str().unwrap()))).unwrap();
+    assert_eq!(2, core.folder_watcher().folders().len());
+    std::fs::write(&path, "kind: I18N\nlocale: EN\ndata:\n  name: \"Changed\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Changed", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+
+    // The folder is unwatched with the last holder of the folder.
+    core.remove_locale("EN").unwrap();
+    assert_eq!(vec![folder.canonicalize().unwrap()], core.folder_watcher().folders());
+
+    // The providers of the factories are watched by the watcher of the core too.
+    let path = other.join("I18N_FR.yaml");
+    std::fs::write(&path, "kind: I18N\nlocale: FR\ndata:\n  name: \"Autre\"\n").unwrap();
+    let factory_path = path.clone();
+    core.register_provider_factory("other", move |_locale, _path| {
+        Ok(Box::new(FileProvider::from_path(factory_path.to_str().unwrap())))
+    }).unwrap();
+    assert_eq!(2, core.folder_watcher().folders().len());
+    std::fs::write(&path, "kind: I18N\nlocale: FR\ndata:\n  name: \"Changé\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Changé", core.get_by_locale("FR").unwrap().get_or_default("data.name"));
+
+    drop(core);
+    std::fs::remove_dir_all(&folder).unwrap();
+    std::fs::remove_dir_all(&other).unwrap();
+}
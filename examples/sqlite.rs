@@ -0,0 +1,40 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use rusqlite::Connection;
+use sorrow_i18n::sqlite::SqliteProvider;
+use sorrow_i18n::{GetData, InternationalCore};
+
+fn main() {
+    // Prepare database
+    let path = std::env::temp_dir().join("sorrow_i18n_sqlite.db");
+    let _ = std::fs::remove_file(&path);
+    let db = Connection::open(&path).unwrap();
+    db.execute_batch("CREATE TABLE i18n (locale TEXT NOT NULL, key TEXT NOT NULL, value TEXT NOT NULL);
+        INSERT INTO i18n VALUES ('en', 'data.name', 'Database test');
+        INSERT INTO i18n VALUES ('RU', 'data.name', 'Тест из базы');").unwrap();
+
+    // Init core
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let mut core = InternationalCore::new(manifest);
+    let provider = SqliteProvider::new(path.to_str().unwrap(), "EN").interval(Duration::from_millis(50));
+    core.add_provider("EN", Box::new(provider)).unwrap();
+
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Database test", en.get_or_default("data.name"));
+    // Rows replace the data of the holder.
+    assert_eq!(None, en.get("data.greeting"));
+
+    // Ops edit the string in the database.
+    db.execute("UPDATE i18n SET value = 'Edited test' WHERE locale = 'en'", []).unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Edited test", en.get_or_default("data.name"));
+
+    // Table does not exist
+    let provider = SqliteProvider::new(path.to_str().unwrap(), "RU").table("unknown");
+    assert!(core.add_provider("RU", Box::new(provider)).is_err());
+
+    drop(core);
+    drop(en);
+    std::fs::remove_file(&path).unwrap();
+}
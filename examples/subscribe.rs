@@ -0,0 +1,51 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use sorrow_i18n::metrics::Metrics;
+use sorrow_i18n::{Error, InternationalCore};
+
+#[derive(Default)]
+struct Reloads(AtomicUsize);
+
+impl Metrics for Reloads {
+    fn lookup(&self, _locale: &str, _found: bool) {}
+
+    fn reload(&self, _locale: &str, _error: Option<&Error>) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+// UI layers re-render only when translations actually change.
+fn main() {
+    // Prepare folder
+    let folder = std::env::temp_dir().join("sorrow_i18n_subscribe");
+    std::fs::create_dir_all(&folder).unwrap();
+    let path = folder.join("I18N_EN.yaml");
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Test\"\n  title: \"Title\"\n").unwrap();
+
+    let mut core = InternationalCore::new(folder.to_str().unwrap());
+    let events = core.subscribe();
+    let reloads = Arc::new(Reloads::default());
+    core.set_metrics(Arc::clone(&reloads));
+
+    // File has been changed by the user.
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Changed\"\n  title: \"Title\"\n  subtitle: \"Subtitle\"\n").unwrap();
+    let event = events.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!("en", event.locale);
+    assert_eq!(vec!["data.name", "data.subtitle"], event.keys);
+
+    // File has been saved by the editor without changes, no reload and no event.
+    let reloaded = reloads.0.load(Ordering::Relaxed);
+    let data = std::fs::read(&path).unwrap();
+    std::fs::write(&path, &data).unwrap();
+    assert!(events.recv_timeout(Duration::from_millis(300)).is_err());
+    assert_eq!(reloaded, reloads.0.load(Ordering::Relaxed));
+
+    // Manual reload without changes, no event.
+    core.reload().unwrap();
+    assert!(events.recv_timeout(Duration::from_millis(300)).is_err());
+
+    drop(core);
+    std::fs::remove_dir_all(&folder).unwrap();
+}
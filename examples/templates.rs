@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use sorrow_i18n::InternationalCore;
+use sorrow_i18n::locale::LocaleGuard;
+
+// Translation inline in the server-rendered templates of Tera and Handlebars.
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = Arc::new(InternationalCore::new(manifest));
+
+    // Tera
+    let mut tera = tera::Tera::default();
+    sorrow_i18n::tera::register(&mut tera, Arc::clone(&core));
+    tera.add_raw_template("hello", r#"<h1>{{ t(key="data.name", locale=locale) }}</h1><p>{{ t(key="data.greeting", name=user, locale=locale) }}</p>"#).unwrap();
+    let mut context = tera::Context::new();
+    context.insert("user", "Bob");
+    context.insert("locale", "en");
+    assert_eq!("<h1>Test</h1><p>Hello, Bob!</p>", tera.render("hello", &context).unwrap());
+    context.insert("locale", "ru");
+    assert_eq!("<h1>Тест</h1><p>data.greeting</p>", tera.render("hello", &context).unwrap());
+
+    // Without the locale argument, the current locale of the thread is used.
+    tera.add_raw_template("name", r#"{{ t(key="data.name") }}"#).unwrap();
+    let guard = LocaleGuard::new("ru");
+    assert_eq!("Тест", tera.render("name", &tera::Context::new()).unwrap());
+    drop(guard);
+    // The key is required.
+    tera.add_raw_template("invalid", "{{ t(name=user) }}").unwrap();
+    assert!(tera.render("invalid", &context).is_err());
+
+    // Handlebars, the locale is the field of the rendered data.
+    let mut handlebars = handlebars::Handlebars::new();
+    sorrow_i18n::handlebars::register(&mut handlebars, Arc::clone(&core));
+    handlebars.register_template_string("hello", "<h1>{{t \"data.name\"}}</h1><p>{{t \"data.greeting\" name=user}}</p>").unwrap();
+    let html = handlebars.render("hello", &serde_json::json!({"user": "Bob", "locale": "en"})).unwrap();
+    assert_eq!("<h1>Test</h1><p>Hello, Bob!</p>", html);
+    let html = handlebars.render("hello", &serde_json::json!({"user": "Bob", "locale": "ru"})).unwrap();
+    assert_eq!("<h1>Тест</h1><p>data.greeting</p>", html);
+    // The locale argument overrides the field.
+    handlebars.register_template_string("name", "{{t \"data.name\" locale=\"ru\"}}").unwrap();
+    assert_eq!("Тест", handlebars.render("name", &serde_json::json!({"locale": "en"})).unwrap());
+}
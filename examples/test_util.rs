@@ -0,0 +1,24 @@
+use sorrow_i18n::feature_macro::{replace, reset, set_current_locale, DEFAULT_CORE};
+use sorrow_i18n::{i18n, init_i18n, t, InternationalCore};
+
+// Tests with the different fixtures of the static core, in one process.
+fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    init_i18n!(manifest.as_str()).unwrap();
+    assert_eq!("Тест", i18n!("RU", "data.name"));
+
+    // Init again after reset
+    set_current_locale("RU");
+    reset();
+    assert_eq!("data.name", i18n!("RU", "data.name"));
+    init_i18n!(manifest.as_str()).unwrap();
+    assert_eq!("Test", i18n!("EN", "data.name"));
+
+    // Fixture core, the previous core is dropped
+    replace(DEFAULT_CORE, InternationalCore::builder().insert("EN", "data.name", "Fixture").build());
+    assert_eq!("Fixture", i18n!("EN", "data.name"));
+    replace("ui", InternationalCore::builder().insert("EN", "menu.open", "Open").build());
+    assert_eq!("Open", i18n!(core = "ui", "EN", "menu.open"));
+    set_current_locale("EN");
+    assert_eq!("Open", t!(core = "ui", "menu.open"));
+}
@@ -0,0 +1,60 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use sorrow_i18n::metrics::Metrics;
+use sorrow_i18n::{Error, GetData, InternationalCore};
+
+#[derive(Default)]
+struct Reloads(Mutex<Vec<bool>>);
+
+impl Metrics for Reloads {
+    fn lookup(&self, _locale: &str, _found: bool) {}
+
+    fn reload(&self, _locale: &str, error: Option<&Error>) {
+        self.0.lock().unwrap().push(error.is_none());
+    }
+}
+
+// Watching and reloading the files on the tokio runtime.
+#[tokio::main]
+async fn main() {
+    // Prepare folder with watched file
+    let folder = std::env::temp_dir().join("sorrow_i18n_tokio");
+    std::fs::create_dir_all(&folder).unwrap();
+    let en_path = folder.join("I18N_EN.yaml");
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Test\"\n").unwrap();
+
+    // Init core, the file watchers are replaced by the tasks of the runtime.
+    let mut core = InternationalCore::new(folder.to_str().unwrap());
+    let reloads = Arc::new(Reloads::default());
+    core.set_metrics(Arc::clone(&reloads));
+    core.watch_on_runtime(tokio::runtime::Handle::current(), Duration::from_millis(50)).unwrap();
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+
+    // Change file, the task reloads it.
+    tokio::fs::write(&en_path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Changed\"\n").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!("Changed", en.get_or_default("data.name"));
+    assert_eq!(1, core.stats()[0].reload.reloads);
+
+    // Async reload on demand
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Test\"\n").unwrap();
+    core.reload_async().await.unwrap();
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!(vec![true, true], *reloads.0.lock().unwrap());
+
+    // Invalid file, the previous data is kept.
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\ndata: [").unwrap();
+    assert!(core.reload_async().await.is_err());
+    assert_eq!("Test", en.get_or_default("data.name"));
+
+    // File without data, e.g. truncated while it is being written, the previous data is kept.
+    std::fs::write(&en_path, "kind: I18N\nlocale: EN\n").unwrap();
+    assert!(matches!(core.reload_async().await, Err(Error::EmptyReload { .. })));
+    assert_eq!("Test", en.get_or_default("data.name"));
+    assert_eq!(vec![true, true, false, false], *reloads.0.lock().unwrap());
+    let stats = &core.stats()[0].reload;
+    assert_eq!((2, 2), (stats.reloads, stats.errors));
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,86 @@
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+
+use sorrow_i18n::{GetData, InternationalCore};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Names of the spans and messages of the events, e.g. exported to the distributed traces by your subscriber.
+#[derive(Default)]
+struct Records {
+    spans: Mutex<Vec<String>>,
+    events: Mutex<Vec<String>>,
+    next: AtomicU64,
+}
+
+struct Recorder(Arc<Records>);
+
+struct Message(String);
+
+impl Visit for Message {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{:?}", value);
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.0.spans.lock().unwrap().push(span.metadata().name().to_string());
+        Id::from_u64(self.0.next.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut message = Message(String::new());
+        event.record(&mut message);
+        self.0.events.lock().unwrap().push(message.0);
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+// The loads, reloads, watcher events and missing keys are traced, feature `tracing`.
+fn main() {
+    let records = Arc::new(Records::default());
+    tracing::subscriber::set_global_default(Recorder(Arc::clone(&records))).unwrap();
+
+    // Prepare folder
+    let folder = std::env::temp_dir().join("sorrow_i18n_tracing");
+    std::fs::create_dir_all(&folder).unwrap();
+    let path = folder.join("I18N_EN.yaml");
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Test\"\n").unwrap();
+
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    assert!(records.spans.lock().unwrap().contains(&"i18n.load".to_string()));
+
+    // Missing key
+    let en = core.get_by_locale("EN").unwrap();
+    assert_eq!("data.title", en.get_or_default("data.title"));
+    assert!(records.events.lock().unwrap().contains(&"missing key".to_string()));
+
+    // Reload by the watcher
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Changed\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Changed", en.get_or_default("data.name"));
+    assert!(records.spans.lock().unwrap().contains(&"i18n.reload".to_string()));
+    assert!(records.events.lock().unwrap().contains(&"watch event".to_string()));
+    assert!(records.events.lock().unwrap().contains(&"done".to_string()));
+
+    drop(core);
+    std::fs::remove_dir_all(&folder).unwrap();
+}
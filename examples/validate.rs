@@ -0,0 +1,48 @@
+use sorrow_i18n::check::DiagnosticKind;
+use sorrow_i18n::InternationalCore;
+
+// Linting the catalogs, all problems are reported at once.
+fn main() {
+    let folder = std::env::temp_dir().join("sorrow_i18n_validate");
+    std::fs::create_dir_all(&folder).unwrap();
+    let en = folder.join("I18N_EN.yaml");
+    std::fs::write(&en, "kind: I18N\nlocale: EN\ndata:\n  name: \"Test\"\n  title: \"\"\n  greeting: \"Hello, {name}! \"\n  save:\n    value: \"Save\"\n    max_len: 6\n").unwrap();
+    std::fs::write(folder.join("I18N_RU.yaml"), "kind: I18N\nlocale: RU\ndata:\n  name: \"Тест\"\n  greeting: \"Привет, {}!\"\n  save: \"Сохранить\"\n").unwrap();
+
+    let core = InternationalCore::new(folder.to_str().unwrap());
+    let diagnostics = core.validate();
+    for diagnostic in diagnostics.iter() {
+        println!("{}", diagnostic);
+    }
+    let found = diagnostics.iter()
+        .map(|d| (d.locale.as_str(), d.key.as_deref().unwrap_or_default(), d.kind.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        ("en", "data.greeting", DiagnosticKind::SuspiciousWhitespace),
+        ("en", "data.title", DiagnosticKind::EmptyValue),
+        ("ru", "data.greeting", DiagnosticKind::InvalidPlaceholder { position: 14 }),
+        // `max_len` of the English message applies to the translation.
+        ("ru", "data.save", DiagnosticKind::TooLong { len: 9, max_len: 6 }),
+    ], found);
+
+    // Placeholders of the translations are compared with the base locale.
+    let mismatches = core.check_placeholders("EN").unwrap();
+    for diagnostic in mismatches.iter() {
+        println!("{}", diagnostic);
+    }
+    assert_eq!(1, mismatches.len());
+    assert_eq!(Some("data.greeting"), mismatches[0].key.as_deref());
+    assert_eq!(DiagnosticKind::PlaceholderMismatch {
+        base: "en".to_string(),
+        missing: vec!["name".to_string()],
+        extra: vec!["".to_string()],
+    }, mismatches[0].kind);
+
+    // The source file is checked as well.
+    std::fs::write(&en, "kind: I18N_DRAFT\nlocale: EN\ndata:\n  name: \"Test\"\n").unwrap();
+    let diagnostics = core.validate();
+    assert_eq!(DiagnosticKind::InvalidKind { kind: "I18N_DRAFT".to_string() }, diagnostics[0].kind);
+    assert_eq!(None, diagnostics[0].key);
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
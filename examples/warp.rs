@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use sorrow_i18n::warp::{locale, localized, Locale};
+use sorrow_i18n::{Data, GetData, InternationalCore};
+use warp::Filter;
+
+// Filters pass the data by the locale of the request to the handlers.
+#[tokio::main]
+async fn main() {
+    let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+    let core = Arc::new(InternationalCore::new(manifest));
+    let welcome = warp::path("welcome")
+        .and(localized(Arc::clone(&core)))
+        .map(|msgs: Data| msgs.get_or_default("data.name"));
+    let current = warp::path("locale")
+        .and(locale(Arc::clone(&core)))
+        .map(|Locale(locale)| locale);
+    let routes = welcome.or(current);
+
+    let response = warp::test::request().path("/welcome").header("Accept-Language", "ru-RU, en;q=0.8").reply(&routes).await;
+    assert_eq!("Тест", response.body());
+    let response = warp::test::request().path("/welcome").header("Accept-Language", "de, en-GB;q=0.7").reply(&routes).await;
+    assert_eq!("Test", response.body());
+    let response = warp::test::request().path("/locale").header("Accept-Language", "de, en-GB;q=0.7").reply(&routes).await;
+    assert_eq!("en-GB", response.body());
+}
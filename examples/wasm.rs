@@ -0,0 +1,21 @@
+use include_dir::{include_dir, Dir};
+use sorrow_i18n::{GetData, Holder, InternationalCore};
+
+// Embedded and in-memory catalogs, without file watching and file IO.
+// The same code builds for wasm32-unknown-unknown with `default-features = false`, see README.
+const PROJECT_DIR: Dir = include_dir!("resources/en_ru");
+
+fn main() {
+    let mut core = InternationalCore::try_from_dir(PROJECT_DIR).unwrap();
+    assert_eq!("Тест", core.get_by_locale("RU").unwrap().get_or_default("data.name"));
+
+    // Catalog fetched by the application, e.g. by `fetch` in the browser
+    let de = Holder::parse(r#"{"kind": "I18N", "locale": "DE", "data": {"name": "Prüfung"}}"#).unwrap();
+    core.add_locale("DE", de).unwrap();
+    assert_eq!("Prüfung", core.get_by_locale("de").unwrap().get_or_default("data.name"));
+
+    let core = InternationalCore::builder()
+        .insert("EN", "data.name", "Test")
+        .build();
+    assert_eq!(Some("Test".to_string()), core.get_by_locale("EN").unwrap().get("data.name"));
+}
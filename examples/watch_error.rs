@@ -0,0 +1,61 @@
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+
+use sorrow_i18n::{Error, GetData, InternationalCore};
+
+// The application is informed when the watched file cannot be reloaded.
+fn main() {
+    // Prepare folder
+    let folder = std::env::temp_dir().join("sorrow_i18n_watch_error");
+    std::fs::create_dir_all(&folder).unwrap();
+    let path = folder.join("I18N_EN.yaml");
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Test\"\n").unwrap();
+
+    let mut core = InternationalCore::new(folder.to_str().unwrap());
+    let errors = Arc::new(Mutex::new(vec![]));
+    let received = Arc::clone(&errors);
+    core.on_watch_error(move |e| {
+        match e {
+            Error::InvalidStructure { path, .. } | Error::EmptyReload { path } | Error::WatchError { path, .. } => received.lock().unwrap().push(path.clone()),
+            _ => {}
+        }
+    });
+
+    // Broken file
+    std::fs::write(&path, "kind: I18N\nlocale: EN\ndata: [\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert!(!errors.lock().unwrap().is_empty());
+    // The last loaded data is kept.
+    assert_eq!("Test", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+
+    // Half-written file without the data, the last loaded data is kept.
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert!(errors.lock().unwrap().len() >= 2);
+    assert_eq!("Test", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+
+    // Fixed file
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Fixed\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Fixed", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+
+    // Deleted file, the last loaded data is kept.
+    errors.lock().unwrap().clear();
+    std::fs::remove_file(&path).unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!(1, errors.lock().unwrap().len());
+    assert_eq!("Fixed", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+
+    // The file is created again, the watching is continued.
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Restored\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Restored", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+    std::fs::write(&path, "kind: I18N\nlocale: EN\nprovider: FileProvider\ndata:\n  name: \"Changed\"\n").unwrap();
+    sleep(Duration::from_millis(300));
+    assert_eq!("Changed", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+    assert_eq!(1, errors.lock().unwrap().len());
+
+    drop(core);
+    std::fs::remove_dir_all(&folder).unwrap();
+}
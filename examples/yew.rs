@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use sorrow_i18n::yew::{use_i18n, I18nProvider};
+use sorrow_i18n::InternationalCore;
+use yew::prelude::*;
+
+#[function_component]
+fn Greeting() -> Html {
+    let i18n = use_i18n();
+    let switch = {
+        let i18n = i18n.clone();
+        Callback::from(move |_| i18n.set_locale("ru"))
+    };
+    html! { <button onclick={switch}>{ i18n.t("data.name") }</button> }
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    locale: AttrValue,
+}
+
+#[function_component]
+fn App(props: &AppProps) -> Html {
+    let core = use_memo((), |_| {
+        let manifest = format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/resources/en_ru");
+        Arc::new(InternationalCore::new(manifest))
+    });
+    html! {
+        <I18nProvider core={Arc::clone(&core)} locale={props.locale.clone()}>
+            <Greeting />
+        </I18nProvider>
+    }
+}
+
+// Components receive messages by the active locale, rendered on the server there.
+#[tokio::main]
+async fn main() {
+    let html = yew::ServerRenderer::<App>::with_props(|| AppProps { locale: "en".into() }).render().await;
+    assert!(html.contains(">Test</button>"));
+    let html = yew::ServerRenderer::<App>::with_props(|| AppProps { locale: "ru".into() }).render().await;
+    assert!(html.contains(">Тест</button>"));
+}
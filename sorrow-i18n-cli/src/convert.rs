@@ -0,0 +1,41 @@
+//! `convert` command.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use crate::format::{self, CatalogFormat};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Source catalog
+    input: PathBuf,
+    /// Converted catalog
+    output: PathBuf,
+    /// Format of the source, by default by the extension
+    #[arg(long, value_enum)]
+    from: Option<CatalogFormat>,
+    /// Format of the result, by default by the extension
+    #[arg(long, value_enum)]
+    to: Option<CatalogFormat>,
+    /// Locale of the flat source (`.properties`, PO), by default from the file
+    #[arg(long)]
+    locale: Option<String>,
+}
+
+/// Read the catalog in one format and write it in another one, e.g. `.properties` to the file structure YAML.
+pub fn run(args: Args) -> ExitCode {
+    match convert(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn convert(args: &Args) -> Result<(), String> {
+    let from = CatalogFormat::of(&args.input, args.from)?;
+    let to = CatalogFormat::of(&args.output, args.to)?;
+    let holder = format::read(&args.input, from, args.locale.as_deref())?;
+    format::write(&holder, &args.output, to)
+}
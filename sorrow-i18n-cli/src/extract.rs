@@ -0,0 +1,152 @@
+//! `extract` command.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use sorrow_i18n::Holder;
+
+use crate::format::{self, CatalogFormat};
+
+/// Methods of [sorrow_i18n::GetData], the first argument is the key.
+const METHODS: [&str; 4] = ["get_or_default", "get_with_args", "get_html", "get_ordinal"];
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Folder of the sources, e.g. the crate root. `target` and hidden folders are skipped
+    dir: PathBuf,
+    /// Locale of the skeleton
+    #[arg(long, default_value = "en")]
+    locale: String,
+    /// Skeleton catalog, by default YAML to stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Format of the skeleton, by default by the extension
+    #[arg(long, value_enum)]
+    to: Option<CatalogFormat>,
+}
+
+/// Scan `.rs` files for the string literal keys of `i18n!`, `t!` and `get_or_default` (and other lookups of [METHODS]),
+/// then write the skeleton catalog with empty messages for all found keys.
+pub fn run(args: Args) -> ExitCode {
+    match extract(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn extract(args: &Args) -> Result<(), String> {
+    let mut files = Vec::new();
+    sources(&args.dir, &mut files)?;
+    files.sort();
+    let mut keys = BTreeSet::new();
+    for file in &files {
+        let source = std::fs::read_to_string(file).map_err(|e| format!("{}: {}", file.display(), e))?;
+        let tokens = TokenStream::from_str(&source).map_err(|e| format!("{}: {}", file.display(), e))?;
+        let mut found = Vec::new();
+        scan(tokens, &mut found);
+        keys.extend(found);
+    }
+    eprintln!("{} keys found in {} files.", keys.len(), files.len());
+
+    let holder = Holder::from_map(&args.locale, keys.into_iter().map(|k| (k, String::new())).collect::<HashMap<String, String>>());
+    match &args.output {
+        None => {
+            print!("{}", holder.to_yaml().map_err(|e| e.to_string())?);
+            Ok(())
+        }
+        Some(output) => format::write(&holder, output, CatalogFormat::of(output, args.to)?),
+    }
+}
+
+/// Collect `.rs` files of the folder recursively.
+fn sources(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| format!("{}: {}", dir.display(), e))?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        if path.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                sources(&path, files)?;
+            }
+        } else if name.ends_with(".rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Find the keys in the tokens, including the bodies of other macros (e.g. `format!`).
+fn scan(tokens: TokenStream, keys: &mut Vec<String>) {
+    let tokens = tokens.into_iter().collect::<Vec<TokenTree>>();
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Ident(ident) => {
+                let next = (tokens.get(i + 1), tokens.get(i + 2));
+                if ident == "i18n" {
+                    // i18n!("key") or i18n!("locale", "key"), the core can be selected by `core = "name"`
+                    if let (Some(TokenTree::Punct(p)), Some(TokenTree::Group(g))) = next {
+                        if p.as_char() == '!' {
+                            let args = macro_args(g.stream());
+                            if let Some(Some(key)) = args.last().filter(|_| args.len() <= 2) {
+                                keys.push(key.clone());
+                            }
+                        }
+                    }
+                } else if ident == "t" {
+                    // t!("key") or t!("key", name = value), the core can be selected by `core = "name"`
+                    if let (Some(TokenTree::Punct(p)), Some(TokenTree::Group(g))) = next {
+                        if p.as_char() == '!' {
+                            if let Some(Some(key)) = macro_args(g.stream()).first() {
+                                keys.push(key.clone());
+                            }
+                        }
+                    }
+                } else if METHODS.iter().any(|m| ident == m) && i > 0 && matches!(&tokens[i - 1], TokenTree::Punct(p) if p.as_char() == '.') {
+                    if let (Some(TokenTree::Group(g)), _) = next {
+                        if g.delimiter() == Delimiter::Parenthesis {
+                            if let Some(Some(key)) = string_args(g.stream()).first() {
+                                keys.push(key.clone());
+                            }
+                        }
+                    }
+                }
+            }
+            TokenTree::Group(group) => scan(group.stream(), keys),
+            _ => {}
+        }
+    }
+}
+
+/// Arguments of the macro, without the leading `core = "name"`.
+fn macro_args(tokens: TokenStream) -> Vec<Option<String>> {
+    let named = matches!(
+        tokens.clone().into_iter().take(2).collect::<Vec<TokenTree>>().as_slice(),
+        [TokenTree::Ident(ident), TokenTree::Punct(p)] if ident == "core" && p.as_char() == '='
+    );
+    let mut args = string_args(tokens);
+    if named && !args.is_empty() {
+        args.remove(0);
+    }
+    args
+}
+
+/// Arguments separated by `,`, the value is `Some` if the argument is the string literal.
+fn string_args(tokens: TokenStream) -> Vec<Option<String>> {
+    let tokens = tokens.into_iter().collect::<Vec<TokenTree>>();
+    tokens.split(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == ','))
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| match arg {
+            [TokenTree::Literal(lit)] => match syn::Lit::new(lit.clone()) {
+                syn::Lit::Str(s) => Some(s.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
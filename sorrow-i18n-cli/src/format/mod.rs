@@ -0,0 +1,99 @@
+//! Formats of the catalogs. YAML and JSON are the file structure of the crate, see [sorrow_i18n::FileStructure],
+//! `.properties` and PO are flat catalogs of one locale.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use clap::ValueEnum;
+use sorrow_i18n::Holder;
+
+mod po;
+mod properties;
+
+/// Format of the catalog file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CatalogFormat {
+    /// File structure in YAML
+    Yaml,
+    /// File structure in JSON
+    Json,
+    /// Java properties, `key=value`
+    Properties,
+    /// Gettext PO, `msgid` is the key
+    Po,
+}
+
+impl CatalogFormat {
+    /// Format by the file extension.
+    pub fn detect(path: &Path) -> Option<CatalogFormat> {
+        match path.extension()?.to_str()? {
+            "yaml" | "yml" => Some(CatalogFormat::Yaml),
+            "json" => Some(CatalogFormat::Json),
+            "properties" => Some(CatalogFormat::Properties),
+            "po" | "pot" => Some(CatalogFormat::Po),
+            _ => None,
+        }
+    }
+
+    /// `format` or the format by the file extension.
+    pub fn of(path: &Path, format: Option<CatalogFormat>) -> Result<CatalogFormat, String> {
+        format.or_else(|| CatalogFormat::detect(path))
+            .ok_or_else(|| format!("{}: unknown format, use --from or --to", path.display()))
+    }
+}
+
+/// Flat catalog of the formats without the file structure.
+pub struct Flat {
+    /// Locale from the file, if it is specified
+    pub locale: Option<String>,
+    pub messages: HashMap<String, String>,
+}
+
+/// Read the catalog. The locale of the flat formats is taken from `locale`, the file
+/// (`Language` header of PO) or the file name (`messages_ru.properties`), in this order.
+/// The file structure has its own locale, `locale` is ignored.
+pub fn read(path: &Path, format: CatalogFormat, locale: Option<&str>) -> Result<Holder, String> {
+    let data = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let flat = match format {
+        CatalogFormat::Yaml | CatalogFormat::Json => {
+            return Holder::parse(&data).map_err(|e| format!("{}: {}", path.display(), e));
+        }
+        CatalogFormat::Properties => properties::parse(&data),
+        CatalogFormat::Po => po::parse(&data),
+    }.map_err(|e| format!("{}:{}", path.display(), e))?;
+    let locale = locale.map(|l| l.to_string())
+        .or(flat.locale)
+        .or_else(|| locale_of_file_name(path))
+        .ok_or_else(|| format!("{}: locale is not specified, use --locale", path.display()))?;
+    Ok(Holder::from_map(&locale, flat.messages))
+}
+
+/// Write the catalog.
+pub fn write(holder: &Holder, path: &Path, format: CatalogFormat) -> Result<(), String> {
+    let data = match format {
+        CatalogFormat::Yaml => holder.to_yaml().map_err(|e| e.to_string())?,
+        CatalogFormat::Json => holder.to_json().map_err(|e| e.to_string())? + "\n",
+        CatalogFormat::Properties => properties::write(holder),
+        CatalogFormat::Po => po::write(holder),
+    };
+    std::fs::write(path, data).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Locale by the suffix of the file name, `messages_pt_BR.properties` is `pt_BR`.
+fn locale_of_file_name(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    let mut parts = stem.split('_').skip(1).collect::<Vec<&str>>();
+    parts.retain(|p| !p.is_empty());
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("_"))
+    }
+}
+
+/// Keys of the holder, sorted.
+fn sorted(holder: &Holder) -> Vec<(String, String)> {
+    let mut messages = holder.messages().to_map().into_iter().collect::<Vec<(String, String)>>();
+    messages.sort();
+    messages
+}
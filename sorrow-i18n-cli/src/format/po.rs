@@ -0,0 +1,143 @@
+//! Gettext PO. The `msgid` is the key and the `msgstr` is the message, the locale is the `Language` header.
+//! The `msgctxt` is added to the key: `key@context`, see [sorrow_i18n::context_key].
+//! Entries without translation are skipped, plural forms are not supported.
+
+use std::collections::HashMap;
+
+use sorrow_i18n::{context_key, Holder, CONTEXT_SEPARATOR};
+
+use super::{sorted, Flat};
+
+/// Field of the entry, the value is continued by the following string lines.
+#[derive(PartialEq)]
+enum Field {
+    None,
+    Context,
+    Id,
+    Str,
+}
+
+pub fn parse(data: &str) -> Result<Flat, String> {
+    let mut entries = Vec::new();
+    let mut context: Option<String> = None;
+    let mut id: Option<String> = None;
+    let mut message: Option<String> = None;
+    let mut field = Field::None;
+    for (number, line) in data.lines().enumerate() {
+        let error = |e: String| format!("{}: {}", number + 1, e);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("msgctxt ") {
+            if let Some(id) = id.take() {
+                entries.push((id, message.take().unwrap_or_default()));
+            }
+            context = Some(string(rest).map_err(error)?);
+            field = Field::Context;
+        } else if let Some(rest) = line.strip_prefix("msgid ") {
+            if field != Field::Context {
+                if let Some(id) = id.take() {
+                    entries.push((id, message.take().unwrap_or_default()));
+                }
+            }
+            let value = string(rest).map_err(error)?;
+            id = Some(match context.take() {
+                None => value,
+                Some(context) => context_key(&value, &context),
+            });
+            field = Field::Id;
+        } else if let Some(rest) = line.strip_prefix("msgstr ") {
+            if id.is_none() {
+                return Err(error("msgstr without msgid".to_string()));
+            }
+            message = Some(string(rest).map_err(error)?);
+            field = Field::Str;
+        } else if line.starts_with('"') {
+            let value = string(line).map_err(error)?;
+            match field {
+                Field::Context => context.get_or_insert_with(String::new).push_str(&value),
+                Field::Id => id.get_or_insert_with(String::new).push_str(&value),
+                Field::Str => message.get_or_insert_with(String::new).push_str(&value),
+                Field::None => return Err(error("string without msgid or msgstr".to_string())),
+            }
+        } else {
+            let keyword = line.split_whitespace().next().unwrap_or_default();
+            return Err(error(format!("{} is not supported", keyword)));
+        }
+    }
+    if let Some(id) = id.take() {
+        entries.push((id, message.take().unwrap_or_default()));
+    }
+
+    let mut locale = None;
+    let mut messages = HashMap::new();
+    for (id, message) in entries {
+        if id.is_empty() {
+            locale = message.lines()
+                .find_map(|l| l.strip_prefix("Language:"))
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty());
+        } else if !message.is_empty() {
+            messages.insert(id, message);
+        }
+    }
+    Ok(Flat { locale, messages })
+}
+
+pub fn write(holder: &Holder) -> String {
+    let mut out = String::new();
+    if let Some(description) = holder.description() {
+        out.push_str(&format!("# {}\n", description));
+    }
+    out.push_str("msgid \"\"\nmsgstr \"\"\n");
+    out.push_str(&format!("\"Language: {}\\n\"\n", holder.locale()));
+    out.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    for (key, value) in sorted(holder) {
+        match key.rsplit_once(CONTEXT_SEPARATOR) {
+            None => out.push_str(&format!("\nmsgid {}\nmsgstr {}\n", quote(&key), quote(&value))),
+            Some((key, context)) => out.push_str(&format!("\nmsgctxt {}\nmsgid {}\nmsgstr {}\n", quote(context), quote(key), quote(&value))),
+        }
+    }
+    out
+}
+
+/// Value of the quoted C string.
+fn string(quoted: &str) -> Result<String, String> {
+    let inner = quoted.trim()
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| format!("expected quoted string, found {}", quoted))?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some(c) => out.push(c),
+            None => return Err(format!("unterminated escape in {}", quoted)),
+        }
+    }
+    Ok(out)
+}
+
+fn quote(value: &str) -> String {
+    let mut out = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
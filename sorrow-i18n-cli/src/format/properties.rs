@@ -0,0 +1,112 @@
+//! Java `.properties`: `key=value` (or `key: value`, `key value`), `#` and `!` comments,
+//! `\` at the end of the line continues the value. The files are read and written in UTF-8.
+
+use std::collections::HashMap;
+
+use sorrow_i18n::Holder;
+
+use super::{sorted, Flat};
+
+pub fn parse(data: &str) -> Result<Flat, String> {
+    let mut messages = HashMap::new();
+    let mut lines = data.lines().enumerate();
+    while let Some((number, line)) = lines.next() {
+        let line = line.trim_start();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+        let mut logical = line.to_string();
+        while ends_with_continuation(&logical) {
+            logical.pop();
+            match lines.next() {
+                Some((_, next)) => logical.push_str(next.trim_start()),
+                None => break,
+            }
+        }
+        let (key, value) = split(&logical);
+        let key = unescape(key).map_err(|e| format!("{}: {}", number + 1, e))?;
+        let value = unescape(value).map_err(|e| format!("{}: {}", number + 1, e))?;
+        messages.insert(key, value);
+    }
+    Ok(Flat { locale: None, messages })
+}
+
+pub fn write(holder: &Holder) -> String {
+    let mut out = String::new();
+    if let Some(description) = holder.description() {
+        out.push_str(&format!("# {}\n", description));
+    }
+    for (key, value) in sorted(holder) {
+        out.push_str(&format!("{}={}\n", escape(&key, true), escape(&value, false)));
+    }
+    out
+}
+
+/// Odd count of trailing `\`.
+fn ends_with_continuation(line: &str) -> bool {
+    line.chars().rev().take_while(|c| *c == '\\').count() % 2 == 1
+}
+
+/// Split the line by the first unescaped `=`, `:` or whitespace.
+fn split(line: &str) -> (&str, &str) {
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '=' || c == ':' || c.is_whitespace() {
+            let rest = line[i..].trim_start();
+            let rest = if !c.is_whitespace() || rest.starts_with(['=', ':']) { rest[1..].trim_start() } else { rest };
+            return (&line[..i], rest);
+        }
+    }
+    (line, "")
+}
+
+fn unescape(value: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('f') => out.push('\u{c}'),
+            Some('u') => {
+                let code = chars.by_ref().take(4).collect::<String>();
+                let c = u32::from_str_radix(&code, 16).ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("invalid escape \\u{}", code))?;
+                out.push(c);
+            }
+            Some(c) => out.push(c),
+            None => {}
+        }
+    }
+    Ok(out)
+}
+
+/// Escape the special chars, the spaces of the key and the leading space of the value.
+fn escape(value: &str, key: bool) -> String {
+    let mut out = String::with_capacity(value.len());
+    for (i, c) in value.chars().enumerate() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            '=' | ':' | '#' | '!' if key => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' ' if key || i == 0 => out.push_str("\\ "),
+            c => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,54 @@
+//! Command line tool for the catalogs of sorrow-i18n.
+//!
+//! ```text
+//! simple-i18n validate locale/
+//! simple-i18n convert messages_ru.properties locale/I18N_RU.yaml
+//! simple-i18n merge auth/I18N_RU.yaml billing/I18N_RU.yaml -o locale/I18N_RU.yaml
+//! simple-i18n extract . --locale de -o locale/I18N_DE.yaml
+//! simple-i18n pseudo locale/I18N_EN.yaml -o locale/I18N_EN_XA.yaml
+//! ```
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+mod convert;
+mod extract;
+mod format;
+mod merge;
+mod pseudo;
+mod report;
+mod validate;
+
+/// Tools for the locale folders of sorrow-i18n.
+#[derive(Parser)]
+#[command(name = "simple-i18n", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Load the folder by the crate loader and print the errors of the catalogs.
+    Validate(validate::Args),
+    /// Convert the catalog between YAML, JSON, `.properties` and PO.
+    Convert(convert::Args),
+    /// Merge the catalogs of one locale to one file, conflicting keys are reported.
+    Merge(merge::Args),
+    /// Extract the keys of `i18n!`, `t!` and `get_or_default` from the sources to the skeleton catalog.
+    Extract(extract::Args),
+    /// Generate the pseudo-localized catalog: accented, expanded and wrapped by brackets messages.
+    Pseudo(pseudo::Args),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Validate(args) => validate::run(args),
+        Command::Convert(args) => convert::run(args),
+        Command::Merge(args) => merge::run(args),
+        Command::Extract(args) => extract::run(args),
+        Command::Pseudo(args) => pseudo::run(args),
+    }
+}
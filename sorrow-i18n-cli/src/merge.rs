@@ -0,0 +1,98 @@
+//! `merge` command.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::ValueEnum;
+
+use crate::format::{self, CatalogFormat};
+
+/// What to do, when the key has different messages in the files (as [sorrow_i18n::MergePolicy]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Conflict {
+    /// Nothing is written
+    Error,
+    /// Keep the message of the first file
+    Keep,
+    /// Overwrite by the message of the last file
+    Overwrite,
+}
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Catalogs of the same locale, in order of merging
+    #[arg(required = true, num_args = 2..)]
+    inputs: Vec<PathBuf>,
+    /// Merged catalog
+    #[arg(short, long)]
+    output: PathBuf,
+    /// Format of the result, by default by the extension
+    #[arg(long, value_enum)]
+    to: Option<CatalogFormat>,
+    /// Policy of the conflicting keys
+    #[arg(long, value_enum, default_value_t = Conflict::Error)]
+    on_conflict: Conflict,
+    /// Locale of the flat catalogs (`.properties`, PO), by default from the file
+    #[arg(long)]
+    locale: Option<String>,
+}
+
+/// Merge the catalogs of one locale to one file, which can be loaded by [sorrow_i18n::InternationalCore].
+/// The description, namespace and provider are taken from the first file. All conflicts are printed,
+/// with [Conflict::Error] the exit code is 1 and the output is not written.
+pub fn run(args: Args) -> ExitCode {
+    match merge(&args) {
+        Ok(conflicts) if conflicts > 0 && args.on_conflict == Conflict::Error => {
+            eprintln!("error: {} conflicting keys, nothing is written. Use --on-conflict keep or overwrite.", conflicts);
+            ExitCode::FAILURE
+        }
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Return count of the conflicts.
+fn merge(args: &Args) -> Result<usize, String> {
+    let mut holders = Vec::new();
+    for input in &args.inputs {
+        holders.push(format::read(input, CatalogFormat::detect(input).ok_or_else(|| format!("{}: unknown format", input.display()))?, args.locale.as_deref())?);
+    }
+    let first = &holders[0];
+    if let Some((path, other)) = args.inputs.iter().zip(&holders).find(|(_, h)| h.locale() != first.locale()) {
+        return Err(format!("{}: locale {} differs from locale {} of {}", path.display(), other.locale(), first.locale(), args.inputs[0].display()));
+    }
+
+    let mut merged: HashMap<String, (String, &Path)> = HashMap::new();
+    let mut conflicts = 0;
+    for (path, holder) in args.inputs.iter().zip(&holders) {
+        let mut messages = holder.messages().to_map().into_iter().collect::<Vec<(String, String)>>();
+        messages.sort();
+        for (key, value) in messages {
+            match merged.get_mut(&key) {
+                None => {
+                    merged.insert(key, (value, path));
+                }
+                Some((existing, source)) if *existing != value => {
+                    conflicts += 1;
+                    eprintln!("conflict[{}]: {:?} ({}) != {:?} ({})", key, existing, source.display(), value, path.display());
+                    if args.on_conflict == Conflict::Overwrite {
+                        *existing = value;
+                        *source = path;
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    if conflicts > 0 && args.on_conflict == Conflict::Error {
+        return Ok(conflicts);
+    }
+
+    first.messages().store(merged.into_iter().map(|(k, (v, _))| (k, v)).collect::<HashMap<String, String>>());
+    format::write(first, &args.output, CatalogFormat::of(&args.output, args.to)?)?;
+    Ok(conflicts)
+}
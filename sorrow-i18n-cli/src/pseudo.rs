@@ -0,0 +1,40 @@
+//! `pseudo` command.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use sorrow_i18n::pseudo::{pseudo_holder, PSEUDO_LOCALE};
+
+use crate::format::{self, CatalogFormat};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Source catalog, e.g. the catalog of English
+    input: PathBuf,
+    /// Pseudo catalog
+    #[arg(short, long)]
+    output: PathBuf,
+    /// Locale of the pseudo catalog
+    #[arg(long, default_value = PSEUDO_LOCALE)]
+    locale: String,
+    /// Format of the pseudo catalog, by default by the extension
+    #[arg(long, value_enum)]
+    to: Option<CatalogFormat>,
+}
+
+/// Write the pseudo-localized catalog, see [sorrow_i18n::pseudo].
+pub fn run(args: Args) -> ExitCode {
+    let result = CatalogFormat::of(&args.input, None)
+        .and_then(|from| format::read(&args.input, from, None))
+        .and_then(|holder| {
+            let to = CatalogFormat::of(&args.output, args.to)?;
+            format::write(&pseudo_holder(&holder, &args.locale), &args.output, to)
+        });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Structured output of the problems.
+
+use std::fmt::{Display, Formatter};
+
+use clap::ValueEnum;
+use serde::Serialize;
+use sorrow_i18n::check::{Diagnostic, DiagnosticKind};
+use sorrow_i18n::Error;
+
+/// Output format of the problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One problem per line
+    Text,
+    /// JSON array
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Problem of the catalog.
+#[derive(Debug, Serialize)]
+pub struct Problem {
+    pub severity: Severity,
+    pub kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    pub message: String,
+}
+
+impl Problem {
+    /// Problems of the loading error, [Error::LoadErrors] is flattened.
+    pub fn from_error(error: &Error) -> Vec<Problem> {
+        let problem = |kind: &'static str, path: Option<&String>, locale: Option<&String>| Problem {
+            severity: Severity::Error,
+            kind,
+            path: path.cloned(),
+            locale: locale.cloned(),
+            key: None,
+            message: error.to_string(),
+        };
+        match error {
+            Error::LoadErrors { errors } => errors.iter().flat_map(Problem::from_error).collect(),
+            Error::IoError { path, .. } => vec![problem("io", Some(path), None)],
+            Error::InvalidStructure { path, .. } => vec![problem("yaml", Some(path), None)],
+            Error::InvalidHeader { path } => vec![problem("kind", Some(path), None)],
+            Error::WatchError { path, .. } => vec![problem("watch", Some(path), None)],
+            Error::NotSupportedFileExtension { path } => vec![problem("extension", Some(path), None)],
+            Error::DuplicateLocale { locale } => vec![problem("duplicate_locale", None, Some(locale))],
+            e => vec![Problem { severity: Severity::Error, kind: "error", path: None, locale: None, key: None, message: e.to_string() }],
+        }
+    }
+
+    /// Problem of [sorrow_i18n::InternationalCore::validate], the invalid source file is an error, the rest are warnings.
+    pub fn from_diagnostic(diagnostic: &Diagnostic) -> Problem {
+        let (severity, kind) = match &diagnostic.kind {
+            DiagnosticKind::EmptyValue => (Severity::Warning, "empty_value"),
+            DiagnosticKind::SuspiciousWhitespace => (Severity::Warning, "whitespace"),
+            DiagnosticKind::InvalidPlaceholder { .. } => (Severity::Warning, "placeholder"),
+            DiagnosticKind::TooLong { .. } => (Severity::Warning, "max_len"),
+            DiagnosticKind::PlaceholderMismatch { .. } => (Severity::Warning, "placeholder_mismatch"),
+            DiagnosticKind::InvalidKind { .. } => (Severity::Error, "kind"),
+            DiagnosticKind::InvalidFile { .. } => (Severity::Error, "file"),
+        };
+        Problem {
+            severity,
+            kind,
+            path: None,
+            locale: Some(diagnostic.locale.clone()),
+            key: diagnostic.key.clone(),
+            message: diagnostic.to_string(),
+        }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}[{}]: {}", severity, self.kind, self.message)
+    }
+}
+
+/// Print the problems to stdout.
+pub fn print(problems: &[Problem], format: Format) {
+    match format {
+        Format::Text => problems.iter().for_each(|p| println!("{}", p)),
+        Format::Json => println!("{}", serde_json::to_string_pretty(problems).expect("problems are serializable")),
+    }
+}
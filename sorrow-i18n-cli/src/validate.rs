@@ -0,0 +1,48 @@
+//! `validate` command.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use sorrow_i18n::InternationalCore;
+
+use crate::report::{self, Format, Problem, Severity};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Locale folder
+    dir: PathBuf,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Base locale, the placeholders of other locales are compared with it
+    #[arg(long)]
+    base: Option<String>,
+    /// Fail on warnings (empty messages, whitespace, invalid placeholders, too long messages)
+    #[arg(long)]
+    deny_warnings: bool,
+}
+
+/// Load the folder as [InternationalCore::try_new] does, then lint the loaded catalogs.
+/// With `--base` the placeholders are compared with the base locale (see [InternationalCore::check_placeholders]).
+/// Exit code is 1, if there are errors (or warnings with `--deny-warnings`).
+pub fn run(args: Args) -> ExitCode {
+    let problems = match InternationalCore::try_new(args.dir.to_string_lossy()) {
+        Err(e) => Problem::from_error(&e),
+        Ok(core) => {
+            let mut problems = core.validate().iter().map(Problem::from_diagnostic).collect::<Vec<Problem>>();
+            match args.base.as_deref().map(|base| core.check_placeholders(base)) {
+                None => {}
+                Some(Ok(mismatches)) => problems.extend(mismatches.iter().map(Problem::from_diagnostic)),
+                Some(Err(e)) => problems.extend(Problem::from_error(&e)),
+            }
+            problems
+        }
+    };
+    report::print(&problems, args.format);
+    let failed = problems.iter().any(|p| p.severity == Severity::Error || args.deny_warnings);
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,90 @@
+//! Derive macro `Localize` for [sorrow-i18n](https://docs.rs/sorrow-i18n).
+//! Use it through the `derive` feature of `sorrow-i18n`.
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, LitStr};
+
+/// Implements `sorrow_i18n::Localize` for enum, every variant is mapped to the catalog key.
+///
+/// The key is `{prefix}.{variant}`, variant name in snake case. The default prefix is `data.{enum}`, enum name in snake case.
+/// The prefix is set by `#[localize(prefix = "...")]` on enum, the key of variant by `#[localize(key = "...")]`.
+#[proc_macro_derive(Localize, attributes(localize))]
+pub fn derive_localize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => return Err(syn::Error::new_spanned(&input.ident, "Localize can be derived only for enums.")),
+    };
+    let prefix = attribute(&input.attrs, "prefix")?
+        .unwrap_or_else(|| format!("data.{}", snake_case(&input.ident.to_string())));
+
+    let mut arms = vec![];
+    for variant in data.variants.iter() {
+        let ident = &variant.ident;
+        let key = attribute(&variant.attrs, "key")?
+            .unwrap_or_else(|| format!("{}.{}", prefix, snake_case(&ident.to_string())));
+        let pattern = match variant.fields {
+            Fields::Unit => quote!(Self::#ident),
+            Fields::Unnamed(_) => quote!(Self::#ident(..)),
+            Fields::Named(_) => quote!(Self::#ident { .. }),
+        };
+        arms.push(quote!(#pattern => #key,));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::sorrow_i18n::Localize for #name #ty_generics #where_clause {
+            fn key(&self) -> &'static str {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Value of `#[localize(name = "...")]`.
+fn attribute(attrs: &[Attribute], name: &str) -> syn::Result<Option<String>> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("localize")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(name) {
+                value = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("prefix") || meta.path.is_ident("key") {
+                Err(meta.error(format!("expected `{}`", name)))
+            } else {
+                Err(meta.error("unknown localize attribute, expected `prefix` or `key`"))
+            }
+        })?;
+    }
+    Ok(value)
+}
+
+/// `NotFound` -> `not_found`, `HTTPError` -> `http_error`.
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<char>>();
+    let mut result = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_lower = chars.get(i + 1).map(|n| n.is_lowercase()).unwrap_or(false);
+            if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_lower) {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_lowercase());
+    }
+    result
+}
@@ -0,0 +1,157 @@
+//! Integration with actix-web. The middleware resolves the locale of the request (query, cookie, `Accept-Language`)
+//! and stores [Data] of the locale in the request extensions.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use actix_web::{web, App};
+//! use sorrow_i18n::actix::{I18n, Localized};
+//! use sorrow_i18n::InternationalCore;
+//!
+//! async fn welcome(msgs: Localized) -> String {
+//!     msgs.t("welcome")
+//! }
+//!
+//! let core = Arc::new(InternationalCore::new("locale/"));
+//! let app = App::new()
+//!     .wrap(I18n::new(core).query("lang").cookie("lang"))
+//!     .route("/", web::get().to(welcome));
+//! ```
+
+use std::future::{ready, Ready};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use actix_web::dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::error::ErrorInternalServerError;
+use actix_web::http::header::ACCEPT_LANGUAGE;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+
+use crate::{Data, GetData, InternationalCore};
+
+/// Middleware of the locale. The locale is taken from the query parameter, then from the cookie (if they are set and the locale is loaded),
+/// then negotiated from `Accept-Language` (see [InternationalCore::negotiate]). Otherwise, the current locale is used (see [InternationalCore::current_locale]).
+#[derive(Clone)]
+pub struct I18n {
+    core: Arc<InternationalCore>,
+    query: Option<String>,
+    cookie: Option<String>,
+}
+
+impl I18n {
+    /// Middleware by the core, only `Accept-Language` is used.
+    pub fn new(core: Arc<InternationalCore>) -> Self {
+        I18n { core, query: None, cookie: None }
+    }
+
+    /// Query parameter with the locale, e.g. `?lang=ru`.
+    pub fn query(mut self, name: &str) -> Self {
+        self.query = Some(name.to_string());
+        self
+    }
+
+    /// Cookie with the locale.
+    pub fn cookie(mut self, name: &str) -> Self {
+        self.cookie = Some(name.to_string());
+        self
+    }
+
+    fn resolve(&self, req: &ServiceRequest) -> String {
+        let query = self.query.as_ref().and_then(|name| {
+            req.query_string()
+                .split('&')
+                .filter_map(|p| p.split_once('='))
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.to_string())
+        });
+        let cookie = self.cookie.as_ref().and_then(|name| req.cookie(name)).map(|c| c.value().to_string());
+        let header = req.headers().get(ACCEPT_LANGUAGE).and_then(|h| h.to_str().ok()).map(|h| h.to_string());
+        query.into_iter()
+            .chain(cookie)
+            .chain(header)
+            .find_map(|l| self.core.negotiate(&l))
+            .unwrap_or_else(|| self.core.current_locale())
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for I18n
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = I18nMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(I18nMiddleware { service, i18n: self.clone() }))
+    }
+}
+
+/// Service of [I18n].
+pub struct I18nMiddleware<S> {
+    service: S,
+    i18n: I18n,
+}
+
+impl<S, B> Service<ServiceRequest> for I18nMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = S::Future;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let locale = self.i18n.resolve(&req);
+        if let Some(data) = self.i18n.core.get_by_locale(&locale) {
+            req.extensions_mut().insert(Localized(data));
+        }
+        req.extensions_mut().insert(Locale(locale));
+        self.service.call(req)
+    }
+}
+
+/// Locale of the request, resolved by [I18n].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale(pub String);
+
+impl FromRequest for Locale {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(req.extensions().get::<Locale>().cloned().ok_or_else(|| ErrorInternalServerError("I18n middleware is not registered.")))
+    }
+}
+
+/// [Data] by the locale of the request, resolved by [I18n].
+/// If the locale is not loaded, the request fails with `500 Internal Server Error`.
+#[derive(Clone)]
+pub struct Localized(pub Data);
+
+impl Localized {
+    /// Message by key or the key itself, helper for templates.
+    pub fn t(&self, key: &str) -> String {
+        self.0.get_or_default(key)
+    }
+}
+
+impl Deref for Localized {
+    type Target = Data;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromRequest for Localized {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(req.extensions().get::<Localized>().cloned().ok_or_else(|| ErrorInternalServerError("Locale of the request is not found.")))
+    }
+}
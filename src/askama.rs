@@ -0,0 +1,66 @@
+//! Filters for Askama templates. Import [filters] in the module of the template struct,
+//! then `{{ "data.name"|t(locale) }}` translates by the core of [install] (or with feature `macro` by the core of `init_i18n!`),
+//! and `{{ "data.name"|t_in(core, locale) }}` by the core passed to the template.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use askama::Template;
+//! use sorrow_i18n::InternationalCore;
+//! use sorrow_i18n::askama::filters;
+//!
+//! #[derive(Template)]
+//! #[template(source = "<h1>{{ \"data.name\"|t(locale) }}</h1>", ext = "html")]
+//! struct Hello {
+//!     locale: String,
+//! }
+//!
+//! sorrow_i18n::askama::install(Arc::new(InternationalCore::new("locale/")));
+//! let html = Hello { locale: "en".to_string() }.render().unwrap();
+//! ```
+
+use std::sync::{Arc, RwLock};
+
+use crate::{GetData, InternationalCore};
+
+static CORE: RwLock<Option<Arc<InternationalCore>>> = RwLock::new(None);
+
+/// Set the core of the filter `t`, the previous core is replaced.
+pub fn install(core: Arc<InternationalCore>) {
+    *CORE.write().unwrap() = Some(core);
+}
+
+/// Message by key in the locale, or the key itself.
+fn translate(core: &InternationalCore, locale: &str, key: &str) -> String {
+    match core.get_by_locale(locale) {
+        None => key.to_string(),
+        Some(data) => data.get_or_default(key),
+    }
+}
+
+/// Filters of the templates, see [crate::askama].
+pub mod filters {
+    use std::fmt::Display;
+
+    use askama::Values;
+
+    use super::{translate, CORE};
+    use crate::InternationalCore;
+
+    /// `{{ "data.name"|t(locale) }}`, translated by the core of [super::install].
+    /// If the core is not installed, the core of `init_i18n!` is used (feature `macro`), otherwise the key is returned.
+    pub fn t<K: Display, L: Display>(key: K, _: &dyn Values, locale: L) -> askama::Result<String> {
+        let (key, locale) = (key.to_string(), locale.to_string());
+        match CORE.read().unwrap().as_deref() {
+            Some(core) => Ok(translate(core, &locale, &key)),
+            #[cfg(feature = "macro")]
+            None => Ok(crate::feature_macro::get_param(&locale, &key)),
+            #[cfg(not(feature = "macro"))]
+            None => Ok(key),
+        }
+    }
+
+    /// `{{ "data.name"|t_in(core, locale) }}`, translated by the core passed to the template, e.g. `Arc<InternationalCore>`.
+    pub fn t_in<K: Display, L: Display>(key: K, _: &dyn Values, core: &InternationalCore, locale: L) -> askama::Result<String> {
+        Ok(translate(core, &locale.to_string(), &key.to_string()))
+    }
+}
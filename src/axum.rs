@@ -0,0 +1,77 @@
+//! Integration with axum. The core is shared by the state of the router,
+//! the locale of the request is negotiated from `Accept-Language` against the loaded locales.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use axum::{routing::get, Router};
+//! use sorrow_i18n::axum::Localized;
+//! use sorrow_i18n::{GetData, InternationalCore};
+//!
+//! async fn welcome(msgs: Localized) -> String {
+//!     msgs.get_or_default("welcome")
+//! }
+//!
+//! let core = Arc::new(InternationalCore::new("locale/"));
+//! let app: Router = Router::new().route("/", get(welcome)).with_state(core);
+//! ```
+
+use std::convert::Infallible;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::header::ACCEPT_LANGUAGE;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+
+use crate::{Data, InternationalCore};
+
+/// Locale of the request, see [InternationalCore::negotiate]. The core is taken from the state of the router:
+/// `Arc<InternationalCore>` or any state with [FromRef].
+/// If no locale of `Accept-Language` is loaded, the current locale (see [InternationalCore::current_locale]) is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale(pub String);
+
+impl<S: Send + Sync> FromRequestParts<S> for Locale
+where
+    Arc<InternationalCore>: FromRef<S>,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let core = Arc::<InternationalCore>::from_ref(state);
+        let locale = parts.headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| core.negotiate(h))
+            .unwrap_or_else(|| core.current_locale());
+        Ok(Locale(locale))
+    }
+}
+
+/// [Data] by the locale of the request (see [Locale]), so handlers can write `msgs.get("welcome")`.
+/// If the locale is not loaded, the request is rejected with `500 Internal Server Error`.
+pub struct Localized(pub Data);
+
+impl Deref for Localized {
+    type Target = Data;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Localized
+where
+    Arc<InternationalCore>: FromRef<S>,
+{
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Locale(locale) = Locale::from_request_parts(parts, state).await.unwrap_or_else(|e| match e {});
+        Arc::<InternationalCore>::from_ref(state)
+            .get_by_locale(&locale)
+            .map(Localized)
+            .ok_or_else(|| (StatusCode::INTERNAL_SERVER_ERROR, format!("Locale {:?} not found.", locale)))
+    }
+}
@@ -0,0 +1,100 @@
+//! Bevy plugin. The core is exposed as [I18n] resource, UI `Text` tagged by [LocalizedText] is updated
+//! when the locale is switched and when the watched catalogs reload, so game UIs hot-reload translations during development.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use sorrow_i18n::bevy::{I18nPlugin, LocalizedText};
+//! use sorrow_i18n::InternationalCore;
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands.spawn((Text::default(), LocalizedText::new("menu.start")));
+//! }
+//!
+//! App::new()
+//!     .add_plugins(I18nPlugin::new(InternationalCore::new("locale/"), "en"))
+//!     .add_systems(Startup, setup)
+//!     .run();
+//! ```
+
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+
+use crate::events::ReloadEvent;
+use crate::{GetData, InternationalCore};
+
+/// The core and the current locale. Switching the locale updates all [LocalizedText].
+#[derive(Resource)]
+pub struct I18n {
+    /// The core
+    pub core: Arc<InternationalCore>,
+    /// Current locale
+    pub locale: String,
+}
+
+impl I18n {
+    /// Message by key in the current locale, or the key itself.
+    pub fn t(&self, key: &str) -> String {
+        match self.core.get_by_locale(&self.locale) {
+            None => key.to_string(),
+            Some(data) => data.get_or_default(key),
+        }
+    }
+}
+
+/// Key of the message for `Text` of the entity.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct LocalizedText {
+    /// Key of the message
+    pub key: String,
+}
+
+impl LocalizedText {
+    /// Tag by key.
+    pub fn new(key: &str) -> Self {
+        LocalizedText { key: key.to_string() }
+    }
+}
+
+/// Events of the core, see [InternationalCore::subscribe].
+#[derive(Resource)]
+struct ReloadEvents(Mutex<Receiver<ReloadEvent>>);
+
+/// Plugin adding [I18n] resource and the system updating [LocalizedText].
+pub struct I18nPlugin {
+    core: Arc<InternationalCore>,
+    locale: String,
+    events: Mutex<Option<Receiver<ReloadEvent>>>,
+}
+
+impl I18nPlugin {
+    /// Plugin by the core and the initial locale.
+    pub fn new(mut core: InternationalCore, locale: &str) -> Self {
+        let events = core.subscribe();
+        I18nPlugin { core: Arc::new(core), locale: locale.to_string(), events: Mutex::new(Some(events)) }
+    }
+}
+
+impl Plugin for I18nPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(I18n { core: Arc::clone(&self.core), locale: self.locale.clone() })
+            .add_systems(Update, update_texts);
+        if let Some(events) = self.events.lock().unwrap().take() {
+            app.insert_resource(ReloadEvents(Mutex::new(events)));
+        }
+    }
+}
+
+/// Update `Text` of the new or changed [LocalizedText], all of them when the locale is switched, and the reloaded keys.
+fn update_texts(i18n: Res<I18n>, events: Option<Res<ReloadEvents>>, mut texts: Query<(Ref<LocalizedText>, &mut Text)>) {
+    let reloaded = match events {
+        None => vec![],
+        Some(events) => events.0.lock().unwrap().try_iter().flat_map(|e| e.keys).collect::<Vec<String>>(),
+    };
+    for (localized, mut text) in texts.iter_mut() {
+        if i18n.is_changed() || localized.is_changed() || reloaded.contains(&localized.key) {
+            text.0 = i18n.t(&localized.key);
+        }
+    }
+}
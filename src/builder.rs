@@ -0,0 +1,57 @@
+//! Catalogs assembled in code or in tests, no file or YAML involved.
+//! Keys are used as is, so use the same keys as in files (`data.name`) to mix both.
+
+use std::collections::HashMap;
+
+use crate::{Holder, InternationalCore};
+
+/// Builder of [InternationalCore] by (locale, key, value).
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::{GetData, InternationalCore};
+/// let core = InternationalCore::builder()
+///     .insert("EN", "data.name", "Test")
+///     .locale("RU", [("data.name", "Тест"), ("data.greeting", "Привет, {name}!")])
+///     .build();
+/// assert_eq!("Test", core.get_by_locale("en").unwrap().get_or_default("data.name"));
+/// assert_eq!(vec!["en", "ru"], core.locales());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CatalogBuilder {
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl CatalogBuilder {
+    /// Empty builder.
+    pub fn new() -> Self {
+        CatalogBuilder::default()
+    }
+
+    /// Add the message to the locale. The existing key is replaced.
+    pub fn insert<K: Into<String>, V: Into<String>>(mut self, locale: &str, key: K, value: V) -> Self {
+        self.entry(locale).insert(key.into(), value.into());
+        self
+    }
+
+    /// Add (key, value) pairs to the locale. The existing keys are replaced.
+    pub fn locale<K: Into<String>, V: Into<String>, I: IntoIterator<Item = (K, V)>>(mut self, locale: &str, pairs: I) -> Self {
+        self.entry(locale).extend(pairs.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Build the core, every locale is the holder without file.
+    pub fn build(self) -> InternationalCore {
+        let mut core = InternationalCore::empty();
+        for (locale, messages) in self.locales {
+            core.insert_holder(Holder::from_map(&locale, messages));
+        }
+        core
+    }
+
+    /// Messages of the locale, locales are normalized.
+    fn entry(&mut self, locale: &str) -> &mut HashMap<String, String> {
+        self.locales.entry(crate::locale::normalize(locale)).or_default()
+    }
+}
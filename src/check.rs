@@ -0,0 +1,353 @@
+//! Checks of the catalogs across locales.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+use crate::format::Template;
+use crate::{Error, Holder};
+
+/// Keys which are not translated to all locales, see [crate::InternationalCore::check_consistency].
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::InternationalCore;
+/// let core = InternationalCore::builder()
+///     .insert("EN", "data.name", "Test")
+///     .insert("EN", "data.title", "Title")
+///     .insert("RU", "data.name", "Тест")
+///     .build();
+/// let report = core.check_consistency();
+/// assert!(!report.is_consistent());
+/// assert_eq!(vec!["data.title"], report.missing_in("RU"));
+/// assert!(report.missing_in("EN").is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsistencyReport {
+    /// Locale -> keys existing in other locales, but missing in this locale. Keys are sorted, locales without missing keys are not included.
+    pub missing: BTreeMap<String, Vec<String>>,
+    /// Locale -> keys existing only in this locale. Keys are sorted, locales without such keys are not included.
+    pub unique: BTreeMap<String, Vec<String>>,
+}
+
+impl ConsistencyReport {
+    /// All locales have the same keys.
+    pub fn is_consistent(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    /// Keys missing in the locale.
+    pub fn missing_in(&self, locale: &str) -> Vec<&str> {
+        self.missing.get(&crate::locale::normalize(locale))
+            .map(|keys| keys.iter().map(|k| k.as_str()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Compare own keys of the holders, fallback chains are not used.
+pub(crate) fn consistency(holders: &HashMap<String, Holder>) -> ConsistencyReport {
+    let keys = holders.iter()
+        .map(|(locale, holder)| {
+            holder.load_lazy();
+            (locale.clone(), holder.messages.load().keys().cloned().collect::<BTreeSet<String>>())
+        })
+        .collect::<BTreeMap<String, BTreeSet<String>>>();
+    let mut count = BTreeMap::<&String, usize>::new();
+    for key in keys.values().flatten() {
+        *count.entry(key).or_default() += 1;
+    }
+    let mut report = ConsistencyReport::default();
+    for (locale, own) in keys.iter() {
+        let missing = count.keys().filter(|k| !own.contains(**k)).map(|k| k.to_string()).collect::<Vec<String>>();
+        if !missing.is_empty() {
+            report.missing.insert(locale.clone(), missing);
+        }
+        let unique = own.iter().filter(|k| keys.len() > 1 && count[k] == 1).cloned().collect::<Vec<String>>();
+        if !unique.is_empty() {
+            report.unique.insert(locale.clone(), unique);
+        }
+    }
+    report
+}
+
+/// Translation progress of the locales against the base locale, see [crate::InternationalCore::coverage].
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::InternationalCore;
+/// let core = InternationalCore::builder()
+///     .insert("EN", "data.name", "Test")
+///     .insert("EN", "data.title", "Title")
+///     .insert("RU", "data.name", "Тест")
+///     .insert("DE", "data.name", "Test")
+///     .insert("DE", "data.title", "")
+///     .build();
+/// let report = core.coverage("EN").unwrap();
+/// assert_eq!(2, report.total);
+/// assert_eq!(Some(50.0), report.percent("RU"));
+/// assert_eq!(vec!["data.title"], report.locales["de"].missing);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoverageReport {
+    /// Base locale
+    pub base: String,
+    /// Count of the keys of the base locale
+    pub total: usize,
+    /// Locale -> coverage of the locale, the base locale is not included.
+    pub locales: BTreeMap<String, Coverage>,
+}
+
+impl CoverageReport {
+    /// Percent of the translated keys of the locale, None if the locale is not loaded.
+    pub fn percent(&self, locale: &str) -> Option<f64> {
+        self.locales.get(&crate::locale::normalize(locale)).map(|c| c.percent())
+    }
+}
+
+/// Coverage of one locale, see [CoverageReport].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// Count of the keys of the base locale
+    pub total: usize,
+    /// Count of the keys of the base locale translated to this locale
+    pub translated: usize,
+    /// Keys of the base locale missing or empty in this locale, sorted.
+    pub missing: Vec<String>,
+}
+
+impl Coverage {
+    /// Percent of the translated keys, 100 if the base locale has no keys.
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.translated as f64 * 100.0 / self.total as f64
+        }
+    }
+}
+
+/// Compare own keys of the holders with the keys of `base`, fallback chains are not used. Empty messages are not translated.
+pub(crate) fn coverage(holders: &HashMap<String, Holder>, base: &str) -> Result<CoverageReport, Error> {
+    let base = crate::locale::normalize(base);
+    let base_holder = holders.get(&base).ok_or_else(|| Error::MissingLocale { locale: base.clone() })?;
+    let mut keys = base_holder.messages().load().keys().cloned().collect::<Vec<String>>();
+    keys.sort();
+    let mut report = CoverageReport { base: base.clone(), total: keys.len(), locales: BTreeMap::new() };
+    for (locale, holder) in holders.iter().filter(|(l, _)| **l != base) {
+        let messages = holder.messages().load();
+        let missing = keys.iter()
+            .filter(|k| messages.get(*k).is_none_or(|v| v.is_empty()))
+            .cloned()
+            .collect::<Vec<String>>();
+        report.locales.insert(locale.clone(), Coverage { total: keys.len(), translated: keys.len() - missing.len(), missing });
+    }
+    Ok(report)
+}
+
+/// Problem of the catalog found by [Holder::validate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Locale of the holder
+    pub locale: String,
+    /// Key of the message, `None` for the problems of the source file
+    pub key: Option<String>,
+    /// The problem
+    pub kind: DiagnosticKind,
+}
+
+/// Kinds of [Diagnostic].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// The message is empty.
+    EmptyValue,
+    /// The message starts or ends with whitespace.
+    SuspiciousWhitespace,
+    /// `{` is not closed, `}` is not opened or the placeholder name is empty or contains whitespace. Position is the byte offset in the message.
+    InvalidPlaceholder {
+        /// Byte offset of the brace
+        position: usize,
+    },
+    /// The message is longer than `max_len` of the key (see [crate::messages::Metadata::max_len]), length is the count of the characters.
+    TooLong {
+        /// Length of the message
+        len: usize,
+        /// Max length of the key
+        max_len: usize,
+    },
+    /// The message has not the same placeholders as the message of the base locale, see [crate::InternationalCore::check_placeholders].
+    PlaceholderMismatch {
+        /// Base locale
+        base: String,
+        /// Placeholders of the base message missing in this message, sorted.
+        missing: Vec<String>,
+        /// Placeholders of this message missing in the base message, sorted.
+        extra: Vec<String>,
+    },
+    /// Kind of the source file is not `I18N`.
+    InvalidKind {
+        /// Kind of the file
+        kind: String,
+    },
+    /// The source file can not be read or parsed.
+    InvalidFile {
+        /// Cause error
+        cause: String,
+    },
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", &self.locale)?;
+        if let Some(key) = &self.key {
+            write!(f, " {}", key)?;
+        }
+        match &self.kind {
+            DiagnosticKind::EmptyValue => write!(f, ": empty message"),
+            DiagnosticKind::SuspiciousWhitespace => write!(f, ": leading or trailing whitespace"),
+            DiagnosticKind::InvalidPlaceholder { position } => write!(f, ": invalid placeholder at {}", position),
+            DiagnosticKind::TooLong { len, max_len } => write!(f, ": message is too long, {} characters, max {}", len, max_len),
+            DiagnosticKind::PlaceholderMismatch { base, missing, extra } => {
+                write!(f, ": placeholders differ from {}", base)?;
+                if !missing.is_empty() {
+                    write!(f, ", missing {{{}}}", missing.join("}, {"))?;
+                }
+                if !extra.is_empty() {
+                    write!(f, ", extra {{{}}}", extra.join("}, {"))?;
+                }
+                Ok(())
+            }
+            DiagnosticKind::InvalidKind { kind } => write!(f, ": invalid kind {:?}, expected I18N", kind),
+            DiagnosticKind::InvalidFile { cause } => write!(f, ": invalid file, {}", cause),
+        }
+    }
+}
+
+/// Max length by key, declared in the metadata of any of the holders. If the holders declare different lengths, the least is used.
+pub(crate) fn limits<'a, I: IntoIterator<Item = &'a Holder>>(holders: I) -> HashMap<String, usize> {
+    let mut limits = HashMap::<String, usize>::new();
+    for holder in holders {
+        holder.load_lazy();
+        for (key, metadata) in holder.messages.metadata().iter() {
+            if let Some(max_len) = metadata.max_len {
+                limits.entry(key.clone())
+                    .and_modify(|l| *l = max_len.min(*l))
+                    .or_insert(max_len);
+            }
+        }
+    }
+    limits
+}
+
+/// Check the messages of the holder and its source file, if the holder has been loaded from file.
+/// Messages are checked against the max lengths by key, see [limits].
+pub(crate) fn validate(holder: &Holder, limits: &HashMap<String, usize>) -> Vec<Diagnostic> {
+    let diagnostic = |key: Option<&str>, kind: DiagnosticKind| Diagnostic {
+        locale: holder.locale().to_string(),
+        key: key.map(|k| k.to_string()),
+        kind,
+    };
+    let mut diagnostics = vec![];
+    if let Some(path) = &holder.path {
+        let header = std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|data| serde_yaml::from_str::<serde_yaml::Value>(&data).map_err(|e| e.to_string()));
+        match header {
+            Err(cause) => diagnostics.push(diagnostic(None, DiagnosticKind::InvalidFile { cause })),
+            Ok(header) => {
+                let kind = header["kind"].as_str().unwrap_or_default();
+                if kind != "I18N" {
+                    diagnostics.push(diagnostic(None, DiagnosticKind::InvalidKind { kind: kind.to_string() }));
+                }
+            }
+        }
+    }
+    holder.load_lazy();
+    let messages = holder.messages.load();
+    let mut keys = messages.keys().collect::<Vec<&String>>();
+    keys.sort();
+    for key in keys {
+        let value = &messages[key];
+        if value.is_empty() {
+            diagnostics.push(diagnostic(Some(key), DiagnosticKind::EmptyValue));
+            continue;
+        }
+        if let Some(max_len) = limits.get(key.as_str()).copied() {
+            let len = value.chars().count();
+            if len > max_len {
+                diagnostics.push(diagnostic(Some(key), DiagnosticKind::TooLong { len, max_len }));
+            }
+        }
+        if value.trim() != &**value {
+            diagnostics.push(diagnostic(Some(key), DiagnosticKind::SuspiciousWhitespace));
+        }
+        if let Some(position) = invalid_placeholder(value) {
+            diagnostics.push(diagnostic(Some(key), DiagnosticKind::InvalidPlaceholder { position }));
+        }
+    }
+    diagnostics
+}
+
+/// Position of the first invalid brace of the message.
+fn invalid_placeholder(value: &str) -> Option<usize> {
+    let mut open = None;
+    for (i, c) in value.char_indices() {
+        match (c, open) {
+            ('{', None) => open = Some(i),
+            ('{', Some(start)) => return Some(start),
+            ('}', None) => return Some(i),
+            ('}', Some(start)) => {
+                let name = &value[start + 1..i];
+                if name.is_empty() || name.contains(char::is_whitespace) {
+                    return Some(start);
+                }
+                open = None;
+            }
+            _ => {}
+        }
+    }
+    open
+}
+
+/// Compare the placeholders of the messages of the holders with the messages of `base`, see [crate::InternationalCore::check_placeholders].
+/// Keys missing or empty in the locale are skipped, see [coverage].
+pub(crate) fn placeholders(holders: &HashMap<String, Holder>, base: &str) -> Result<Vec<Diagnostic>, Error> {
+    let base = crate::locale::normalize(base);
+    let base_holder = holders.get(&base).ok_or_else(|| Error::MissingLocale { locale: base.clone() })?;
+    base_holder.load_lazy();
+    let base_messages = base_holder.messages.load();
+    let mut locales = holders.iter().filter(|(l, _)| **l != base).collect::<Vec<(&String, &Holder)>>();
+    locales.sort_by_key(|(l, _)| *l);
+    let mut diagnostics = vec![];
+    for (locale, holder) in locales {
+        holder.load_lazy();
+        let messages = holder.messages.load();
+        let mut keys = base_messages.keys().collect::<Vec<&String>>();
+        keys.sort();
+        for key in keys {
+            let value = match messages.get(key) {
+                Some(value) if !value.is_empty() => value,
+                _ => continue,
+            };
+            let expected = names(&base_messages[key]);
+            let found = names(value);
+            if expected != found {
+                diagnostics.push(Diagnostic {
+                    locale: locale.clone(),
+                    key: Some(key.clone()),
+                    kind: DiagnosticKind::PlaceholderMismatch {
+                        base: base.clone(),
+                        missing: expected.difference(&found).cloned().collect(),
+                        extra: found.difference(&expected).cloned().collect(),
+                    },
+                });
+            }
+        }
+    }
+    Ok(diagnostics)
+}
+
+/// Names of the placeholders of the message.
+fn names(value: &str) -> BTreeSet<String> {
+    Template::new(value).placeholders().map(|p| p.to_string()).collect()
+}
@@ -0,0 +1,115 @@
+//! Generation of key constants from the locale folder, for build scripts.
+//! Every key of every locale becomes a `&str` constant, so typos in key strings become compile errors.
+//! Keys of namespaced files are generated into the module with the namespace name.
+//!
+//! `build.rs`:
+//!
+//! ```no_run
+//! let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("i18n_keys.rs");
+//! sorrow_i18n::codegen::generate_keys("locale/", out).unwrap();
+//! println!("cargo:rerun-if-changed=locale/");
+//! ```
+//!
+//! And in the project:
+//!
+//! ```ignore
+//! mod keys {
+//!     include!(concat!(env!("OUT_DIR"), "/i18n_keys.rs"));
+//! }
+//! let name = core.get_by_locale("EN").unwrap().get_or_default(keys::DATA_NAME);
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use crate::{Error, Holder};
+
+/// Read all files in `folder` and write the module with key constants to `out`.
+pub fn generate_keys<P: AsRef<Path>>(folder: &str, out: P) -> Result<(), Error> {
+    let source = keys_source(folder)?;
+    let out = out.as_ref();
+    std::fs::write(out, source).map_err(|e| Error::IoError { path: out.to_string_lossy().to_string(), cause: e })
+}
+
+/// Read all files in `folder` and return the source of the module with key constants.
+///
+/// ```yaml
+/// kind: I18N
+/// locale: EN
+/// data:
+///   name: "Test"
+/// ```
+///
+/// Generates:
+///
+/// ```ignore
+/// /// `data.name`
+/// pub const DATA_NAME: &str = "data.name";
+/// ```
+pub fn keys_source(folder: &str) -> Result<String, Error> {
+    let dir = std::fs::read_dir(folder).map_err(|e| Error::IoError { path: folder.to_string(), cause: e })?;
+    // Namespace -> keys, root keys are in the empty namespace.
+    let mut namespaces: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for path in dir {
+        let path = path.map_err(|e| Error::IoError { path: folder.to_string(), cause: e })?.path();
+        let holder = match Holder::new(path.to_string_lossy().to_string()) {
+            Ok(holder) => holder,
+            Err(Error::NotSupportedFileExtension { .. } | Error::IncludeFile { .. }) => continue,
+            Err(e) => return Err(e),
+        };
+        let keys = namespaces.entry(holder.namespace().unwrap_or_default().to_string()).or_default();
+        keys.extend(holder.messages.load().keys().cloned());
+    }
+
+    let mut source = String::from("// Generated by sorrow_i18n::codegen, do not edit.\n");
+    for (namespace, keys) in namespaces.iter() {
+        let indent = if namespace.is_empty() { "" } else { "    " };
+        if !namespace.is_empty() {
+            source.push_str(&format!("\n/// Keys of `{}` namespace.\npub mod {} {{\n", namespace, module_name(namespace)));
+        }
+        let mut names: BTreeMap<String, &String> = BTreeMap::new();
+        for key in keys {
+            let name = const_name(key);
+            if let Some(other) = names.insert(name.clone(), key) {
+                return Err(Error::KeyNameConflict { name, key: other.clone(), other: key.clone() });
+            }
+            source.push_str(&format!("{}/// `{}`\n{}pub const {}: &str = {:?};\n", indent, key, indent, name, key));
+        }
+        if !namespace.is_empty() {
+            source.push_str("}\n");
+        }
+    }
+    Ok(source)
+}
+
+/// Constant name by key, `data.user-name` -> `DATA_USER_NAME`.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::codegen::const_name;
+/// assert_eq!("DATA_USER_NAME", const_name("data.user-name"));
+/// assert_eq!("_1_TITLE", const_name("1.title"));
+/// ```
+pub fn const_name(key: &str) -> String {
+    let name = key.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_ascii_digit()) || name.is_empty() {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+/// Module name by namespace, `checkout-v2` -> `checkout_v2`.
+fn module_name(namespace: &str) -> String {
+    let name = namespace.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_ascii_digit()) || name.is_empty() {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
@@ -0,0 +1,185 @@
+//! Layered provider. Every layer is a provider with own data, the layers are merged into the holder's data by priority,
+//! for example remote overrides file overrides embedded. Lookups stay in one merged map.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+
+use crate::stats::ReloadStats;
+use crate::{ChangeListener, Error, ErrorListener, ErrorReporter, Messages, WatchProvider};
+#[cfg(feature = "watch")]
+use crate::watcher::FolderWatcher;
+
+/// Chains providers with priority, the last added layer has the highest priority.
+/// The holder's data at the moment of adding the provider is the lowest layer.
+/// When the layer refreshes its data (see [WatchProvider::set_listener]), the layering is re-applied.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use sorrow_i18n::Messages;
+/// # use sorrow_i18n::{Error, WatchProvider};
+/// use sorrow_i18n::InternationalCore;
+/// use sorrow_i18n::composite::CompositeProvider;
+/// # struct RemoteProvider {}
+/// # impl WatchProvider for RemoteProvider {
+/// #     fn watch(&mut self) -> Result<(), Error> { Ok(()) }
+/// #     fn set_data(&mut self, _data: Arc<Messages>) -> Result<(), Error> { Ok(()) }
+/// # }
+/// let mut core = InternationalCore::new("locale/");
+/// // File data of the holder is overridden by the remote layer.
+/// let provider = CompositeProvider::new().layer(Box::new(RemoteProvider {}));
+/// core.add_provider("EN", Box::new(provider)).unwrap();
+/// ```
+pub struct CompositeProvider {
+    layers: Vec<Layer>,
+    data: Arc<Messages>,
+    listener: Option<ChangeListener>,
+    errors: ErrorReporter,
+    /// The holder's data is the lowest layer.
+    base: bool,
+}
+
+struct Layer {
+    provider: Box<dyn WatchProvider + Sync + Send>,
+    data: Arc<Messages>,
+}
+
+impl CompositeProvider {
+    /// Provider without layers.
+    pub fn new() -> Self {
+        CompositeProvider {
+            layers: vec![],
+            data: Arc::new(Messages::default()),
+            listener: None,
+            errors: ErrorReporter::default(),
+            base: true,
+        }
+    }
+
+    /// Add the layer, it overrides all previous layers.
+    pub fn layer(mut self, provider: Box<dyn WatchProvider + Sync + Send>) -> Self {
+        self.layers.push(Layer { provider, data: Arc::new(Messages::default()) });
+        self
+    }
+
+    /// Add the layer with the data loaded before, e.g. the holder of the file in other folder.
+    pub(crate) fn layer_with_data(mut self, provider: Box<dyn WatchProvider + Sync + Send>, data: Arc<Messages>) -> Self {
+        self.layers.push(Layer { provider, data });
+        self
+    }
+
+    /// The holder's data is rebuilt only from the layers, so the keys removed from all layers are removed from the holder,
+    /// and the statistics of the layers (see [crate::Messages::stats]) are merged into the holder's data. Used by the layered holders.
+    pub(crate) fn without_base(mut self) -> Self {
+        self.base = false;
+        self
+    }
+}
+
+impl Default for CompositeProvider {
+    fn default() -> Self {
+        CompositeProvider::new()
+    }
+}
+
+/// Merging of the layers, shared with the listeners of the layers.
+/// References are weak, so the refresh threads of the layers stop when the holder is dropped.
+struct Layering {
+    base: HashMap<String, Arc<str>>,
+    layers: Vec<Weak<Messages>>,
+    target: Weak<Messages>,
+    listener: Option<ChangeListener>,
+    /// Merge the statistics of the layers into the target, see [CompositeProvider::without_base].
+    stats: bool,
+}
+
+impl Layering {
+    fn apply(&self) {
+        let target = match self.target.upgrade() {
+            None => return,
+            Some(target) => target,
+        };
+        let mut merged = self.base.clone();
+        for layer in self.layers.iter().filter_map(|l| l.upgrade()) {
+            merged.extend(layer.load().iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        target.store(merged);
+        self.merge_stats(&target);
+        if let Some(listener) = &self.listener {
+            listener();
+        }
+    }
+
+    /// Statistics of the target by the layers, after the reload and after the failure of the layer.
+    fn merge_stats(&self, target: &Messages) {
+        if self.stats {
+            let stats = ReloadStats::merged(self.layers.iter().filter_map(|l| l.upgrade()).map(|l| l.stats()));
+            target.record(|current| *current = stats);
+        }
+    }
+}
+
+impl WatchProvider for CompositeProvider {
+    fn watch(&mut self) -> Result<(), Error> {
+        let layering = Arc::new(Layering {
+            base: match self.base {
+                true => HashMap::clone(&self.data.load()),
+                false => HashMap::new(),
+            },
+            layers: self.layers.iter().map(|l| Arc::downgrade(&l.data)).collect(),
+            target: Arc::downgrade(&self.data),
+            listener: self.listener.clone(),
+            stats: !self.base,
+        });
+        for layer in self.layers.iter_mut() {
+            let (changes, failures, errors) = (Arc::clone(&layering), Arc::clone(&layering), self.errors.clone());
+            layer.provider.set_data(Arc::clone(&layer.data))?;
+            layer.provider.set_listener(Arc::new(move || changes.apply()));
+            // The error listener of the holder may be set after the watching has been started.
+            layer.provider.set_error_listener(Arc::new(move |e: &Error| {
+                if let Some(target) = failures.target.upgrade() {
+                    failures.merge_stats(&target);
+                }
+                if let Some(listener) = errors.listener() {
+                    listener(e);
+                }
+            }));
+            layer.provider.watch()?;
+        }
+        layering.apply();
+        Ok(())
+    }
+
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
+        self.data = data;
+        Ok(())
+    }
+
+    fn set_listener(&mut self, listener: ChangeListener) {
+        self.listener = Some(listener);
+    }
+
+    fn set_error_listener(&mut self, listener: ErrorListener) {
+        self.errors.set(listener);
+    }
+
+    #[cfg(feature = "watch")]
+    fn set_folder_watcher(&mut self, watcher: FolderWatcher) {
+        for layer in self.layers.iter_mut() {
+            layer.provider.set_folder_watcher(watcher.clone());
+        }
+    }
+
+    fn unwatch(&mut self) -> Result<(), Error> {
+        let mut result = Ok(());
+        for layer in self.layers.iter_mut() {
+            if let Err(e) = layer.provider.unwatch() {
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,62 @@
+//! Compressed embedded files of `incl_dir` and `rust_embed`, features `gzip` and `zstd`.
+//! The compression is detected by the extension (`.gz`, `.zst`) or by the magic bytes of the content.
+
+use std::borrow::Cow;
+#[cfg(any(feature = "gzip", feature = "zstd"))]
+use std::io::Read;
+
+use crate::Error;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// Compression of the embedded file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compression {
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    fn detect(path: &str, content: &[u8]) -> Option<Compression> {
+        if path.ends_with(".gz") || content.starts_with(GZIP_MAGIC) {
+            Some(Compression::Gzip)
+        } else if path.ends_with(".zst") || content.starts_with(ZSTD_MAGIC) {
+            Some(Compression::Zstd)
+        } else {
+            None
+        }
+    }
+}
+
+/// Content of the file, decompressed if it is compressed. Plain content is borrowed.
+/// If the feature of the compression is not enabled, return [Error::IoError].
+pub(crate) fn decompress<'a>(path: &str, content: &'a [u8]) -> Result<Cow<'a, [u8]>, Error> {
+    let compression = match Compression::detect(path, content) {
+        None => return Ok(Cow::Borrowed(content)),
+        Some(compression) => compression,
+    };
+    log::trace!("Decompressing {} by {:?}.", path, compression);
+    let error = |cause: std::io::Error| Error::IoError { path: path.to_string(), cause };
+    match compression {
+        #[cfg(feature = "gzip")]
+        Compression::Gzip => {
+            let mut data = Vec::new();
+            flate2::read::GzDecoder::new(content).read_to_end(&mut data).map_err(error)?;
+            Ok(Cow::Owned(data))
+        }
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => {
+            let mut data = Vec::new();
+            let mut decoder = ruzstd::decoding::StreamingDecoder::new(content)
+                .map_err(|e| error(std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string())))?;
+            decoder.read_to_end(&mut data).map_err(error)?;
+            Ok(Cow::Owned(data))
+        }
+        #[allow(unreachable_patterns)]
+        other => {
+            let feature = if other == Compression::Gzip { "gzip" } else { "zstd" };
+            Err(error(std::io::Error::new(std::io::ErrorKind::Unsupported, format!("File is compressed, enable feature `{}`.", feature))))
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Provider for locale data stored in Postgres or MySQL table `(locale, key, value)`, based on `sqlx`.
+//! The rows of the holder's locale replace the holder's data. The table is polled for changes,
+//! for Postgres the provider can also listen the channel (`LISTEN/NOTIFY`) to pick up the changes immediately.
+//!
+//! ```sql
+//! CREATE TABLE i18n (locale TEXT NOT NULL, key TEXT NOT NULL, value TEXT NOT NULL);
+//! -- After editing the strings
+//! NOTIFY i18n_changed;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::mpsc::{channel, Sender};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use sqlx::any::AnyPoolOptions;
+use sqlx::postgres::PgListener;
+use sqlx::{AnyPool, Row};
+
+use crate::locale::{language, normalize};
+use crate::{ChangeListener, Error, ErrorListener, ErrorReporter, Messages, WatchProvider};
+
+/// Loads the key/value rows of the locale from the database table and refreshes them.
+/// The provider runs on its own thread, so it can be added both from sync code and from async runtime.
+/// The thread stops when the holder is dropped. With feature `tokio` it can run on the application runtime, see [DatabaseProvider::on_runtime].
+///
+/// # Examples
+///
+/// ```no_run
+/// use sorrow_i18n::InternationalCore;
+/// use sorrow_i18n::database::DatabaseProvider;
+/// let mut core = InternationalCore::new("locale/");
+/// let provider = DatabaseProvider::new("postgres://localhost/app", "EN").listen("i18n_changed").unwrap();
+/// core.add_provider("EN", Box::new(provider)).unwrap();
+/// ```
+pub struct DatabaseProvider {
+    url: String,
+    table: String,
+    locale: String,
+    interval: Duration,
+    channel: Option<String>,
+    data: Arc<Messages>,
+    listener: Option<ChangeListener>,
+    stopped: Arc<AtomicBool>,
+    errors: ErrorReporter,
+    #[cfg(feature = "tokio")]
+    handle: Option<tokio::runtime::Handle>,
+}
+
+impl DatabaseProvider {
+    /// Provider for the database `url` (`postgres://...` or `mysql://...`) and locale. Table `i18n`, polling interval 30 seconds.
+    pub fn new(url: &str, locale: &str) -> Self {
+        DatabaseProvider {
+            url: url.to_string(),
+            table: "i18n".to_string(),
+            locale: normalize(locale),
+            interval: Duration::from_secs(30),
+            channel: None,
+            data: Arc::new(Messages::default()),
+            listener: None,
+            stopped: Arc::new(AtomicBool::new(false)),
+            errors: ErrorReporter::default(),
+            #[cfg(feature = "tokio")]
+            handle: None,
+        }
+    }
+
+    /// Table with columns `locale`, `key` and `value`.
+    pub fn table(mut self, table: &str) -> Self {
+        self.table = table.to_string();
+        self
+    }
+
+    /// Polling interval.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Reload the data on notifications in the channel, Postgres only. Polling continues as well.
+    /// For other databases (e.g. `mysql://...`) returns [Error::ProviderError].
+    pub fn listen(mut self, channel: &str) -> Result<Self, Error> {
+        if !self.url.starts_with("postgres") {
+            return Err(Error::ProviderError {
+                provider: format!("DatabaseProvider({})", &self.locale),
+                cause: "LISTEN/NOTIFY is supported only by Postgres (postgres://...).".to_string(),
+            });
+        }
+        self.channel = Some(channel.to_string());
+        Ok(self)
+    }
+
+    /// Run the provider on the runtime of `handle` instead of its own thread, feature `tokio`.
+    /// The initial load is not awaited by [WatchProvider::watch], its error is passed to the error listener.
+    #[cfg(feature = "tokio")]
+    pub fn on_runtime(mut self, handle: tokio::runtime::Handle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Query of the rows of the locale's language (the locales are compared exactly after normalizing, see [Source::load]),
+    /// identifiers are quoted and the parameter is written by the database kind.
+    fn query(&self) -> String {
+        if self.url.starts_with("mysql") {
+            format!("SELECT `locale`, `key`, `value` FROM `{}` WHERE LOWER(TRIM(`locale`)) LIKE ?", self.table.replace('`', "``"))
+        } else {
+            format!("SELECT \"locale\", \"key\", \"value\" FROM \"{}\" WHERE LOWER(TRIM(\"locale\")) LIKE $1", self.table.replace('"', "\"\""))
+        }
+    }
+}
+
+/// Provider settings moved to the provider's thread.
+struct Source {
+    url: String,
+    query: String,
+    locale: String,
+    interval: Duration,
+    channel: Option<String>,
+    listener: Option<ChangeListener>,
+    stopped: Arc<AtomicBool>,
+    errors: ErrorReporter,
+}
+
+impl Source {
+    fn error<E: std::fmt::Display>(&self, e: E) -> String {
+        log::error!("Error while loading locale {} from database. Message: {}", &self.locale, e);
+        e.to_string()
+    }
+
+    /// Pass the error while refreshing to the error listener.
+    fn report(&self, e: sqlx::Error) {
+        self.errors.report(Error::ProviderError { provider: format!("DatabaseProvider({})", &self.locale), cause: e.to_string() });
+    }
+
+    /// Rows of the table by locale. The database returns the rows of the locale's language,
+    /// the locales of the rows are normalized before comparing (e.g. `en_US` and `en-us`).
+    async fn load(&self, pool: &AnyPool) -> Result<HashMap<String, String>, sqlx::Error> {
+        let rows = sqlx::query(&self.query).bind(format!("{}%", language(&self.locale))).fetch_all(pool).await?;
+        let mut messages = HashMap::new();
+        for row in rows {
+            let locale: String = row.try_get(0)?;
+            if normalize(&locale) == self.locale {
+                messages.insert(row.try_get(1)?, row.try_get(2)?);
+            }
+        }
+        Ok(messages)
+    }
+
+    /// Initial load, then refresh while the holder exists. The result of the initial load is sent to `started`.
+    async fn run(self, holder: Weak<Messages>, started: Sender<Result<(), String>>) {
+        sqlx::any::install_default_drivers();
+        let pool = match AnyPoolOptions::new().max_connections(1).connect(&self.url).await {
+            Ok(pool) => pool,
+            Err(e) => {
+                let _ = started.send(Err(self.error(e)));
+                return;
+            }
+        };
+        let mut listener = match &self.channel {
+            None => None,
+            Some(channel) => {
+                let listener = match PgListener::connect(&self.url).await {
+                    Ok(mut listener) => listener.listen(channel).await.map(|_| listener),
+                    Err(e) => Err(e),
+                };
+                match listener {
+                    Ok(listener) => Some(listener),
+                    Err(e) => {
+                        let _ = started.send(Err(self.error(e)));
+                        return;
+                    }
+                }
+            }
+        };
+        match (self.load(&pool).await, holder.upgrade()) {
+            (Ok(messages), Some(holder)) => {
+                holder.store(messages);
+                let _ = started.send(Ok(()));
+            }
+            (Ok(_), None) => return,
+            (Err(e), _) => {
+                let _ = started.send(Err(self.error(e)));
+                return;
+            }
+        }
+
+        loop {
+            match &mut listener {
+                None => tokio::time::sleep(self.interval).await,
+                Some(listener) => {
+                    if let Ok(Err(e)) = tokio::time::timeout(self.interval, listener.recv()).await {
+                        self.report(e);
+                        tokio::time::sleep(self.interval).await;
+                    }
+                }
+            }
+            if self.stopped.load(Ordering::Relaxed) {
+                break;
+            }
+            let holder = match holder.upgrade() {
+                None => break,
+                Some(holder) => holder,
+            };
+            match self.load(&pool).await {
+                Ok(messages) => {
+                    if holder.to_map() != messages {
+                        log::debug!("Locale {} has been changed in database. Reloading data.", &self.locale);
+                        holder.store(messages);
+                        if let Some(listener) = &self.listener {
+                            listener();
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.report(e);
+                }
+            }
+        }
+        log::trace!("Provider of {} has been stopped.", &self.locale);
+    }
+}
+
+impl WatchProvider for DatabaseProvider {
+    fn watch(&mut self) -> Result<(), Error> {
+        let source = Source {
+            url: self.url.clone(),
+            query: self.query(),
+            locale: self.locale.clone(),
+            interval: self.interval,
+            channel: self.channel.clone(),
+            listener: self.listener.clone(),
+            stopped: Arc::clone(&self.stopped),
+            errors: self.errors.clone(),
+        };
+        let holder = Arc::downgrade(&self.data);
+        let (started, result) = channel();
+        #[cfg(feature = "tokio")]
+        if let Some(handle) = &self.handle {
+            let (errors, provider) = (self.errors.clone(), format!("DatabaseProvider({})", &self.locale));
+            handle.spawn(source.run(holder, started));
+            // The runtime thread is not blocked by waiting for the initial load.
+            handle.spawn_blocking(move || {
+                if let Ok(Err(cause)) = result.recv() {
+                    errors.report(Error::ProviderError { provider, cause });
+                }
+            });
+            return Ok(());
+        }
+        std::thread::spawn(move || {
+            match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => runtime.block_on(source.run(holder, started)),
+                Err(e) => {
+                    let _ = started.send(Err(e.to_string()));
+                }
+            }
+        });
+        let result = result.recv().unwrap_or_else(|_| Err("provider thread has been stopped.".to_string()));
+        result.map_err(|cause| Error::ProviderError { provider: format!("DatabaseProvider({})", &self.locale), cause })
+    }
+
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
+        self.data = data;
+        Ok(())
+    }
+
+    fn set_listener(&mut self, listener: ChangeListener) {
+        self.listener = Some(listener);
+    }
+
+    fn set_error_listener(&mut self, listener: ErrorListener) {
+        self.errors.set(listener);
+    }
+
+    fn unwatch(&mut self) -> Result<(), Error> {
+        self.stopped.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+}
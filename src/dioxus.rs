@@ -0,0 +1,81 @@
+//! Integration with Dioxus. [use_i18n_provider] shares the core and the reactive signal of the current locale by the context,
+//! components reading the messages are re-rendered when the locale is switched.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use dioxus::prelude::*;
+//! use sorrow_i18n::dioxus::{use_i18n, use_i18n_provider};
+//! use sorrow_i18n::InternationalCore;
+//!
+//! fn Greeting() -> Element {
+//!     let i18n = use_i18n();
+//!     rsx! {
+//!         button { onclick: move |_| i18n.set_locale("ru"), {i18n.t("data.name")} }
+//!     }
+//! }
+//!
+//! fn App() -> Element {
+//!     use_i18n_provider(|| Arc::new(InternationalCore::new("locale/")), "en");
+//!     rsx! { Greeting {} }
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use dioxus::prelude::*;
+
+use crate::{Data, GetData, InternationalCore};
+
+/// Context of [use_i18n_provider]. Reading the messages subscribes the component to the locale signal.
+#[derive(Clone, Copy)]
+pub struct I18n {
+    core: CopyValue<Arc<InternationalCore>>,
+    locale: Signal<String>,
+}
+
+impl I18n {
+    /// The core.
+    pub fn core(&self) -> Arc<InternationalCore> {
+        self.core.cloned()
+    }
+
+    /// Signal of the current locale.
+    pub fn locale(&self) -> Signal<String> {
+        self.locale
+    }
+
+    /// Switch the current locale.
+    pub fn set_locale(&self, locale: &str) {
+        let mut signal = self.locale;
+        signal.set(locale.to_string())
+    }
+
+    /// [Data] by the current locale.
+    pub fn data(&self) -> Option<Data> {
+        let locale = self.locale.read();
+        self.core.read().get_by_locale(&locale)
+    }
+
+    /// Message by key in the current locale, or the key itself.
+    pub fn t(&self, key: &str) -> String {
+        match self.data() {
+            None => key.to_string(),
+            Some(data) => data.get_or_default(key),
+        }
+    }
+}
+
+/// Provide [I18n] to the children of the current component, `locale` is the initial current locale.
+/// The core is created once, on the first render.
+pub fn use_i18n_provider<F: FnOnce() -> Arc<InternationalCore>>(core: F, locale: &str) -> I18n {
+    use_context_provider(|| I18n { core: CopyValue::new(core()), locale: Signal::new(locale.to_string()) })
+}
+
+/// Context of [use_i18n_provider].
+///
+/// # Panics
+///
+/// If [use_i18n_provider] has not been called by the parents.
+pub fn use_i18n() -> I18n {
+    use_context::<I18n>()
+}
@@ -0,0 +1,94 @@
+//! Helpers for egui. The core and the current locale are stored in the data of [Context] by [install],
+//! so any widget can translate by [tr] and [language_picker] switches the locale.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use sorrow_i18n::InternationalCore;
+//!
+//! let core = Arc::new(InternationalCore::new("locale/"));
+//! let ctx = egui::Context::default();
+//! sorrow_i18n::egui::install(&ctx, core, "en");
+//! let _ = ctx.run(egui::RawInput::default(), |ctx| {
+//!     egui::CentralPanel::default().show(ctx, |ui| {
+//!         sorrow_i18n::egui::language_picker(ui);
+//!         ui.heading(sorrow_i18n::egui::tr(ctx, "data.title"));
+//!     });
+//! });
+//! ```
+
+use std::sync::Arc;
+
+use egui::{Context, Id, Response, Sense, Ui, Vec2};
+
+use crate::{GetData, InternationalCore};
+
+/// The core and the current locale of the context.
+#[derive(Clone)]
+pub struct I18n {
+    /// The core
+    pub core: Arc<InternationalCore>,
+    /// Current locale
+    pub locale: String,
+}
+
+fn id() -> Id {
+    Id::new("sorrow_i18n")
+}
+
+/// Store the core and the current locale in the context.
+pub fn install(ctx: &Context, core: Arc<InternationalCore>, locale: &str) {
+    ctx.data_mut(|d| d.insert_temp(id(), I18n { core, locale: locale.to_string() }));
+}
+
+/// The core and the current locale, if [install] has been called.
+pub fn i18n(ctx: &Context) -> Option<I18n> {
+    ctx.data(|d| d.get_temp::<I18n>(id()))
+}
+
+/// Current locale of the context.
+pub fn locale(ctx: &Context) -> Option<String> {
+    i18n(ctx).map(|i| i.locale)
+}
+
+/// Switch the locale of the context. Nothing happens, if [install] has not been called.
+pub fn set_locale(ctx: &Context, locale: &str) {
+    if let Some(mut i18n) = i18n(ctx) {
+        i18n.locale = locale.to_string();
+        ctx.data_mut(|d| d.insert_temp(id(), i18n));
+        ctx.request_repaint();
+    }
+}
+
+/// Message by key in the current locale, or the key itself.
+pub fn tr(ctx: &Context, key: &str) -> String {
+    match i18n(ctx).and_then(|i| i.core.get_by_locale(&i.locale)) {
+        None => key.to_string(),
+        Some(data) => data.get_or_default(key),
+    }
+}
+
+/// Combo box of the loaded locales (see [InternationalCore::locales]) labeled by their description.
+/// The response is marked as changed, when the locale has been switched.
+pub fn language_picker(ui: &mut Ui) -> Response {
+    let Some(i18n) = i18n(ui.ctx()) else {
+        return ui.allocate_response(Vec2::ZERO, Sense::hover());
+    };
+    let label = |locale: &str| i18n.core.locale_description(locale).unwrap_or_else(|| locale.to_string());
+    let mut selected = i18n.core.locales().into_iter()
+        .find(|l| l.eq_ignore_ascii_case(&i18n.locale))
+        .unwrap_or_else(|| i18n.locale.clone());
+    let mut response = egui::ComboBox::from_id_salt(id())
+        .selected_text(label(&selected))
+        .show_ui(ui, |ui| {
+            for locale in i18n.core.locales() {
+                let text = label(&locale);
+                ui.selectable_value(&mut selected, locale, text);
+            }
+        })
+        .response;
+    if !selected.eq_ignore_ascii_case(&i18n.locale) {
+        set_locale(ui.ctx(), &selected);
+        response.mark_changed();
+    }
+    response
+}
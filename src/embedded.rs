@@ -0,0 +1,106 @@
+//! Lookup of the embedded catalogs, for targets without std (disable the default features, only `alloc` is required).
+//! The catalog is a static table of keys and messages, e.g. written by hand or generated by a build script,
+//! so there is no file IO, parsing and watching. The messages are formatted as [crate::GetData] does.
+//!
+//! ```
+//! use sorrow_i18n::embedded::{Catalog, Catalogs};
+//! static EN: Catalog = Catalog::new("en", &[("data.name", "Test"), ("data.greeting", "Hello, {name}!")]);
+//! static RU: Catalog = Catalog::new("ru", &[("data.name", "Тест")]);
+//! static CATALOGS: Catalogs = Catalogs::new(&[EN, RU]);
+//!
+//! let ru = CATALOGS.get_by_locale("ru-RU").unwrap();
+//! assert_eq!("Тест", ru.get_or_default("data.name"));
+//! assert_eq!("Hello, Bob!", CATALOGS.get_by_locale("EN").unwrap().get_with_args("data.greeting", &[("name", &"Bob")]));
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
+use crate::format::{FormatOptions, Template};
+use crate::locale::{normalize, truncations};
+use crate::plural::{ordinal_category, PluralCategory};
+
+/// Messages of one locale. Keys are full paths, as the keys of the holder (`data.name`).
+/// Keys are searched linearly, the catalog is expected to be small.
+#[derive(Debug, Clone, Copy)]
+pub struct Catalog {
+    locale: &'static str,
+    messages: &'static [(&'static str, &'static str)],
+}
+
+impl Catalog {
+    /// Catalog of the locale, the locale is compared normalized (see [crate::locale::normalize]).
+    pub const fn new(locale: &'static str, messages: &'static [(&'static str, &'static str)]) -> Catalog {
+        Catalog { locale, messages }
+    }
+
+    /// Locale of the catalog, as it is passed to [Catalog::new].
+    pub fn locale(&self) -> &'static str {
+        self.locale
+    }
+
+    /// Getting message by key. If key does not exist, return [Option::None].
+    pub fn get(&self, key: &str) -> Option<&'static str> {
+        self.messages.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+    }
+
+    /// Getting message by key, or the key itself.
+    pub fn get_or_default<'a>(&self, key: &'a str) -> &'a str {
+        self.get(key).unwrap_or(key)
+    }
+
+    /// Message with `{name}` placeholders replaced by arguments, see [crate::format::interpolate]. If key does not exist, return the key.
+    pub fn get_with_args(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        match self.get(key) {
+            None => key.to_string(),
+            Some(message) => Template::new(message).render(args, &FormatOptions::default()),
+        }
+    }
+
+    /// Ordinal form of the message by `n`, keys of the forms are `key.one`, `key.two`, `key.few` and `key.other` (see [crate::plural]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::embedded::Catalog;
+    /// let en = Catalog::new("en", &[("place.one", "{n}st"), ("place.other", "{n}th")]);
+    /// assert_eq!("21st", en.get_ordinal("place", 21));
+    /// assert_eq!("4th", en.get_ordinal("place", 4));
+    /// ```
+    pub fn get_ordinal(&self, key: &str, n: u64) -> String {
+        let category = ordinal_category(self.locale, n);
+        self.get(&format!("{}.{}", key, category.as_str()))
+            .or_else(|| self.get(&format!("{}.{}", key, PluralCategory::Other.as_str())))
+            .map(|message| Template::new(message).render(&[("n", &n)], &FormatOptions::default()))
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    /// Keys of the catalog, in order of the table.
+    pub fn keys(&self) -> impl Iterator<Item = &'static str> {
+        self.messages.iter().map(|(k, _)| *k)
+    }
+}
+
+/// Catalogs of the locales.
+#[derive(Debug, Clone, Copy)]
+pub struct Catalogs {
+    catalogs: &'static [Catalog],
+}
+
+impl Catalogs {
+    /// Catalogs of the locales, the first catalog with the locale is used.
+    pub const fn new(catalogs: &'static [Catalog]) -> Catalogs {
+        Catalogs { catalogs }
+    }
+
+    /// Catalog by locale. If the locale is not found, the truncated locale is used (`en-GB` -> `en`), see [crate::locale::truncations].
+    pub fn get_by_locale(&self, locale: &str) -> Option<&Catalog> {
+        truncations(locale).iter().find_map(|locale| self.catalogs.iter().find(|c| normalize(c.locale) == *locale))
+    }
+
+    /// Locales of the catalogs, normalized.
+    pub fn locales(&self) -> impl Iterator<Item = String> + '_ {
+        self.catalogs.iter().map(|c| normalize(c.locale))
+    }
+}
@@ -0,0 +1,67 @@
+//! Events of the data changes. UI layers can re-render only when translations actually change.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::{ChangeListener, Holder};
+
+/// The data of the holder has been changed.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ReloadEvent {
+    /// Locale of the holder
+    pub locale: String,
+    /// Namespace of the holder, see [crate::InternationalCore::namespace]
+    pub namespace: Option<String>,
+    /// Added, changed and removed keys, sorted
+    pub keys: Vec<String>,
+}
+
+/// Callbacks of the core.
+pub(crate) type Subscribers = Arc<RwLock<Vec<Box<dyn Fn(&ReloadEvent) + Send + Sync>>>>;
+
+/// Keys which have been added, changed or removed, sorted.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use sorrow_i18n::events::changed_keys;
+/// let before = HashMap::from([("a".to_string(), "1".to_string()), ("b".to_string(), "2".to_string())]);
+/// let after = HashMap::from([("a".to_string(), "1".to_string()), ("c".to_string(), "3".to_string())]);
+/// assert_eq!(vec!["b", "c"], changed_keys(&before, &after));
+/// ```
+pub fn changed_keys<V: PartialEq>(before: &HashMap<String, V>, after: &HashMap<String, V>) -> Vec<String> {
+    let mut keys = after.iter()
+        .filter(|(k, v)| before.get(*k) != Some(*v))
+        .map(|(k, _)| k.clone())
+        .chain(before.keys().filter(|k| !after.contains_key(*k)).cloned())
+        .collect::<Vec<String>>();
+    keys.sort();
+    keys
+}
+
+/// Listener of the holder, which compares the data with the last snapshot and notifies the subscribers.
+pub(crate) fn listener(holder: &Holder, subscribers: Subscribers) -> ChangeListener {
+    let messages = Arc::downgrade(&holder.messages);
+    let snapshot = Mutex::new(holder.messages.load());
+    let locale = holder.locale.clone();
+    let namespace = holder.namespace.clone();
+    Arc::new(move || {
+        let messages = match messages.upgrade() {
+            None => return,
+            Some(messages) => messages,
+        };
+        let current = messages.load();
+        let mut snapshot = snapshot.lock().unwrap();
+        let keys = changed_keys(&snapshot, &current);
+        *snapshot = current;
+        drop(snapshot);
+        if keys.is_empty() {
+            return;
+        }
+        let event = ReloadEvent { locale: locale.clone(), namespace: namespace.clone(), keys };
+        for subscriber in subscribers.read().unwrap().iter() {
+            subscriber(&event);
+        }
+    })
+}
@@ -1,21 +1,37 @@
-use crate::{GetData, InternationalCore, WatchProvider};
+use crate::{get_current_locale_or_default, Error, GetData, InternationalCore, LocalizedString, WatchProvider};
+use std::borrow::Cow;
+use std::fmt::Display;
+use crate::locale::scoped_locale;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::sync::{RwLock};
 use once_cell::sync::Lazy;
 #[cfg(feature = "incl_dir")]
 use include_dir::Dir;
 
-static I18N_CORE: Lazy<RwLock<Vec<InternationalCore>>> = Lazy::new(|| { RwLock::new(vec![]) });
+/// Name of the core initialized without name, used by the macros without `core = ...` argument.
+pub const DEFAULT_CORE: &str = "default";
+
+static I18N_CORE: Lazy<RwLock<HashMap<String, InternationalCore>>> = Lazy::new(|| { RwLock::new(HashMap::new()) });
+
+static CURRENT_LOCALE: Lazy<RwLock<Option<String>>> = Lazy::new(|| { RwLock::new(None) });
 
 /// We statically initialize our core. In case of reinitialization, we panic.
+/// Returns the loading error, see [InternationalCore::try_new].
 ///
 /// # Arguments
 ///
-/// * Arg - locale folder.
+/// * First argument - name of the core, optional. Libraries and applications can own their cores in one process,
+///   the other macros select the core by `core = "name"`. Without the name the core is [DEFAULT_CORE].
+/// * Second argument - locale folder.
 ///
 /// # Examples
 ///
-/// ```
-/// init_i18n!("locale/");
+/// ```no_run
+/// # use sorrow_i18n::{i18n, init_i18n};
+/// init_i18n!("locale/").unwrap();
+/// init_i18n!("ui", "ui/locale/").unwrap();
+/// let title = i18n!(core = "ui", "EN", "menu.title");
 /// ```
 /// Run function `sorrow_i18n::feature_macro::init`
 #[macro_export]
@@ -24,7 +40,12 @@ macro_rules! init_i18n {
         {
             $crate::feature_macro::init($path)
         }
-    }
+    };
+    ($name:expr, $path:expr) => {
+        {
+            $crate::feature_macro::init_named($name, $path)
+        }
+    };
 }
 
 /// Analogue `init_i18n!` but for feature `incl_dir`
@@ -35,30 +56,55 @@ macro_rules! init_i18n_static_dir {
         {
             $crate::feature_macro::init_dir($dir)
         }
-    }
+    };
+    ($name:expr, $dir:expr) => {
+        {
+            $crate::feature_macro::init_dir_named($name, $dir)
+        }
+    };
 }
 
 /// Get a value from the store using the locale and key.
 ///
 /// # Arguments
-/// * First argument - locale
+/// * `core = "name"` - optional, the core initialized by `init_i18n!("name", path)`.
+/// * First argument - locale, optional. If only the key is passed, the current locale is used, see [set_current_locale].
 /// * Second argument - key
 ///
 /// # Examples
-/// ```
+/// ```no_run
+/// # use sorrow_i18n::{i18n, init_i18n};
 ///  // First init core
-///  init_i18n!("locale/");
+///  init_i18n!("locale/").unwrap();
 ///  // Getting data
 ///  let test = i18n!("RU", "data.name");
 ///  assert_eq!("Тест", &*test);
 ///  // / If the key is not found or the locale is not found, return the passed key.
 ///  let not_found_data = i18n!("RU", "data.not_found_me");
 ///  assert_eq!("data.not_found_me", &*not_found_data);
+///  // Using the current locale
+///  sorrow_i18n::feature_macro::set_current_locale("RU");
+///  assert_eq!("Тест", &*i18n!("data.name"));
 /// ```
 ///
 /// Run function `crate::feature_macro::get_param`
 #[macro_export]
 macro_rules! i18n {
+    (core = $core:expr, $key:expr) => {
+        {
+            $crate::feature_macro::get_param_in($core, &$crate::feature_macro::get_current_locale_in($core), $key)
+        }
+    };
+    (core = $core:expr, $locale:expr, $key:expr) => {
+        {
+            $crate::feature_macro::get_param_in($core, $locale, $key)
+        }
+    };
+    ($key:expr) => {
+        {
+            $crate::feature_macro::get_param(&$crate::feature_macro::get_current_locale(), $key)
+        }
+    };
     ($locale:expr, $key:expr) => {
         {
             $crate::feature_macro::get_param($locale, $key)
@@ -66,22 +112,89 @@ macro_rules! i18n {
     };
 }
 
+/// Get a value from the store by key in the current locale (see [get_current_locale]), the fallback chain is honored.
+/// The named arguments are interpolated to the `{name}` placeholders, see [crate::GetData::get_with_args].
+///
+/// # Arguments
+/// * `core = "name"` - optional, the core initialized by `init_i18n!("name", path)`.
+/// * First argument - key
+/// * Other arguments - optional, `name = value`, the value implements `Display`
+///
+/// # Examples
+/// ```no_run
+/// # use sorrow_i18n::{init_i18n, t};
+///  init_i18n!("locale/").unwrap();
+///  sorrow_i18n::feature_macro::set_current_locale("EN");
+///  assert_eq!("Test", t!("data.name"));
+///  // greeting: "Hello, {name}!"
+///  assert_eq!("Hello, Bob!", t!("data.greeting", name = "Bob"));
+/// ```
+///
+/// Run function `crate::feature_macro::get_param_with_args`
+#[macro_export]
+macro_rules! t {
+    (core = $core:expr, $key:expr) => {
+        {
+            $crate::feature_macro::get_param_in($core, &$crate::feature_macro::get_current_locale_in($core), $key)
+        }
+    };
+    (core = $core:expr, $key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        {
+            $crate::feature_macro::get_param_with_args_in(
+                $core,
+                &$crate::feature_macro::get_current_locale_in($core),
+                $key,
+                &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+            )
+        }
+    };
+    ($key:expr) => {
+        {
+            $crate::feature_macro::get_param(&$crate::feature_macro::get_current_locale(), $key)
+        }
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        {
+            $crate::feature_macro::get_param_with_args(
+                &$crate::feature_macro::get_current_locale(),
+                $key,
+                &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+            )
+        }
+    };
+}
+
 /// Setting custom provider by holder.
 ///
 /// # Arguments
+/// * `core = "name"` - optional, the core initialized by `init_i18n!("name", path)`.
 /// * first argument - locale
 /// * second argument - provider
 ///
 /// # Examples
-/// ```rust
+/// ```no_run
 ///     use sorrow_i18n::{init_i18n, set_i18n_provider};
-///     init_i18n!("locale/");
+/// #   use std::sync::Arc;
+/// #   use sorrow_i18n::Messages;
+/// #   use sorrow_i18n::{Error, WatchProvider};
+/// #   pub struct CustomProvider;
+/// #   impl CustomProvider { fn new() -> Self { CustomProvider } }
+/// #   impl WatchProvider for CustomProvider {
+/// #       fn watch(&mut self) -> Result<(), Error> { Ok(()) }
+/// #       fn set_data(&mut self, _data: Arc<Messages>) -> Result<(), Error> { Ok(()) }
+/// #   }
+///     init_i18n!("locale/").unwrap();
 ///     let provider = Box::new(CustomProvider::new());
 ///     set_i18n_provider!("EE", provider);
 /// ```
 /// [Full example](https://github.com/SinmoWay/simple-i18n/blob/main/examples/macro_with_custom_provider.rs)
 #[macro_export]
 macro_rules! set_i18n_provider {
+    (core = $core:expr, $locale:expr, $provider:expr) => {
+        {
+            $crate::feature_macro::set_provider_in($core, $locale, $provider)
+        }
+    };
     ($locale:expr, $provider:expr) => {
         {
             $crate::feature_macro::set_provider($locale, $provider)
@@ -89,53 +202,121 @@ macro_rules! set_i18n_provider {
     }
 }
 
+/// Setting the fallback chain for locale in the static core.
+///
+/// # Arguments
+/// * `core = "name"` - optional, the core initialized by `init_i18n!("name", path)`.
+/// * first argument - locale
+/// * second argument - fallback locales, in lookup order
+///
+/// # Examples
+/// ```no_run
+///     use sorrow_i18n::{init_i18n, set_i18n_fallback_chain};
+///     init_i18n!("locale/").unwrap();
+///     set_i18n_fallback_chain!("fr-CA", &["fr", "en"]);
+/// ```
+#[macro_export]
+macro_rules! set_i18n_fallback_chain {
+    (core = $core:expr, $locale:expr, $chain:expr) => {
+        {
+            $crate::feature_macro::set_fallback_chain_in($core, $locale, $chain)
+        }
+    };
+    ($locale:expr, $chain:expr) => {
+        {
+            $crate::feature_macro::set_fallback_chain($locale, $chain)
+        }
+    }
+}
+
 /// We statically initialize our core. In case of reinitialization, we panic.
-pub fn init<S: AsRef<str>>(_path: S) {
-    check_empty_core();
-    let mut core_holder = I18N_CORE.write().unwrap();
-    let core = InternationalCore::new(_path.as_ref().to_string());
-    core_holder.insert(0, core);
+/// If the core has not been loaded, the error is returned and the core stays uninitialized.
+pub fn init<S: AsRef<str>>(_path: S) -> Result<(), Error> {
+    init_named(DEFAULT_CORE, _path)
+}
+
+/// Analogue `init`, the core is initialized by name. In case of reinitialization of the name, we panic.
+pub fn init_named<S: AsRef<str>>(name: &str, path: S) -> Result<(), Error> {
+    check_empty_core(name);
+    let core = InternationalCore::try_new(path.as_ref().to_string())?;
+    insert_core(name, core);
+    Ok(())
 }
 
 #[cfg(feature = "incl_dir")]
 /// Analogue `init` only for feature `incl_dir`
-pub fn init_dir(dir: Dir) {
-    check_empty_core();
-    let mut core_holder = I18N_CORE.write().unwrap();
-    let core = InternationalCore::from(dir);
-    core_holder.insert(0, core);
+pub fn init_dir(dir: Dir) -> Result<(), Error> {
+    init_dir_named(DEFAULT_CORE, dir)
+}
+
+#[cfg(feature = "incl_dir")]
+/// Analogue `init_named` only for feature `incl_dir`
+pub fn init_dir_named(name: &str, dir: Dir) -> Result<(), Error> {
+    check_empty_core(name);
+    let core = InternationalCore::try_from_dir(dir)?;
+    insert_core(name, core);
+    Ok(())
 }
 
-fn check_empty_core() {
+fn check_empty_core(name: &str) {
     let mut err = false;
 
     {
         let core = I18N_CORE.write().unwrap();
 
-        if !core.is_empty() {
-            log::error!("Double init I18N core.");
+        if core.contains_key(name) {
+            log::error!("Double init I18N core {}.", name);
             err = true;
         }
     }
 
 
     if err {
-        panic!("Error while init i18n core. Core has been init.");
+        panic!("Error while init i18n core. Core {} has been init.", name);
     }
 }
 
+/// Insert the loaded core. The name is checked again by the same lock, the core may have been initialized by other thread while loading.
+fn insert_core(name: &str, core: InternationalCore) {
+    let inserted = match I18N_CORE.write().unwrap().entry(name.to_string()) {
+        Entry::Occupied(_) => false,
+        Entry::Vacant(entry) => {
+            entry.insert(core);
+            true
+        }
+    };
+    // The lock is released before the panic, so it is not poisoned.
+    if !inserted {
+        log::error!("Double init I18N core {}.", name);
+        panic!("Error while init i18n core. Core {} has been init.", name);
+    }
+}
+
+/// Names of the initialized cores, sorted.
+pub fn cores() -> Vec<String> {
+    let mut names = I18N_CORE.read().unwrap().keys().cloned().collect::<Vec<String>>();
+    names.sort();
+    names
+}
+
 /// Get a value from the store using the locale and key.
 pub fn get_param(locale: &str, key: &str) -> String {
+    get_param_in(DEFAULT_CORE, locale, key)
+}
+
+/// Get a value from the named core using the locale and key. If the core is not found, return the passed key.
+/// If the locale or the key is not found, the value by [crate::MissingKeyPolicy] of the core (the key by default).
+pub fn get_param_in(core: &str, locale: &str, key: &str) -> String {
     let guard = I18N_CORE.read().unwrap();
 
-    match guard.get(0) {
+    match guard.get(core) {
         None => {
             key.to_string()
         }
         Some(c) => {
             match c.get_by_locale(locale) {
                 None => {
-                    key.to_string()
+                    c.missing_key(locale, key)
                 }
                 Some(h) => {
                     h.get_or_default(key)
@@ -145,16 +326,54 @@ pub fn get_param(locale: &str, key: &str) -> String {
     }
 }
 
+/// Get a value from the store using the locale and key, the arguments are interpolated, see [crate::GetData::get_with_args].
+/// If the locale is not found, the value by [crate::MissingKeyPolicy] of the core.
+pub fn get_param_with_args(locale: &str, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    get_param_with_args_in(DEFAULT_CORE, locale, key, args)
+}
+
+/// Analogue [get_param_with_args] for the named core.
+pub fn get_param_with_args_in(core: &str, locale: &str, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let guard = I18N_CORE.read().unwrap();
+
+    match guard.get(core) {
+        None => key.to_string(),
+        Some(c) => match c.get_by_locale(locale) {
+            None => c.missing_key(locale, key),
+            Some(h) => h.get_with_args(key, args),
+        },
+    }
+}
+
+/// Message of the static core by key, the value is looked up at formatting time in the current locale (see [get_current_locale]).
+///
+/// # Examples
+/// ```no_run
+/// # use sorrow_i18n::init_i18n;
+/// init_i18n!("locale/").unwrap();
+/// let title = sorrow_i18n::feature_macro::localize("data.name");
+/// sorrow_i18n::feature_macro::set_current_locale("RU");
+/// assert_eq!("Тест", title.to_string());
+/// ```
+pub fn localize<K: Into<Cow<'static, str>>>(key: K) -> LocalizedString {
+    LocalizedString::from_static(key)
+}
+
 /// Add custom provider for locale holder
 pub fn set_provider(locale: &str, provider: Box<dyn WatchProvider + 'static + Sync + Send>) {
+    set_provider_in(DEFAULT_CORE, locale, provider)
+}
+
+/// Add custom provider for locale holder of the named core
+pub fn set_provider_in(core: &str, locale: &str, provider: Box<dyn WatchProvider + 'static + Sync + Send>) {
     let mut guard = I18N_CORE.write().unwrap();
 
-    match guard.get_mut(0) {
+    match guard.get_mut(core) {
         None => {
-            panic!("The i18n core has not been created. Call the init_i18n! or init_i18n_static_dir! macro.");
+            panic!("The i18n core {} has not been created. Call the init_i18n! or init_i18n_static_dir! macro.", core);
         }
         Some(core) => {
-            match core.add_provider(&locale, provider) {
+            match core.add_provider(locale, provider) {
                 Ok(_) => {
                     log::debug!("Provider has been accepted for locale: {}", &locale)
                 }
@@ -165,4 +384,108 @@ pub fn set_provider(locale: &str, provider: Box<dyn WatchProvider + 'static + Sy
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Setting the fallback chain for locale
+pub fn set_fallback_chain<S: AsRef<str>>(locale: &str, chain: &[S]) {
+    set_fallback_chain_in(DEFAULT_CORE, locale, chain)
+}
+
+/// Setting the fallback chain for locale of the named core
+pub fn set_fallback_chain_in<S: AsRef<str>>(core: &str, locale: &str, chain: &[S]) {
+    let mut guard = I18N_CORE.write().unwrap();
+
+    match guard.get_mut(core) {
+        None => {
+            panic!("The i18n core {} has not been created. Call the init_i18n! or init_i18n_static_dir! macro.", core);
+        }
+        Some(core) => {
+            core.set_fallback_chain(locale, chain);
+        }
+    }
+}
+
+/// Setting the resolver of the current locale for the default core, see [InternationalCore::set_locale_resolver].
+/// The locale set by [set_current_locale] takes precedence.
+pub fn set_locale_resolver<F: Fn() -> Option<String> + Send + Sync + 'static>(resolver: F) {
+    set_locale_resolver_in(DEFAULT_CORE, resolver)
+}
+
+/// Setting the resolver of the current locale for the named core
+pub fn set_locale_resolver_in<F: Fn() -> Option<String> + Send + Sync + 'static>(core: &str, resolver: F) {
+    let mut guard = I18N_CORE.write().unwrap();
+
+    match guard.get_mut(core) {
+        None => {
+            panic!("The i18n core {} has not been created. Call the init_i18n! or init_i18n_static_dir! macro.", core);
+        }
+        Some(core) => {
+            core.set_locale_resolver(resolver);
+        }
+    }
+}
+
+/// Drop all static cores and the current locale, so the tests can init the cores again with other fixtures. Feature `test_util`.
+/// The cores are shared by the tests of the process, run such tests by one thread (`--test-threads=1`) or keep them in one test.
+///
+/// # Examples
+/// ```no_run
+/// # use sorrow_i18n::init_i18n;
+/// init_i18n!("tests/fixtures/one").unwrap();
+/// sorrow_i18n::feature_macro::reset();
+/// init_i18n!("tests/fixtures/two").unwrap();
+/// ```
+#[cfg(feature = "test_util")]
+pub fn reset() {
+    I18N_CORE.write().unwrap().clear();
+    *CURRENT_LOCALE.write().unwrap() = None;
+}
+
+/// Set the core by name, the previous core is dropped instead of the panic of the double init. Feature `test_util`.
+///
+/// # Examples
+/// ```
+/// use sorrow_i18n::{i18n, InternationalCore};
+/// use sorrow_i18n::feature_macro::{replace, DEFAULT_CORE};
+///
+/// replace(DEFAULT_CORE, InternationalCore::builder().insert("EN", "data.name", "One").build());
+/// assert_eq!("One", i18n!("EN", "data.name"));
+/// replace(DEFAULT_CORE, InternationalCore::builder().insert("EN", "data.name", "Two").build());
+/// assert_eq!("Two", i18n!("EN", "data.name"));
+/// ```
+#[cfg(feature = "test_util")]
+pub fn replace(name: &str, core: InternationalCore) {
+    I18N_CORE.write().unwrap().insert(name.to_string(), core);
+}
+
+/// Setting the current locale, used by `i18n!` without locale argument.
+/// GUI apps can switch language at runtime, all subsequent calls use the new locale.
+pub fn set_current_locale(locale: &str) {
+    let mut guard = CURRENT_LOCALE.write().unwrap();
+    *guard = Some(locale.to_string());
+}
+
+/// Get the current locale. The thread-local locale (see [crate::locale::with_locale]) takes precedence.
+/// If it has not been set, return the locale of the resolver of the default core (see [set_locale_resolver]), the system locale (or `en-US`).
+pub fn get_current_locale() -> String {
+    get_current_locale_in(DEFAULT_CORE)
+}
+
+/// Get the current locale for the named core, the resolver of the core is used (see [set_locale_resolver_in]).
+pub fn get_current_locale_in(core: &str) -> String {
+    if let Some(locale) = scoped_locale() {
+        return locale;
+    }
+    let guard = CURRENT_LOCALE.read().unwrap();
+    match &*guard {
+        None => {
+            match I18N_CORE.read().unwrap().get(core) {
+                Some(core) => core.current_locale(),
+                None => get_current_locale_or_default(),
+            }
+        }
+        Some(locale) => {
+            locale.clone()
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Message formatting.
+//! Placeholders in message are written as `{name}` and replaced by arguments with the same name.
+
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+/// First strong isolate (FSI).
+const FSI: char = '\u{2068}';
+/// Pop directional isolate (PDI).
+const PDI: char = '\u{2069}';
+
+/// Escaping of interpolated values. The message itself is trusted and never escaped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EscapePolicy {
+    /// Values are inserted as is.
+    #[default]
+    None,
+    /// Values are escaped for embedding in HTML, see [escape_html].
+    Html,
+}
+
+/// Formatting options by holder's. Set on [crate::InternationalCore].
+#[derive(Debug, Clone, Default)]
+pub struct FormatOptions {
+    /// Wrap every interpolated value in FSI/PDI isolation marks.
+    /// Mixed LTR/RTL text does not render scrambled, but the marks are part of the result string.
+    pub bidi_isolation: bool,
+
+    /// Escaping of interpolated values.
+    pub escape: EscapePolicy,
+}
+
+/// Replace `{name}` placeholders in `template` by arguments.
+/// Placeholders without argument are left as is.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::format::{interpolate, FormatOptions};
+/// let options = FormatOptions::default();
+/// assert_eq!("Hello, Bob!", interpolate("Hello, {name}!", &[("name", &"Bob")], &options));
+/// assert_eq!("Hello, {name}!", interpolate("Hello, {name}!", &[], &options));
+///
+/// let options = FormatOptions { bidi_isolation: true, ..Default::default() };
+/// assert_eq!("Hello, \u{2068}Bob\u{2069}!", interpolate("Hello, {name}!", &[("name", &"Bob")], &options));
+/// ```
+pub fn interpolate(template: &str, args: &[(&str, &dyn Display)], options: &FormatOptions) -> String {
+    Template::new(template).render(args, options)
+}
+
+/// Part of the compiled message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    /// Text between placeholders, range of the message.
+    Text(usize, usize),
+    /// Placeholder, range of the name in the message.
+    Arg(usize, usize),
+}
+
+/// Message compiled once, so placeholders are not searched on every rendering.
+/// Templates of the holder's messages are compiled on loading and reloading.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::format::{FormatOptions, Template};
+/// let template = Template::new("Hello, {name}!");
+/// assert_eq!(vec!["name"], template.placeholders().collect::<Vec<&str>>());
+/// assert_eq!("Hello, Bob!", template.render(&[("name", &"Bob")], &FormatOptions::default()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    source: Arc<str>,
+    parts: Vec<Part>,
+}
+
+impl Template {
+    /// Compile the message.
+    pub fn new<S: Into<Arc<str>>>(message: S) -> Template {
+        let source = message.into();
+        let mut parts = vec![];
+        let mut position = 0;
+        while let Some(start) = source[position..].find('{').map(|i| position + i) {
+            match source[start..].find('}') {
+                None => break,
+                Some(end) => {
+                    let end = start + end;
+                    if start > position {
+                        parts.push(Part::Text(position, start));
+                    }
+                    parts.push(Part::Arg(start + 1, end));
+                    position = end + 1;
+                }
+            }
+        }
+        if position < source.len() {
+            parts.push(Part::Text(position, source.len()));
+        }
+        Template { source, parts }
+    }
+
+    /// The message.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Names of the placeholders, in order of the message.
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|p| match p {
+            Part::Arg(start, end) => Some(&self.source[*start..*end]),
+            Part::Text(..) => None,
+        })
+    }
+
+    /// Map the text between placeholders, placeholders are kept as is.
+    #[cfg(feature = "std")]
+    pub(crate) fn map_text<F: FnMut(&str) -> String>(&self, mut f: F) -> String {
+        let mut result = String::with_capacity(self.source.len());
+        for part in self.parts.iter() {
+            match part {
+                Part::Text(start, end) => result.push_str(&f(&self.source[*start..*end])),
+                Part::Arg(start, end) => result.push_str(&self.source[start - 1..=*end]),
+            }
+        }
+        result
+    }
+
+    /// Replace placeholders by arguments, see [interpolate].
+    pub fn render(&self, args: &[(&str, &dyn Display)], options: &FormatOptions) -> String {
+        let mut result = String::with_capacity(self.source.len());
+        for part in self.parts.iter() {
+            match part {
+                Part::Text(start, end) => result.push_str(&self.source[*start..*end]),
+                Part::Arg(start, end) => {
+                    let name = &self.source[*start..*end];
+                    match args.iter().find(|(n, _)| *n == name) {
+                        None => {
+                            result.push_str(&self.source[start - 1..=*end]);
+                        }
+                        Some((_, value)) => {
+                            let value = match options.escape {
+                                EscapePolicy::None => value.to_string(),
+                                EscapePolicy::Html => escape_html(&value.to_string()),
+                            };
+                            if options.bidi_isolation {
+                                result.push(FSI);
+                                result.push_str(&value);
+                                result.push(PDI);
+                            } else {
+                                result.push_str(&value);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Escape `&`, `<`, `>`, `"` and `'` for embedding in HTML text and attribute values.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::format::escape_html;
+/// assert_eq!("&lt;b&gt;Tom &amp; Jerry&lt;/b&gt;", escape_html("<b>Tom & Jerry</b>"));
+/// ```
+pub fn escape_html(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#x27;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
@@ -0,0 +1,75 @@
+//! Helper `t` for Handlebars templates, bound to the core by [register]. The message is formatted by the hash arguments
+//! (see [GetData::get_with_args]), the locale is the hash argument `locale`, the field `locale` of the rendered data,
+//! or the current locale (see [crate::locale::LocaleGuard]).
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use sorrow_i18n::InternationalCore;
+//!
+//! let mut handlebars = handlebars::Handlebars::new();
+//! sorrow_i18n::handlebars::register(&mut handlebars, Arc::new(InternationalCore::new("locale/")));
+//! handlebars.register_template_string("hello", "<h1>{{t \"data.greeting\" name=user}}</h1>").unwrap();
+//!
+//! let html = handlebars.render("hello", &serde_json::json!({"user": "Bob", "locale": "en"})).unwrap();
+//! ```
+
+use std::fmt::Display;
+use std::sync::Arc;
+
+use handlebars::{Context, Handlebars, Helper, HelperDef, JsonValue, RenderContext, RenderError, RenderErrorReason, ScopedJson};
+
+use crate::{GetData, InternationalCore};
+
+/// Helper `t`, see [register].
+pub struct Translate {
+    core: Arc<InternationalCore>,
+}
+
+impl Translate {
+    /// Helper bound to the core.
+    pub fn new(core: Arc<InternationalCore>) -> Self {
+        Translate { core }
+    }
+}
+
+impl HelperDef for Translate {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        ctx: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let key = h.param(0)
+            .and_then(|p| p.value().as_str())
+            .ok_or(RenderErrorReason::ParamNotFoundForIndex("t", 0))?;
+        let locale = h.hash_get("locale")
+            .and_then(|p| p.value().as_str())
+            .or_else(|| ctx.data().get("locale").and_then(JsonValue::as_str))
+            .map(|l| l.to_string())
+            .unwrap_or_else(|| self.core.current_locale());
+        let values = h.hash().iter()
+            .filter(|(name, _)| **name != "locale")
+            .map(|(name, value)| (*name, text(value.value())))
+            .collect::<Vec<(&str, String)>>();
+        let values = values.iter().map(|(name, value)| (*name, value as &dyn Display)).collect::<Vec<_>>();
+        let message = match self.core.get_by_locale(&locale) {
+            None => key.to_string(),
+            Some(data) => data.get_with_args(key, &values),
+        };
+        Ok(ScopedJson::Derived(JsonValue::String(message)))
+    }
+}
+
+/// Register the helper `t` in Handlebars: `{{t "data.name"}}`, `{{t "data.greeting" name=user locale="ru"}}`.
+pub fn register(handlebars: &mut Handlebars, core: Arc<InternationalCore>) {
+    handlebars.register_helper("t", Box::new(Translate::new(core)));
+}
+
+/// Strings without quotes, other values as JSON.
+fn text(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
@@ -0,0 +1,74 @@
+//! Interned keys. The key interned by [KeyId::new] gets a compact id, shared across locales and cores of the process,
+//! so hot paths can look up by [KeyId] without hashing the full dotted path. The loaded keys are not interned,
+//! the symbol table keeps only the keys interned by the application.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Symbol table of the process.
+#[derive(Default)]
+struct Symbols {
+    ids: HashMap<Arc<str>, u32>,
+    names: Vec<Arc<str>>,
+}
+
+fn symbols() -> &'static RwLock<Symbols> {
+    static SYMBOLS: OnceLock<RwLock<Symbols>> = OnceLock::new();
+    SYMBOLS.get_or_init(|| RwLock::new(Symbols::default()))
+}
+
+/// Id of the interned key. Ids are never released, the same key always has the same id.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::{GetData, InternationalCore, KeyId};
+/// static NAME: std::sync::OnceLock<KeyId> = std::sync::OnceLock::new();
+/// let core = InternationalCore::builder().insert("EN", "data.name", "Test").build();
+/// let name = *NAME.get_or_init(|| KeyId::new("data.name"));
+/// assert_eq!("data.name", &*name.as_str());
+/// assert_eq!(Some("Test".into()), core.get_by_locale("EN").unwrap().get_by_id(name));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KeyId(u32);
+
+impl KeyId {
+    /// Intern the key.
+    pub fn new(key: &str) -> KeyId {
+        if let Some(id) = KeyId::find(key) {
+            return id;
+        }
+        let mut symbols = symbols().write().unwrap();
+        if let Some(id) = symbols.ids.get(key) {
+            return KeyId(*id);
+        }
+        let name: Arc<str> = Arc::from(key);
+        let id = symbols.names.len() as u32;
+        symbols.names.push(Arc::clone(&name));
+        symbols.ids.insert(name, id);
+        KeyId(id)
+    }
+
+    /// Id of the key, if the key has been interned.
+    pub fn find(key: &str) -> Option<KeyId> {
+        symbols().read().unwrap().ids.get(key).map(|id| KeyId(*id))
+    }
+
+    /// Ids of the interned keys with their values, the symbol table is locked once for all keys.
+    pub(crate) fn find_each<'a, T, I: IntoIterator<Item = (&'a str, T)>>(keys: I) -> Vec<(KeyId, T)> {
+        let symbols = symbols().read().unwrap();
+        keys.into_iter().filter_map(|(key, value)| symbols.ids.get(key).map(|id| (KeyId(*id), value))).collect()
+    }
+
+    /// The key.
+    pub fn as_str(&self) -> Arc<str> {
+        Arc::clone(&symbols().read().unwrap().names[self.0 as usize])
+    }
+}
+
+impl Display for KeyId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.as_str())
+    }
+}
@@ -0,0 +1,84 @@
+//! Integration with Leptos. [provide_i18n] shares the core and the reactive signal of the current locale by the context,
+//! so translations are resolved through [InternationalCore] both on the server (SSR) and after hydration.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use leptos::prelude::*;
+//! use sorrow_i18n::leptos::{provide_i18n, use_i18n};
+//! use sorrow_i18n::InternationalCore;
+//!
+//! #[component]
+//! fn Greeting() -> impl IntoView {
+//!     let i18n = use_i18n();
+//!     view! {
+//!         <button on:click=move |_| i18n.set_locale("ru")>{move || i18n.t("data.name")}</button>
+//!     }
+//! }
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     provide_i18n(Arc::new(InternationalCore::new("locale/")), "en");
+//!     view! { <Greeting /> }
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use leptos::prelude::*;
+
+use crate::{Data, GetData, InternationalCore};
+
+/// Context of [provide_i18n]. Reading the messages tracks the locale signal, so reactive closures are re-run when the locale is switched.
+#[derive(Clone, Copy)]
+pub struct I18n {
+    core: StoredValue<Arc<InternationalCore>>,
+    locale: RwSignal<String>,
+}
+
+impl I18n {
+    /// The core.
+    pub fn core(&self) -> Arc<InternationalCore> {
+        self.core.get_value()
+    }
+
+    /// Signal of the current locale.
+    pub fn locale(&self) -> RwSignal<String> {
+        self.locale
+    }
+
+    /// Switch the current locale.
+    pub fn set_locale(&self, locale: &str) {
+        self.locale.set(locale.to_string())
+    }
+
+    /// [Data] by the current locale.
+    pub fn data(&self) -> Option<Data> {
+        let locale = self.locale.get();
+        self.core.with_value(|core| core.get_by_locale(&locale))
+    }
+
+    /// Message by key in the current locale, or the key itself.
+    pub fn t(&self, key: &str) -> String {
+        match self.data() {
+            None => key.to_string(),
+            Some(data) => data.get_or_default(key),
+        }
+    }
+}
+
+/// Provide [I18n] to the children of the current component, `locale` is the initial current locale
+/// (on the server it can be negotiated from the request, see [InternationalCore::negotiate]).
+pub fn provide_i18n(core: Arc<InternationalCore>, locale: &str) -> I18n {
+    let i18n = I18n { core: StoredValue::new(core), locale: RwSignal::new(locale.to_string()) };
+    provide_context(i18n);
+    i18n
+}
+
+/// Context of [provide_i18n].
+///
+/// # Panics
+///
+/// If [provide_i18n] has not been called by the parents.
+pub fn use_i18n() -> I18n {
+    expect_context::<I18n>()
+}
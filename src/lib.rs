@@ -3,18 +3,25 @@
 //! For other cases, you can write your own data provider.
 //! [Github project](https://github.com/SinmoWay/simple-i18n)
 //! [Crates](https://crates.io/crates/sorrow-i18n)
+//!
+//! Without the default feature `std` the crate is `no_std` (only `alloc` is required), see [embedded].
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 #![deny(warnings)]
+#![allow(non_local_definitions)]
+
+extern crate alloc;
 
 /// Macro feature.
 /// Adds 2 macros, the first one serves for initialization, the second one for getting the value from the holders.
 ///
 /// # Examples
 ///
-/// ```
+/// ```no_run
+/// # use sorrow_i18n::{i18n, init_i18n};
 /// // Let's initialize our i18n core.
-/// init_i18n!("locale/");
+/// init_i18n!("locale/").unwrap();
 ///
 /// // Getting data by holder. (locale is required)
 /// // If the key is not found or the locale is not found, return the passed key.
@@ -26,44 +33,275 @@
 #[cfg(feature = "macro")]
 pub mod feature_macro;
 
-use std::collections::HashMap;
+/// Embed a single file at compile time and return static [Holder], for the tools without the folder of locales.
+/// The path is relative to the current file, as for [include_str].
+/// Panic if the file structure is invalid, use [Holder::parse] to handle the error.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::include_i18n;
+///
+/// let holder = include_i18n!("../resources/en_ru/I18N_EN.yaml");
+/// assert_eq!("en", holder.locale());
+/// assert_eq!(Some("Test".to_string()), holder.messages().get("data.name"));
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! include_i18n {
+    ($path:expr) => {
+        {
+            $crate::Holder::from_included($path, include_str!($path))
+        }
+    }
+}
+
+/// Plural rules by locale.
+/// Used by [GetData::get_ordinal] to select the key of the message form.
+pub mod plural;
+
+/// Locale identifiers normalization.
+/// Every locale passed to [InternationalCore] is normalized by [locale::normalize].
+pub mod locale;
+
+/// Lookup of the embedded catalogs, `no_std` compatible, see [embedded::Catalog].
+pub mod embedded;
+
+/// Namespaces of keys, see [InternationalCore::namespace].
+#[cfg(feature = "std")]
+pub mod namespace;
+
+/// Generation of key constants, for build scripts.
+#[cfg(feature = "std")]
+pub mod codegen;
+
+/// Events of the data changes, see [InternationalCore::subscribe].
+#[cfg(feature = "std")]
+pub mod events;
+
+/// Layered provider, see [composite::CompositeProvider].
+#[cfg(feature = "std")]
+pub mod composite;
+
+/// Checks of the catalogs, see [InternationalCore::check_consistency] and [InternationalCore::validate].
+#[cfg(feature = "std")]
+pub mod check;
+
+/// Instrumentation of the lookups and reloads, see [InternationalCore::set_metrics].
+#[cfg(feature = "std")]
+pub mod metrics;
+
+/// Pseudo-localization of the catalogs, see [pseudo::pseudo_holder].
+#[cfg(feature = "std")]
+pub mod pseudo;
+
+/// Statistics of the reloads, see [InternationalCore::stats].
+#[cfg(feature = "std")]
+pub mod stats;
+
+/// Search of the messages, see [InternationalCore::search].
+#[cfg(feature = "std")]
+pub mod search;
+
+/// Watcher of the folders shared by the file providers, see [watcher::FolderWatcher], feature `watch`.
+#[cfg(feature = "watch")]
+pub mod watcher;
+
+/// Integration with axum, feature `axum`.
+#[cfg(feature = "axum")]
+pub mod axum;
+
+/// Integration with actix-web, feature `actix`.
+#[cfg(feature = "actix")]
+pub mod actix;
+
+/// Integration with warp, feature `warp`.
+#[cfg(feature = "warp")]
+pub mod warp;
+
+/// Tauri plugin, feature `tauri`.
+#[cfg(feature = "tauri")]
+pub mod tauri;
+
+/// Integration with Yew, feature `yew`.
+#[cfg(feature = "yew")]
+pub mod yew;
+
+/// Integration with Leptos, feature `leptos`.
+#[cfg(feature = "leptos")]
+pub mod leptos;
+
+/// Bevy plugin, feature `bevy`.
+#[cfg(feature = "bevy")]
+pub mod bevy;
+
+/// Helpers for egui, feature `egui`.
+#[cfg(feature = "egui")]
+pub mod egui;
+
+/// Integration with Dioxus, feature `dioxus`.
+#[cfg(feature = "dioxus")]
+pub mod dioxus;
+
+/// Integration with tokio, feature `tokio`.
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
+/// Function `t` for Tera templates, feature `tera`.
+#[cfg(feature = "tera")]
+pub mod tera;
+
+/// Helper `t` for Handlebars templates, feature `handlebars`.
+#[cfg(feature = "handlebars")]
+pub mod handlebars;
+
+/// Filters for Askama templates, feature `askama`.
+#[cfg(feature = "askama")]
+pub mod askama;
+
+/// Storage of the holder's data, see [Messages].
+#[cfg(feature = "std")]
+pub mod messages;
+
+#[cfg(feature = "std")]
+pub use crate::messages::{Entry, Messages};
+
+/// Interned keys, see [KeyId].
+#[cfg(feature = "std")]
+pub mod intern;
+#[cfg(feature = "std")]
+pub use crate::intern::KeyId;
+
+/// Catalogs assembled in code, see [InternationalCore::builder].
+#[cfg(feature = "std")]
+pub mod builder;
+
+/// Localized display of enums, see [Localize], deferred messages, see [LocalizedString], and key constants, see [MessageKey].
+#[cfg(feature = "std")]
+pub mod localize;
+
+#[cfg(feature = "std")]
+pub use crate::localize::{Localize, LocalizedString, MessageKey, ToLocalizedString};
+
+/// SQLite provider, feature `sqlite`.
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+/// Postgres/MySQL provider, feature `database`.
+#[cfg(feature = "database")]
+pub mod database;
+
+/// S3-compatible storage provider, feature `s3`.
+#[cfg(feature = "s3")]
+pub mod s3;
+
+/// Compressed files of `incl_dir` and `rust_embed`, features `gzip`, `zstd`.
+#[cfg(any(feature = "incl_dir", feature = "rust_embed"))]
+mod compress;
+
+#[cfg(feature = "derive")]
+pub use sorrow_i18n_derive::Localize;
+
+/// Message formatting, `{name}` placeholders.
+/// Used by [GetData::get_with_args] and [GetData::get_ordinal].
+pub mod format;
+
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::fmt::Display;
+#[cfg(feature = "watch")]
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
 use std::fs::{File};
-use std::io::Read;
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+#[cfg(feature = "std")]
 use std::path::Path;
-use std::sync::{Arc, Mutex, RwLock};
-use std::thread::sleep;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+#[cfg(feature = "std")]
+use std::sync::mpsc::{channel, Receiver};
+#[cfg(feature = "std")]
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+#[cfg(feature = "watch")]
 use std::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
 use sys_locale::get_locale;
 
+#[cfg(feature = "std")]
+use crate::format::{escape_html, EscapePolicy, FormatOptions, Template};
+#[cfg(feature = "std")]
+use crate::locale::{normalize, parse_accept_language, truncations};
+#[cfg(feature = "std")]
+use crate::builder::CatalogBuilder;
+#[cfg(feature = "std")]
+use crate::check::{ConsistencyReport, CoverageReport, Diagnostic};
+#[cfg(feature = "std")]
+use crate::composite::CompositeProvider;
+#[cfg(feature = "std")]
+use crate::messages::{Metadata, Snapshot};
+#[cfg(feature = "std")]
+use crate::metrics::Metrics;
+#[cfg(feature = "std")]
+use crate::events::{ReloadEvent, Subscribers};
+#[cfg(feature = "std")]
+use crate::namespace::Namespace;
+#[cfg(feature = "std")]
+use crate::plural::{ordinal_category, PluralCategory};
+#[cfg(feature = "std")]
+use crate::stats::HolderStats;
+#[cfg(feature = "watch")]
+use crate::watcher::{FolderWatcher, Registration};
+
+#[cfg(feature = "std")]
 use err_derive::Error;
 #[cfg(feature = "incl_dir")]
 use include_dir::Dir;
-use notify::{ErrorKind, RecommendedWatcher, RecursiveMode, Watcher};
-use serde_yaml::Value;
+#[cfg(feature = "watch")]
+use notify::ErrorKind;
+#[cfg(feature = "std")]
+use serde_yaml::{Mapping, Value};
 
 /// Error type
+#[cfg(feature = "std")]
 pub type Error = I18nError;
 
-/// Library errors
+/// Library errors.
+/// The underlying errors (IO, YAML, watcher) are kept as [std::error::Error::source], so the error chain is not lost.
+#[cfg(feature = "std")]
 #[derive(Debug, Error)]
 pub enum I18nError {
     /// Access denied for file.
     /// Not found file and e.t.c.
-    #[error(display = "File with path {:?} not found.", path)]
+    #[error(display = "Error while reading {:?}. Cause: {}", path, cause)]
     IoError {
         /// The file that generated the error
         path: String,
-        /// Cause message
-        cause: String,
+        /// Cause error
+        #[error(source)]
+        cause: std::io::Error,
     },
 
     /// Invalid structure locale file.
-    #[error(display = "Structure with path {:?} invalid. Additional information: {:?}", path, cause)]
+    #[error(display = "Structure with path {:?} invalid. Additional information: {}", path, cause)]
     InvalidStructure {
         /// The file that generated the error
         path: String,
-        /// Cause message
-        cause: String,
+        /// Cause error
+        #[error(source)]
+        cause: serde_yaml::Error,
+    },
+
+    /// The reloaded file has no messages, e.g. the file is being written. The current data is kept.
+    #[error(display = "File {:?} has no messages, the data is not reloaded.", path)]
+    EmptyReload {
+        /// The reloaded file
+        path: String,
     },
 
     /// Invalid kind of file.
@@ -73,11 +311,15 @@ pub enum I18nError {
         path: String,
     },
 
-    /// Error while watching by file.
-    #[error(display = "Watching by file return error: {:?}", message)]
+    /// Error while watching by file, feature `watch`.
+    #[cfg(feature = "watch")]
+    #[error(display = "Watching by file {:?} return error: {}", path, cause)]
     WatchError {
-        /// Cause message
-        message: String
+        /// The watched file
+        path: String,
+        /// Cause error
+        #[error(source)]
+        cause: notify::Error,
     },
 
     /// File extension is not .yaml or .yml
@@ -87,6 +329,13 @@ pub enum I18nError {
         path: String
     },
 
+    /// The file is included by the locale files (`kind: I18N_INCLUDE`), it is not the locale. The loaders of the folder skip it.
+    #[error(display = "File {:?} is included by the locale files, it is not the locale.", path)]
+    IncludeFile {
+        /// The included file
+        path: String
+    },
+
     /// The error is generated when you have two files with the same locale or when you manually add an existing locale.
     #[error(display = "Duplicate locale holder for {:?}", locale)]
     DuplicateLocale {
@@ -102,6 +351,78 @@ pub enum I18nError {
         /// Cause error
         cause: String,
     },
+
+    /// The locale holder does not exist.
+    #[error(display = "Locale {:?} not found.", locale)]
+    MissingLocale {
+        /// Requested locale
+        locale: String,
+    },
+
+    /// The same key exists in both catalogs while merging with [MergePolicy::Error].
+    #[error(display = "Key {:?} of {:?} locale exists in both catalogs.", key, locale)]
+    MergeConflict {
+        /// Locale of the holder
+        locale: String,
+        /// Conflicting key
+        key: String,
+    },
+
+    /// The key does not exist in the locale holder.
+    #[error(display = "Key {:?} not found in {:?} locale.", key, locale)]
+    MissingKey {
+        /// Locale of the holder
+        locale: String,
+        /// Requested key
+        key: String,
+    },
+
+    /// Error of the data source of the provider (database, storage...).
+    #[error(display = "Provider {:?} return error: {}", provider, cause)]
+    ProviderError {
+        /// Provider and its source
+        provider: String,
+        /// Cause error
+        cause: String,
+    },
+
+    /// Two keys are mapped to the same constant name by [codegen].
+    #[error(display = "Keys {:?} and {:?} have the same constant name {:?}.", key, other, name)]
+    KeyNameConflict {
+        /// Constant name
+        name: String,
+        /// First key
+        key: String,
+        /// Second key
+        other: String,
+    },
+
+    /// The bundle has not been written, see [InternationalCore::export_bundle].
+    #[error(display = "Error while writing {:?} bundle. Cause: {}", format, cause)]
+    ExportError {
+        /// Format of the bundle
+        format: BundleFormat,
+        /// Cause error
+        cause: String,
+    },
+
+    /// Invalid regular expression of the search query, feature `regex`.
+    #[cfg(feature = "regex")]
+    #[error(display = "Pattern {:?} invalid. Cause: {}", pattern, cause)]
+    InvalidPattern {
+        /// Pattern of the query
+        pattern: String,
+        /// Cause error
+        #[error(source)]
+        cause: regex::Error,
+    },
+
+    /// Several files of the folder have not been loaded, see [InternationalCore::try_new].
+    #[error(display = "Files have not been loaded: {:?}", errors)]
+    LoadErrors {
+        /// Errors in order of the file paths
+        errors: Vec<I18nError>,
+    },
 }
 
 /// Implementation of the state observer.
@@ -118,20 +439,23 @@ pub enum I18nError {
 /// The provider must work with information, which means he must receive a link to the working data.
 ///
 /// ```
-/// use std::collections::HashMap;
-/// use std::sync::{Arc, RwLock};
+/// use std::sync::Arc;
+/// use sorrow_i18n::Messages;
 ///
 /// pub struct CustomProvider {
-///     data: Arc<RwLock<HashMap<String, String>>>,
+///     data: Arc<Messages>,
 /// }
 /// ```
 /// ## Implementation WatchProvider for provider
 ///
 ///
 /// ```
-/// use std::collections::HashMap;
-/// use std::sync::{Arc, RwLock};
+/// use std::sync::Arc;
+/// use sorrow_i18n::Messages;
 /// use sorrow_i18n::{Error, WatchProvider};
+/// # pub struct CustomProvider {
+/// #     data: Arc<Messages>,
+/// # }
 ///
 /// impl WatchProvider for CustomProvider {
 ///     fn watch(&mut self) -> Result<(), Error> {
@@ -140,7 +464,7 @@ pub enum I18nError {
 ///     }
 ///
 /// // Setting current data in holder.
-///     fn set_data(&mut self, data: Arc<RwLock<HashMap<String, String>>>) -> Result<(), Error> {
+///     fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
 ///         self.data = data;
 ///         println!("Data has been set");
 ///         Ok(())
@@ -153,138 +477,433 @@ pub enum I18nError {
 ///
 /// Add provider for holder.
 ///
-/// ```
+/// ```no_run
 ///     use sorrow_i18n::InternationalCore;
+/// #   use std::sync::Arc;
+/// #   use sorrow_i18n::Messages;
+/// #   use sorrow_i18n::{Error, WatchProvider};
+/// #   pub struct CustomProvider;
+/// #   impl CustomProvider { fn new() -> Self { CustomProvider } }
+/// #   impl WatchProvider for CustomProvider {
+/// #       fn watch(&mut self) -> Result<(), Error> { Ok(()) }
+/// #       fn set_data(&mut self, _data: Arc<Messages>) -> Result<(), Error> { Ok(()) }
+/// #   }
 ///
 ///     let mut core = InternationalCore::new("resources/locales");
 ///     core.add_provider("my locale", Box::new(CustomProvider::new())).unwrap();
 /// ```
 ///
+#[cfg(feature = "std")]
 pub trait WatchProvider {
     /// The main observer method that is called to observe the state.
     fn watch(&mut self) -> Result<(), Error>;
 
     /// Setter for data reference.
-    fn set_data(&mut self, data: Arc<RwLock<HashMap<String, String>>>) -> Result<(), Error>;
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error>;
+
+    /// Setter for the listener, which the provider calls after the data has been changed by the provider.
+    /// Called before [WatchProvider::watch]. Providers without refresh can ignore it.
+    fn set_listener(&mut self, _listener: ChangeListener) {}
+
+    /// Setter for the listener of the errors while observing (e.g. the file cannot be reloaded), see [InternationalCore::on_watch_error].
+    /// Can be called after [WatchProvider::watch]. Providers without refresh can ignore it.
+    fn set_error_listener(&mut self, _listener: ErrorListener) {}
+
+    /// Setter for the watcher of the folders shared by the providers of the core, see [InternationalCore::folder_watcher].
+    /// Called before [WatchProvider::watch]. Providers not watching files can ignore it.
+    #[cfg(feature = "watch")]
+    fn set_folder_watcher(&mut self, _watcher: FolderWatcher) {}
+
+    /// Stop observing, release the watchers and threads of the provider.
+    /// Called when the provider is replaced by [InternationalCore::add_provider] and when the holder is dropped.
+    fn unwatch(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Listener of the data changes, see [WatchProvider::set_listener].
+#[cfg(feature = "std")]
+pub type ChangeListener = Arc<dyn Fn() + Send + Sync>;
+
+/// Listener of the errors while observing, see [WatchProvider::set_error_listener].
+#[cfg(feature = "std")]
+pub type ErrorListener = Arc<dyn Fn(&Error) + Send + Sync>;
+
+/// Handler of the missing keys, see [InternationalCore::on_missing]. Arguments are locale and key, the returned value is used instead of the key.
+#[cfg(feature = "std")]
+pub type MissingHandler = Arc<dyn Fn(&str, &str) -> Option<String> + Send + Sync>;
+
+/// Resolver of the current locale, see [InternationalCore::set_locale_resolver]. Returns `None` if the locale is not known, e.g. no user is logged in.
+#[cfg(feature = "std")]
+pub type LocaleResolver = Arc<dyn Fn() -> Option<String> + Send + Sync>;
+
+/// Value of the missing key when the handler (see [InternationalCore::on_missing]) returns `None`, see [InternationalCore::set_missing_policy].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub enum MissingKeyPolicy {
+    /// The key itself, for development.
+    #[default]
+    ReturnKey,
+    /// Empty string, so the raw keys are never shown in production UI.
+    Empty,
+    /// Panic, e.g. in tests.
+    Panic,
+    /// The value of the locale (and the key, if the locale has not the key too). The locale must be loaded before the policy is set.
+    Fallback(String),
+    /// Result of the function by locale and key.
+    Custom(fn(&str, &str) -> String),
+}
+
+/// Logged missing keys by locale, see [InternationalCore::log_missing_once].
+#[cfg(feature = "std")]
+type MissingSeen = Arc<Mutex<HashMap<String, HashSet<String>>>>;
+
+/// Change listener shared with the provider, so it can be set after the observing has been started.
+#[cfg(feature = "std")]
+#[derive(Clone, Default)]
+pub(crate) struct ChangeNotifier {
+    listener: Arc<RwLock<Option<ChangeListener>>>,
+}
+
+#[cfg(feature = "std")]
+impl ChangeNotifier {
+    pub(crate) fn set(&self, listener: ChangeListener) {
+        *self.listener.write().unwrap() = Some(listener);
+    }
+
+    pub(crate) fn notify(&self) {
+        let listener = self.listener.read().unwrap().clone();
+        if let Some(listener) = listener {
+            listener();
+        }
+    }
+
+    /// Listener for the provider, which notifies the current listener.
+    pub(crate) fn as_listener(&self) -> ChangeListener {
+        let notifier = self.clone();
+        Arc::new(move || notifier.notify())
+    }
+}
+
+/// Error listener shared with the threads of the provider, so it can be set after the observing has been started.
+#[cfg(feature = "std")]
+#[derive(Clone, Default)]
+pub(crate) struct ErrorReporter {
+    listener: Arc<RwLock<Option<ErrorListener>>>,
+}
+
+#[cfg(feature = "std")]
+impl ErrorReporter {
+    pub(crate) fn set(&self, listener: ErrorListener) {
+        *self.listener.write().unwrap() = Some(listener);
+    }
+
+    pub(crate) fn listener(&self) -> Option<ErrorListener> {
+        self.listener.read().unwrap().clone()
+    }
+
+    /// Log the error and pass it to the listener.
+    pub(crate) fn report(&self, error: Error) {
+        log::error!("{}", &error);
+        #[cfg(feature = "tracing")]
+        tracing::error!(error = %error, "watch error");
+        let listener = self.listener.read().unwrap().clone();
+        if let Some(listener) = listener {
+            listener(&error);
+        }
+    }
 }
 
 /// Base providers
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(from = "String", into = "String")]
 pub enum Providers {
     /// [FileProvider] - dynamically watcher for file.
     FileProvider,
     /// [StaticFileProvider] - static file. It is not being watched. Default option if the `provider` is not specified in the file structure
     StaticFileProvider,
+    /// Provider of the factory registered by name, see [InternationalCore::register_provider_factory].
+    /// Until the factory is registered, the file is not watched.
+    Custom(String),
+}
+
+#[cfg(feature = "std")]
+impl From<String> for Providers {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "FileProvider" => Providers::FileProvider,
+            "StaticFileProvider" => Providers::StaticFileProvider,
+            _ => Providers::Custom(name),
+        }
+    }
 }
 
-/// Files maybe changed. Watch by `modify` system event.
-struct FileProvider {
-    messages: Arc<RwLock<HashMap<String, String>>>,
+#[cfg(feature = "std")]
+impl From<Providers> for String {
+    fn from(provider: Providers) -> Self {
+        match provider {
+            Providers::FileProvider => "FileProvider".to_string(),
+            Providers::StaticFileProvider => "StaticFileProvider".to_string(),
+            Providers::Custom(name) => name,
+        }
+    }
+}
+
+/// Factory of the provider selected by the file (`provider: redis`), see [InternationalCore::register_provider_factory].
+/// Arguments are locale and path of the file, the path is `None` for the files parsed from string.
+#[cfg(feature = "std")]
+pub type ProviderFactory = Arc<dyn Fn(&str, Option<&str>) -> Result<Box<dyn WatchProvider + Send + Sync>, Error> + Send + Sync>;
+
+/// Registered factories by name, shared with the lazy holders.
+#[cfg(feature = "std")]
+type ProviderFactories = Arc<RwLock<HashMap<String, ProviderFactory>>>;
+
+/// Watcher of the core passed to the providers created by the factories, nothing without feature `watch`.
+#[cfg(feature = "watch")]
+type SharedWatcher = FolderWatcher;
+#[cfg(all(feature = "std", not(feature = "watch")))]
+#[derive(Clone, Copy)]
+struct SharedWatcher;
+
+/// Files maybe changed. Watch by `modify`, `create` and `rename` system events in the folder of the file, feature `watch` (default).
+/// Events within the debounce window are collapsed into one reload, the reload is skipped if the content of the file is not changed.
+/// If the file is deleted, the last loaded data is kept and [Error::WatchError] is reported, the file is reloaded when it is created again.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use sorrow_i18n::{FileProvider, InternationalCore};
+/// let mut core = InternationalCore::new("locale/");
+/// let provider = FileProvider::from_path("locale/I18N_EN.yaml").with_debounce(Duration::from_millis(200));
+/// core.add_provider("EN", Box::new(provider)).unwrap();
+/// ```
+#[cfg(feature = "watch")]
+pub struct FileProvider {
+    messages: Arc<Messages>,
     path: String,
-    watcher: Option<RecommendedWatcher>,
+    debounce: Duration,
+    watcher: FolderWatcher,
+    registration: Option<Registration>,
+    listener: Option<ChangeListener>,
+    errors: ErrorReporter,
 }
 
+#[cfg(feature = "watch")]
 impl FileProvider {
-    pub fn new(messages: Arc<RwLock<HashMap<String, String>>>, path: String) -> Self {
+    pub(crate) fn new(messages: Arc<Messages>, path: String) -> Self {
         FileProvider {
             messages,
             path,
-            watcher: None,
+            debounce: Duration::from_millis(10),
+            watcher: FolderWatcher::default(),
+            registration: None,
+            listener: None,
+            errors: ErrorReporter::default(),
         }
     }
+
+    /// Provider for the file, debounce window 10 ms. The data is set by the holder.
+    /// The file is watched by own watcher, unless the provider is added to the core (see [InternationalCore::folder_watcher]).
+    pub fn from_path(path: &str) -> Self {
+        FileProvider::new(Arc::new(Messages::default()), path.to_string())
+    }
+
+    /// Debounce window. The file is reloaded when there are no events in the window after the last event.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
 }
 
+#[cfg(feature = "watch")]
 impl WatchProvider for FileProvider {
     fn watch(&mut self) -> Result<(), Error> {
+        if !Path::new(&self.path).exists() {
+            log::error!("Path not found: {}", &self.path);
+            return Err(Error::IoError { path: self.path.clone(), cause: std::io::Error::from(std::io::ErrorKind::NotFound) });
+        }
         let holder = Arc::clone(&self.messages);
         let path = self.path.clone();
-        let res_watcher = notify::recommended_watcher(move |result: Result<notify::Event, notify::Error>| {
-            let event = result.map_err(|e| Error::WatchError { message: e.to_string() }).unwrap();
-            if event.kind.is_modify() {
-                // Hack.
-                // Inappropriate library behavior was detected when the file was updated on the Winodws platform.
-                // For some reason, 2 save events are fired, which causes double reads of the file.
-                // At the same time, the intervals between reading the file (updated configuration) are too small, which causes an error in the form of EOF.
-                // The simplest solution is to set a minimum timeout between these events.
-                sleep(Duration::from_millis(10));
-                log::debug!("Modify {}. Reloading data.", &path.clone());
-                // Validation file
-                let structure = load_struct(&path.clone()).unwrap();
-                // Lock data and clear
-                let mut w_holder = holder.write().unwrap();
-                w_holder.clear();
-
-                // Clone internal state.
-                let l_holder = structure.messages.write().unwrap().clone();
-                w_holder.extend(l_holder);
-                // Unlock
-            }
-        });
-
-        return match res_watcher {
-            Ok(mut w) => {
-                // TODO: Check error's?
-                w.watch(Path::new(&self.path.clone()), RecursiveMode::NonRecursive).unwrap();
-                self.watcher = Some(w);
-                Ok(())
-            }
-
-            Err(e) => {
-                match e.kind {
-                    ErrorKind::Generic(message) => {
-                        log::error!("Error while watch by file {}. Message: {}", &self.path, &message);
-                        Err(Error::WatchError { message })
-                    }
-                    ErrorKind::Io(err) => {
-                        log::error!("Error while watch by file {}. Message: {}", &self.path, &err);
-                        Err(Error::WatchError { message: err.to_string() })
+        let listener = self.listener.clone();
+        let debounce = self.debounce;
+        let errors = self.errors.clone();
+        let (sender, events) = channel::<()>();
+        let mut hash = content_hash(&path);
+        let mut missing = false;
+        // Some platforms (e.g. Windows) fire several modify events for one save, and the file may be read while it is being written.
+        // The events are collapsed, and the file is read when it is quiet. The thread stops with the registration.
+        std::thread::spawn(move || {
+            while events.recv().is_ok() {
+                while events.recv_timeout(debounce).is_ok() {}
+                // Editors and rsync touch the files without changing them.
+                let current = content_hash(&path);
+                if current.is_none() {
+                    // The file is deleted, the last loaded data is kept. The folder is watched, so the file is reloaded when it is created again.
+                    if !missing {
+                        missing = true;
+                        let cause = notify::Error::path_not_found().add_path(Path::new(&path).to_path_buf());
+                        let error = Error::WatchError { path: path.clone(), cause };
+                        holder.record(|stats| stats.failed(error.to_string()));
+                        errors.report(error);
                     }
-                    ErrorKind::PathNotFound => {
-                        log::error!("Path not found: {}", &self.path);
-                        Err(Error::IoError { path: self.path.clone(), cause: String::default() })
-                    }
-                    ErrorKind::WatchNotFound => {
-                        log::error!("Watcher not found for: {}", &self.path);
-                        // Ignore
-                        Ok(())
-                    }
-                    ErrorKind::InvalidConfig(err) => {
-                        log::error!("Invalid watch config. {:?}", &err);
-                        // Ignore
-                        Ok(())
+                    continue;
+                }
+                if missing {
+                    missing = false;
+                    log::info!("File {} is restored.", &path);
+                }
+                if current == hash {
+                    log::debug!("Modify {}, the content is not changed. Reloading is skipped.", &path);
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(path = %path, "reload skipped, the content is not changed");
+                    continue;
+                }
+                log::debug!("Modify {}. Reloading data.", &path);
+                match reload_messages(&holder, &path) {
+                    Ok(_) => {
+                        hash = current;
+                        if let Some(listener) = &listener {
+                            listener();
+                        }
                     }
-                    ErrorKind::MaxFilesWatch => {
-                        log::error!("Max watchers for: {}", &self.path);
-                        Err(Error::WatchError { message: String::from("max watchers for file. Please try again latter or remove exists watcher.") })
+                    Err(e) => {
+                        errors.report(e);
                     }
                 }
             }
-        };
+        });
+
+        // The folder is watched, the file may be replaced by rename.
+        self.registration = Some(self.watcher.register(&self.path, sender, self.errors.clone())?);
+        Ok(())
     }
 
-    fn set_data(&mut self, data: Arc<RwLock<HashMap<String, String>>>) -> Result<(), Error> {
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
         self.messages = data;
         Ok(())
     }
+
+    fn set_listener(&mut self, listener: ChangeListener) {
+        self.listener = Some(listener);
+    }
+
+    fn set_error_listener(&mut self, listener: ErrorListener) {
+        self.errors.set(listener);
+    }
+
+    fn set_folder_watcher(&mut self, watcher: FolderWatcher) {
+        self.watcher = watcher;
+    }
+
+    fn unwatch(&mut self) -> Result<(), Error> {
+        // The reloading thread stops with the events sender of the registration.
+        self.registration = None;
+        Ok(())
+    }
+}
+
+/// Hash of the content of the file, `None` if the file can not be read.
+#[cfg(feature = "watch")]
+fn content_hash(path: &str) -> Option<u64> {
+    let content = std::fs::read(path).ok()?;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    content.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// Converting [notify::Error] while watching by file `path`. Some errors are ignored.
+#[cfg(feature = "watch")]
+fn watch_error(path: &str, e: notify::Error) -> Result<(), Error> {
+    match &e.kind {
+        ErrorKind::Generic(message) => {
+            log::error!("Error while watch by file {}. Message: {}", path, message);
+            Err(Error::WatchError { path: path.to_string(), cause: e })
+        }
+        ErrorKind::Io(err) => {
+            log::error!("Error while watch by file {}. Message: {}", path, err);
+            Err(Error::WatchError { path: path.to_string(), cause: e })
+        }
+        ErrorKind::PathNotFound => {
+            log::error!("Path not found: {}", path);
+            Err(Error::IoError { path: path.to_string(), cause: std::io::Error::new(std::io::ErrorKind::NotFound, e) })
+        }
+        ErrorKind::WatchNotFound => {
+            log::error!("Watcher not found for: {}", path);
+            // Ignore
+            Ok(())
+        }
+        ErrorKind::InvalidConfig(err) => {
+            log::error!("Invalid watch config. {:?}", err);
+            // Ignore
+            Ok(())
+        }
+        ErrorKind::MaxFilesWatch => {
+            log::error!("Max watchers for: {}", path);
+            Err(Error::WatchError { path: path.to_string(), cause: e })
+        }
+    }
 }
 
 /// Files does not changed. Only loading files.
 /// Default option by [FileStructure]
+#[cfg(feature = "std")]
 struct StaticFileProvider {}
 
+#[cfg(feature = "std")]
 impl WatchProvider for StaticFileProvider {
     fn watch(&mut self) -> Result<(), Error> {
         Ok(())
     }
 
-    fn set_data(&mut self, _data: Arc<RwLock<HashMap<String, String>>>) -> Result<(), Error> {
+    fn set_data(&mut self, _data: Arc<Messages>) -> Result<(), Error> {
         Ok(())
     }
 }
 
+/// Conflict policy for [InternationalCore::merge], when the same key exists in both catalogs.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// Keep the value of the current catalog.
+    KeepExisting,
+    /// Overwrite by the value of the merged catalog.
+    Overwrite,
+    /// Return [Error::MergeConflict], nothing is merged.
+    Error,
+}
+
+/// Format of the document written by [InternationalCore::export_bundle].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleFormat {
+    /// YAML sequence of the file structures.
+    Yaml,
+    /// JSON array of the file structures.
+    Json,
+}
+
 /// Holder for localization map.
+#[cfg(feature = "std")]
 pub struct InternationalCore {
     holders: HashMap<String, Holder>,
+    namespaces: HashMap<String, HashMap<String, Holder>>,
+    format: FormatOptions,
+    fallbacks: HashMap<String, Vec<String>>,
+    default_fallback: Vec<String>,
+    watch_error: Option<ErrorListener>,
+    missing: Option<MissingHandler>,
+    missing_handler: Option<MissingHandler>,
+    missing_seen: Option<MissingSeen>,
+    missing_policy: MissingKeyPolicy,
+    case_insensitive: bool,
+    locale_resolver: Option<LocaleResolver>,
+    provider_factories: ProviderFactories,
+    metrics: Option<Arc<dyn Metrics>>,
+    subscribers: Subscribers,
+    #[cfg(feature = "watch")]
+    watcher: FolderWatcher,
 }
 
 /// Additional library, use features = ["incl_dir"] to enable.
@@ -293,8 +912,8 @@ pub struct InternationalCore {
 ///
 /// # Examples
 ///
-/// ```
-/// use include_dir::Dir;
+/// ```no_run
+/// use include_dir::{include_dir, Dir};
 /// use sorrow_i18n::InternationalCore;
 /// const PROJECT_DIR: Dir = include_dir!("resources/en_ru");
 /// fn main() {
@@ -305,118 +924,558 @@ pub struct InternationalCore {
 #[cfg(feature = "incl_dir")]
 impl<'a> From<Dir<'a>> for InternationalCore {
     fn from(dir: Dir) -> Self {
-        let files = dir.files();
-        let mut msg_holder = HashMap::new();
+        Self::try_from_dir(dir).unwrap_or_else(|e| panic!("Error while loading file. {:?}", e))
+    }
+}
+
+#[cfg(feature = "incl_dir")]
+impl InternationalCore {
+    /// Analogue `InternationalCore::from(dir)` without panic. Returns the error of the first invalid file.
+    pub fn try_from_dir(dir: Dir) -> Result<InternationalCore, Error> {
+        let mut core = InternationalCore::empty();
         // Folder is not required if files include in project.
-        // Setting default watcher by StaticFileProvider immediately.
-        for file in files {
-            let content = std::str::from_utf8(file.contents()).unwrap();
-            let structure = load_struct_from_str(content, None).unwrap();
-            let cl_struct = Arc::clone(&structure.provider);
-            let provider = cl_struct.lock();
-            match provider {
-                Ok(mut provider) => {
-                    *provider = Box::new(StaticFileProvider {});
-                }
-                Err(_e) => {
-                    panic!("Update provider by file has been failed. Poison mutex status.");
-                }
+        let read = |path: &str| match dir.get_file(path) {
+            None => Err(Error::IoError { path: path.to_string(), cause: std::io::Error::from(std::io::ErrorKind::NotFound) }),
+            Some(file) => embedded_text(path, file.contents()),
+        };
+        for file in dir.files() {
+            core.insert_embedded(file.path().to_string_lossy().to_string(), file.contents(), &read)?;
+        };
+        Ok(core)
+    }
+}
+
+/// Additional library, use features = ["rust_embed"] to enable.
+/// Analogue of `incl_dir` for the assets of [rust_embed], all files of the asset struct are loaded.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_embed::RustEmbed;
+/// use sorrow_i18n::InternationalCore;
+///
+/// #[derive(RustEmbed)]
+/// #[folder = "resources/en_ru"]
+/// struct Locales;
+///
+/// fn main() {
+///     let core = InternationalCore::from_embed::<Locales>();
+///     let locale_holder = core.get_by_locale("my_locale").unwrap();
+/// }
+/// ```
+#[cfg(feature = "rust_embed")]
+impl InternationalCore {
+    /// Creating new instance of InternationalCore from the asset struct, panic if the file is invalid.
+    /// Use [InternationalCore::try_from_embed] to handle errors.
+    pub fn from_embed<E: rust_embed::RustEmbed>() -> InternationalCore {
+        Self::try_from_embed::<E>().unwrap_or_else(|e| panic!("Error while loading file. {:?}", e))
+    }
+
+    /// Analogue [InternationalCore::from_embed] without panic. Returns the error of the first invalid file.
+    pub fn try_from_embed<E: rust_embed::RustEmbed>() -> Result<InternationalCore, Error> {
+        let mut core = InternationalCore::empty();
+        // Ordered by path, the order of the asset struct is not specified.
+        let mut paths = E::iter().collect::<Vec<_>>();
+        paths.sort();
+        let get = |path: &str| E::get(path).ok_or_else(|| Error::IoError { path: path.to_string(), cause: std::io::Error::from(std::io::ErrorKind::NotFound) });
+        let read = |path: &str| embedded_text(path, &get(path)?.data);
+        for path in paths {
+            core.insert_embedded(path.to_string(), &get(&path)?.data, &read)?;
+        }
+        Ok(core)
+    }
+}
+
+/// Text of the embedded file. Files compressed by gzip or zstd are decompressed, features `gzip`, `zstd`.
+#[cfg(any(feature = "incl_dir", feature = "rust_embed"))]
+fn embedded_text(path: &str, content: &[u8]) -> Result<String, Error> {
+    let content = compress::decompress(path, content)?;
+    String::from_utf8(content.into_owned())
+        .map_err(|e| Error::IoError { path: path.to_string(), cause: std::io::Error::new(std::io::ErrorKind::InvalidData, e) })
+}
+
+#[cfg(any(feature = "incl_dir", feature = "rust_embed"))]
+impl InternationalCore {
+    /// Loading the embedded file, the holder is static. The included files are read by `read` from the embedded files.
+    fn insert_embedded(&mut self, path: String, content: &[u8], read: IncludeReader) -> Result<(), Error> {
+        let content = embedded_text(&path, content)?;
+        let mut structure = match load_struct_with_includes(&content, Some(path), read) {
+            Err(Error::IncludeFile { path }) => {
+                log::trace!("Skipped {}, file is included by the locale files.", path);
+                return Ok(());
             }
-            msg_holder.insert(structure.locale.clone(), structure);
+            structure => structure?,
         };
-        InternationalCore {
-            holders: msg_holder
+        // Setting default watcher by StaticFileProvider immediately.
+        match structure.provider.lock() {
+            Ok(mut provider) => {
+                *provider = Box::new(StaticFileProvider {});
+            }
+            Err(_e) => {
+                panic!("Update provider by file has been failed. Poison mutex status.");
+            }
         }
+        // Embedded files can not be re-read.
+        structure.path = None;
+        self.insert_holder(structure);
+        Ok(())
     }
 }
 
+#[cfg(feature = "std")]
 impl InternationalCore {
     /// Creating new instance of InternationalCore.
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use sorrow_i18n::InternationalCore;
     /// let core = InternationalCore::new("folder/locales");
     /// ```
     /// If the file generates an error [Error::NotSupportedFileExtension], it will be skipped.
-    /// The rest of the errors cause panic.
+    /// The rest of the errors cause panic, use [InternationalCore::try_new] to handle them.
     pub fn new<S: Into<String>>(folder: S) -> InternationalCore {
+        Self::try_new(folder).unwrap_or_else(|e| panic!("Error while loading file. {:?}", e))
+    }
+
+    /// Creating new instance of InternationalCore, analogue [InternationalCore::new] without panic.
+    /// If the file generates an error [Error::NotSupportedFileExtension], it will be skipped.
+    /// The files are parsed in parallel. The rest of the errors (reading folder, [Error::InvalidStructure], [Error::InvalidHeader], watching by file,
+    /// [Error::DuplicateLocale]) are returned, if several files have errors, return [Error::LoadErrors].
+    /// Files are ordered by path, so the duplicate locale is always reported for the same file.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// match InternationalCore::try_new("folder/locales") {
+    ///     Ok(core) => {
+    ///         // Work with core
+    ///     }
+    ///     Err(e) => {
+    ///         eprintln!("Localization is not loaded: {}", e);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_new<S: Into<String>>(folder: S) -> Result<InternationalCore, Error> {
         let folder = folder.into();
-        let dir = std::fs::read_dir(&folder)
-            .map_err(|e| {
-                log::error!("{}", &e);
-                Error::IoError { path: folder, cause: e.to_string() }
-            }).unwrap();
-        let mut msg_holder = HashMap::new();
+        InternationalCore::empty().load_folder(folder)
+    }
 
-        for path in dir {
-            let full_path = path.unwrap().path().to_str().unwrap().to_string();
-            let holder = Holder::new(full_path);
+    /// Loading the files of the folder, without the subfolders.
+    fn load_folder(self, folder: String) -> Result<InternationalCore, Error> {
+        let paths = read_folder(&folder)?.into_iter().filter(|(_, is_dir)| !is_dir).map(|(path, _)| (path, None)).collect();
+        self.load_paths(paths)
+    }
+
+    /// Dry run of [InternationalCore::try_new]: every file of the folder is checked as by [Holder::check], and the locales
+    /// (and namespaces) are checked for duplicates. The holders and the watchers are not created. All problems are returned,
+    /// in order of the file paths, the empty result means the folder is loaded without errors. Useful for pre-deploy checks.
+    /// The files with [Error::NotSupportedFileExtension] and [Error::IncludeFile] are skipped, as by [InternationalCore::try_new].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let errors = InternationalCore::check("folder/locales");
+    /// for e in errors.iter() {
+    ///     eprintln!("{}", e);
+    /// }
+    /// assert!(errors.is_empty());
+    /// ```
+    pub fn check<S: Into<String>>(folder: S) -> Vec<Error> {
+        let paths = match read_folder(&folder.into()) {
+            Ok(paths) => paths,
+            Err(e) => return vec![e],
+        };
+        let mut errors = vec![];
+        let mut locales = HashSet::new();
+        for (path, _) in paths.into_iter().filter(|(_, is_dir)| !is_dir) {
+            match check_file(path) {
+                Ok(structure) => {
+                    let locale = normalize(&structure.locale);
+                    if !locales.insert((structure.namespace, locale.clone())) {
+                        errors.push(Error::DuplicateLocale { locale });
+                    }
+                }
+                Err(Error::NotSupportedFileExtension { .. } | Error::IncludeFile { .. }) => {}
+                Err(e) => errors.push(e),
+            }
+        }
+        errors
+    }
+
+    /// Analogue [InternationalCore::try_new] with the files of the subfolders. The namespace of the file in the subfolder is taken
+    /// from the relative path: `locales/checkout/I18N_EN.yaml` -> `checkout`, `locales/shop/cart/I18N_EN.yaml` -> `shop.cart`,
+    /// unless the file declares the namespace. The keys are available through [InternationalCore::namespace].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let core = InternationalCore::try_new_recursive("folder/locales").unwrap();
+    /// let title = core.namespace("checkout").get_or_default("data.title");
+    /// ```
+    pub fn try_new_recursive<S: Into<String>>(folder: S) -> Result<InternationalCore, Error> {
+        let mut paths = vec![];
+        let mut folders = vec![(folder.into(), None::<String>)];
+        while let Some((folder, namespace)) = folders.pop() {
+            for (path, is_dir) in read_folder(&folder)? {
+                if !is_dir {
+                    paths.push((path, namespace.clone()));
+                    continue;
+                }
+                let name = Path::new(&path).file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+                let namespace = match &namespace {
+                    None => name,
+                    Some(parent) => format!("{}.{}", parent, name),
+                };
+                folders.push((path, Some(namespace)));
+            }
+        }
+        paths.sort();
+        InternationalCore::empty().load_paths(paths)
+    }
+
+    /// Loading the files with the namespaces derived from the path.
+    fn load_paths(self, paths: Vec<(String, Option<String>)>) -> Result<InternationalCore, Error> {
+        let (paths, namespaces): (Vec<String>, Vec<Option<String>>) = paths.into_iter().unzip();
+        let mut core = self;
+        let mut errors = vec![];
+        let loaded = load_files(&paths, &|holder: &mut Holder| core.watch_holder(holder));
+        for (holder, namespace) in loaded.into_iter().zip(namespaces) {
             match holder {
                 Ok(mut holder) => {
-                    holder.watch().unwrap();
-                    msg_holder.insert(holder.locale.clone(), holder);
-                }
-                Err(err) => {
-                    match err {
-                        Error::NotSupportedFileExtension { path } => {
-                            log::trace!("Skipped {}, file is not supported .yml/.yaml extension.", path);
-                            continue;
-                        }
-                        e => {
-                            panic!("Error while loading file. {:?}", e)
-                        }
+                    if holder.namespace.is_none() {
+                        holder.namespace = namespace;
                     }
+                    let locale = holder.locale.clone();
+                    if let Err(e) = core.add_locale(&locale, holder) {
+                        errors.push(e);
+                    }
+                }
+                Err(Error::NotSupportedFileExtension { path }) => {
+                    log::trace!("Skipped {}, file is not supported .yml/.yaml extension.", path);
+                }
+                Err(Error::IncludeFile { path }) => {
+                    log::trace!("Skipped {}, file is included by the locale files.", path);
+                }
+                Err(e) => {
+                    errors.push(e);
                 }
             }
         }
-        InternationalCore { holders: msg_holder }
+        core.check_extends();
+        match errors.len() {
+            0 => Ok(core),
+            1 => Err(errors.remove(0)),
+            _ => Err(Error::LoadErrors { errors }),
+        }
     }
 
-    /// Get a mutable link to your localization. If no localization is found, you will get `None`.
-    pub fn get_by_locale(&self, locale: &str) -> Option<Data> {
-        let holders = &self.holders;
-        let holder = holders.get(locale)?;
-        Some(Data::new(Arc::clone(&holder.messages)))
+    /// Start watching of the loaded holder, the files are watched by the watcher of the core.
+    fn watch_holder(&self, holder: &mut Holder) -> Result<(), Error> {
+        #[cfg(feature = "watch")]
+        holder.set_folder_watcher(self.watcher.clone());
+        holder.watch()
     }
 
-    /// Get a mutable link to your system localization. If no localization is found, you will get `None`.
-    pub fn get_current_locale(&self) -> Option<Data> {
-        let locale = get_current_locale_or_default();
-        self.get_by_locale(&*locale)
+    /// Log the holders extending the locales which are not loaded, see [Holder::extends].
+    fn check_extends(&self) {
+        for holders in std::iter::once(&self.holders).chain(self.namespaces.values()) {
+            for holder in holders.values() {
+                if let Some(parent) = holder.extends().filter(|parent| !holders.contains_key(*parent)) {
+                    log::warn!("Locale {} extends {}, which is not loaded.", &holder.locale, parent);
+                }
+            }
+        }
     }
 
-    /// Get unmodifiable values (UnWatch). Perfect for localizations built into the project, due to which you get a small wrapper on `HashMap`.
-    /// If no localization is found, you will get `None`.
-    pub fn get_by_locale_state(&self, locale: &str) -> Option<UnWatchData> {
-        let holders = &self.holders;
-        let holder = holders.get(locale)?;
-        let read_state = holder.messages.read().unwrap();
-        Some(UnWatchData::new(&read_state))
+    /// Creating new instance of InternationalCore from several folders, e.g. system folder and user config folder, loaded in order.
+    /// Panic on error, use [InternationalCore::try_from_folders] to handle errors.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let core = InternationalCore::from_folders(["/usr/share/app/locales", "/home/user/.config/app/locales"]);
+    /// ```
+    pub fn from_folders<I: IntoIterator<Item = S>, S: Into<String>>(folders: I) -> InternationalCore {
+        Self::try_from_folders(folders).unwrap_or_else(|e| panic!("Error while loading file. {:?}", e))
     }
 
-    /// Get unmodifiable values (UnWatch). Perfect for localizations built into the project, due to which you get a small wrapper on `HashMap`.
-    /// If no localization is found, you will get `None`. If a localization is found, then returns the current system localization.
-    pub fn get_current_locale_state(&self) -> Option<UnWatchData> {
-        let locale = get_current_locale_or_default();
-        let state = self.get_by_locale_state(&*locale)?;
-        Some(state)
+    /// Analogue [InternationalCore::from_folders] without panic. Every folder is loaded by [InternationalCore::try_new], the first error is returned.
+    /// Keys of the later folder override keys of the earlier folders for the same locale (and namespace), other keys are kept.
+    /// Folders that do not exist are skipped. The files of the layered locale are watched by their providers,
+    /// [InternationalCore::reload] skips the layered locale.
+    pub fn try_from_folders<I: IntoIterator<Item = S>, S: Into<String>>(folders: I) -> Result<InternationalCore, Error> {
+        let mut core = InternationalCore::empty();
+        for folder in folders {
+            core.add_overrides(folder)?;
+        }
+        Ok(core)
     }
 
-    /// Overrides the current provider for your localization. Implementation example: `examples/custom_provider.rs`
-    pub fn add_provider(&mut self, locale: &str, provider: Box<dyn WatchProvider + 'static + Sync + Send>) -> Result<(), Error> {
-        let holder = self.holders.get(locale);
-        match holder {
+    /// Creating new instance of InternationalCore, the files are parsed on first access to the locale (e.g. [InternationalCore::get_by_locale]).
+    /// Useful if the application uses only a few locales of the folder.
+    /// The locale is taken from the file name: `I18N_EN.yaml` -> `en` (the part after the last `_`), the `locale` of the file is not checked
+    /// and `namespace` is not supported. Only the folder is read there, errors while parsing the files are passed to [InternationalCore::on_watch_error].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let core = InternationalCore::lazy("folder/locales").unwrap();
+    /// // Only I18N_EN.yaml is parsed.
+    /// let name = core.get_by_locale("EN").unwrap().get("data.name");
+    /// ```
+    pub fn lazy<S: Into<String>>(folder: S) -> Result<InternationalCore, Error> {
+        let folder = folder.into();
+        let dir = std::fs::read_dir(&folder).map_err(|e| Error::IoError { path: folder.clone(), cause: e })?;
+        let mut core = InternationalCore::empty();
+        for path in dir {
+            let path = path.map_err(|e| Error::IoError { path: folder.clone(), cause: e })?.path();
+            let extension = path.extension().and_then(|e| e.to_str());
+            if extension != Some("yaml") && extension != Some("yml") {
+                log::trace!("Skipped {}, file is not supported .yml/.yaml extension.", path.display());
+                continue;
+            }
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+            let locale = stem.rsplit('_').next().unwrap_or(stem);
+            let full_path = path.to_str()
+                .ok_or_else(|| Error::IoError {
+                    path: path.to_string_lossy().to_string(),
+                    cause: std::io::Error::new(std::io::ErrorKind::InvalidData, "path is not valid unicode."),
+                })?
+                .to_string();
+            core.add_locale(locale, Holder::lazy(locale, full_path))?;
+        }
+        Ok(core)
+    }
+
+    /// Return [InternationalCore] by holders, without files. If two holders have the same locale (and namespace), return [Error::DuplicateLocale].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::{GetData, Holder, InternationalCore};
+    /// let core = InternationalCore::from_holders(vec![
+    ///     Holder::from_pairs("EN", [("data.name", "Test")]),
+    ///     Holder::from_pairs("RU", [("data.name", "Тест")]),
+    /// ]).unwrap();
+    /// assert_eq!("Тест", core.get_by_locale("RU").unwrap().get_or_default("data.name"));
+    /// ```
+    pub fn from_holders<I: IntoIterator<Item = Holder>>(holders: I) -> Result<InternationalCore, Error> {
+        let mut core = InternationalCore::empty();
+        for holder in holders {
+            let locale = holder.locale.clone();
+            core.add_locale(&locale, holder)?;
+        }
+        Ok(core)
+    }
+
+    /// Builder of the core with catalogs assembled in code, see [builder::CatalogBuilder].
+    pub fn builder() -> CatalogBuilder {
+        CatalogBuilder::new()
+    }
+
+    /// Core without holders.
+    fn empty() -> InternationalCore {
+        InternationalCore {
+            holders: HashMap::new(),
+            namespaces: HashMap::new(),
+            format: FormatOptions::default(),
+            fallbacks: HashMap::new(),
+            default_fallback: vec![],
+            watch_error: None,
+            missing: None,
+            missing_handler: None,
+            missing_seen: None,
+            missing_policy: MissingKeyPolicy::default(),
+            case_insensitive: false,
+            locale_resolver: None,
+            provider_factories: ProviderFactories::default(),
+            metrics: None,
+            subscribers: Subscribers::default(),
+            #[cfg(feature = "watch")]
+            watcher: FolderWatcher::default(),
+        }
+    }
+
+    /// Patch the keys of the loaded locales by the files of the folder, e.g. strings customized by the end user.
+    /// Only the keys of the override files are replaced, other keys of the locale are kept. Locales missing in the core are added.
+    /// The folder is loaded by [InternationalCore::try_new], if it does not exist, nothing is changed.
+    /// The files of both layers are watched by their providers, [InternationalCore::reload] skips the patched locale.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let mut core = InternationalCore::new("locales/");
+    /// core.add_overrides("overrides/").unwrap();
+    /// ```
+    pub fn add_overrides<S: Into<String>>(&mut self, folder: S) -> Result<(), Error> {
+        let folder = folder.into();
+        if !Path::new(&folder).is_dir() {
+            log::debug!("Skipped {}, folder does not exist.", &folder);
+            return Ok(());
+        }
+        #[cfg(feature = "watch")]
+        let loaded = InternationalCore { watcher: self.watcher.clone(), ..InternationalCore::empty() };
+        #[cfg(not(feature = "watch"))]
+        let loaded = InternationalCore::empty();
+        let loaded = loaded.load_folder(folder)?;
+        let namespaced = loaded.namespaces.into_values().flat_map(|holders| holders.into_values());
+        for holder in loaded.holders.into_values().chain(namespaced) {
+            self.layer_holder(holder)?;
+        }
+        self.set_listeners();
+        Ok(())
+    }
+
+    /// Add the holder, if the locale exists the holder is the layer over the existing one, see [InternationalCore::try_from_folders].
+    fn layer_holder(&mut self, holder: Holder) -> Result<(), Error> {
+        let holders = match &holder.namespace {
+            None => &mut self.holders,
+            Some(namespace) => self.namespaces.entry(namespace.clone()).or_default(),
+        };
+        let holder = match holders.remove(&holder.locale) {
+            None => holder,
+            Some(base) => Holder::layered(base, holder)?,
+        };
+        holders.insert(holder.locale.clone(), holder);
+        Ok(())
+    }
+
+    /// Insert the loaded holder by locale, into its namespace if the file declares it. The existing holder is replaced.
+    fn insert_holder(&mut self, holder: Holder) {
+        holder.messages.set_case_insensitive(self.case_insensitive);
+        let holders = match &holder.namespace {
+            None => &mut self.holders,
+            Some(namespace) => self.namespaces.entry(namespace.clone()).or_default(),
+        };
+        holders.insert(holder.locale.clone(), holder);
+    }
+
+    /// Get a mutable link to your localization. If no localization is found, you will get `None`.
+    /// Keys missing in the localization are searched in the language holder (`en-GB` -> `en`) and by the fallback chain, see [InternationalCore::set_fallback_chain].
+    /// If the localization does not exist, but one of the fallback chain exists, you get data by chain.
+    pub fn get_by_locale(&self, locale: &str) -> Option<Data> {
+        self.data_in(&self.holders, locale)
+    }
+
+    /// Strict lookup of the message by locale and key, for services which must never show raw keys to users.
+    /// If the localization does not exist, you will get [Error::MissingLocale], if the key does not exist, [Error::MissingKey].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::{Error, InternationalCore};
+    /// let core = InternationalCore::builder().insert("EN", "data.name", "Test").build();
+    /// assert_eq!("Test", core.try_get("EN", "data.name").unwrap());
+    /// assert!(matches!(core.try_get("EN", "data.title"), Err(Error::MissingKey { .. })));
+    /// assert!(matches!(core.try_get("DE", "data.name"), Err(Error::MissingLocale { .. })));
+    /// ```
+    pub fn try_get(&self, locale: &str, key: &str) -> Result<String, Error> {
+        self.get_by_locale(locale)
+            .ok_or_else(|| Error::MissingLocale { locale: normalize(locale) })?
+            .try_get(key)
+    }
+
+    /// Get a mutable link to your system localization (or thread-local, see [locale::with_locale]). If no localization is found, you will get `None`.
+    pub fn get_current_locale(&self) -> Option<Data> {
+        let locale = self.current_locale();
+        self.get_by_locale(&locale)
+    }
+
+    /// Get unmodifiable values (UnWatch). Perfect for localizations built into the project, due to which you get a small wrapper on `HashMap`.
+    /// If no localization is found, you will get `None`.
+    /// Keys missing in the localization are taken from the fallback chain at the moment of the call.
+    pub fn get_by_locale_state(&self, locale: &str) -> Option<UnWatchData> {
+        self.state_in(&self.holders, locale)
+    }
+
+    /// Get unmodifiable values (UnWatch). Perfect for localizations built into the project, due to which you get a small wrapper on `HashMap`.
+    /// If no localization is found, you will get `None`. If a localization is found, then returns the current system localization.
+    pub fn get_current_locale_state(&self) -> Option<UnWatchData> {
+        let locale = self.current_locale();
+        let state = self.get_by_locale_state(&locale)?;
+        Some(state)
+    }
+
+    /// Register the factory of the provider, so the files select it by name instead of [InternationalCore::add_provider] calls:
+    ///
+    /// ```yaml
+    /// kind: I18N
+    /// locale: EN
+    /// provider: redis
+    /// ```
+    ///
+    /// The provider is created for the loaded holders with the provider of this name and for the holders added later (lazy holders on loading).
+    /// Files with the provider not registered yet are not watched. The registered factory of the same name is replaced,
+    /// the providers created earlier are kept. Returns the first error of the factory or the provider, the rest holders get the provider anyway.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use sorrow_i18n::{Error, GetData, Holder, InternationalCore, Messages, WatchProvider};
+    ///
+    /// struct RedisProvider;
+    ///
+    /// impl WatchProvider for RedisProvider {
+    ///     fn watch(&mut self) -> Result<(), Error> { Ok(()) }
+    ///     fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
+    ///         data.insert("data.source", "redis");
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let holder = Holder::parse("kind: I18N\nlocale: EN\nprovider: redis\ndata:\n  name: Test\n").unwrap();
+    /// let mut core = InternationalCore::from_holders(vec![holder]).unwrap();
+    /// core.register_provider_factory("redis", |_locale, _path| Ok(Box::new(RedisProvider))).unwrap();
+    /// assert_eq!(Some("redis".to_string()), core.get_by_locale("EN").unwrap().get("data.source"));
+    /// ```
+    pub fn register_provider_factory<F>(&mut self, name: &str, factory: F) -> Result<(), Error>
+    where
+        F: Fn(&str, Option<&str>) -> Result<Box<dyn WatchProvider + Send + Sync>, Error> + Send + Sync + 'static,
+    {
+        let factory: ProviderFactory = Arc::new(factory);
+        self.provider_factories.write().unwrap().insert(name.to_string(), Arc::clone(&factory));
+        let kind = Some(Providers::Custom(name.to_string()));
+        let mut result = Ok(());
+        let watcher = self.shared_watcher();
+        let namespaced = self.namespaces.values().flat_map(|holders| holders.values());
+        for holder in self.holders.values().chain(namespaced).filter(|holder| holder.provider_kind() == kind) {
+            if let Err(e) = holder.install_provider(&factory, &watcher) {
+                log::error!("Error while creating {} provider of {} locale. {}", name, &holder.locale, &e);
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        self.set_listeners();
+        result
+    }
+
+    /// Overrides the current provider for your localization. Implementation example: `examples/custom_provider.rs`
+    pub fn add_provider(&mut self, locale: &str, provider: Box<dyn WatchProvider + 'static + Sync + Send>) -> Result<(), Error> {
+        let holder = self.holders.get(&normalize(locale));
+        match holder {
             None => {
                 log::warn!("The provider has not been added. The locale to which you tried to add the provider does not exist.");
                 return Err(Error::ProviderNotAddedError { locale: locale.to_string(), cause: "locale not found.".to_string() });
             }
             Some(holder) => {
+                // The provider of the file must not replace the added one.
+                holder.load_lazy();
                 let guard = holder.provider.lock();
                 match guard {
                     Ok(mut pr) => {
+                        if let Err(e) = pr.unwatch() {
+                            log::error!("Error while stopping the replaced provider of {}. Message: {}", locale, e);
+                        }
                         *pr = provider;
+                        if let Some(listener) = &self.watch_error {
+                            pr.set_error_listener(Arc::clone(listener));
+                        }
+                        pr.set_listener(holder.changes.as_listener());
                         pr.set_data(Arc::clone(&holder.messages))?;
+                        #[cfg(feature = "watch")]
+                        pr.set_folder_watcher(self.watcher.clone());
                         pr.watch()?;
                     }
                     Err(_e) => {
@@ -429,176 +1488,1834 @@ impl InternationalCore {
         Ok(())
     }
 
-    /// Add locale with custom locale holder
-    pub fn add_locale(&mut self, locale: &str, locale_holder: Holder) -> Result<(), Error> {
-        let holder = self.holders.get(locale);
-        return if holder.is_some() {
-            Err(Error::DuplicateLocale { locale: locale.to_string() })
+    /// Watcher of the folders shared by the file providers of the core: the files loaded by the core, the lazy files and
+    /// the providers added by [InternationalCore::add_provider]. Every folder is watched once, the events are dispatched to the holders.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let core = InternationalCore::new("locale/");
+    /// println!("Watched folders: {:?}", core.folder_watcher().folders());
+    /// ```
+    #[cfg(feature = "watch")]
+    pub fn folder_watcher(&self) -> &FolderWatcher {
+        &self.watcher
+    }
+
+    /// Watcher for the providers created by the factories, see [InternationalCore::register_provider_factory].
+    fn shared_watcher(&self) -> SharedWatcher {
+        #[cfg(feature = "watch")]
+        return self.watcher.clone();
+        #[cfg(not(feature = "watch"))]
+        SharedWatcher
+    }
+
+    /// Wrap interpolated values in Unicode FSI/PDI isolation marks, so mixed LTR/RTL text does not render scrambled.
+    /// Disabled by default. Applies to data received after the call.
+    pub fn set_bidi_isolation(&mut self, enabled: bool) {
+        self.format.bidi_isolation = enabled;
+    }
+
+    /// Escaping of interpolated values, see [EscapePolicy]. The messages in the catalog are trusted and never escaped.
+    /// [EscapePolicy::None] by default. Applies to data received after the call.
+    pub fn set_escape_policy(&mut self, escape: EscapePolicy) {
+        self.format.escape = escape;
+    }
+
+    /// Loaded locale identifiers (normalized, see [locale::normalize]), sorted.
+    /// Useful for a language picker UI.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let core = InternationalCore::new("locale");
+    /// for locale in core.locales() {
+    ///     println!("{}: {}", &locale, core.locale_description(&locale).unwrap_or_default());
+    /// }
+    /// ```
+    pub fn locales(&self) -> Vec<String> {
+        let mut locales = self.holders.keys().cloned().collect::<Vec<String>>();
+        locales.sort();
+        locales
+    }
+
+    /// The first locale of the `Accept-Language` header (see [locale::parse_accept_language]) which is loaded, directly or by its language (`en-GB` -> `en`).
+    /// The requested locale is returned, so [InternationalCore::get_by_locale] keeps the region. If no locale is loaded, you will get `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::InternationalCore;
+    /// let core = InternationalCore::builder().insert("EN", "data.name", "Test").insert("RU", "data.name", "Тест").build();
+    /// assert_eq!(Some("en-GB".to_string()), core.negotiate("de, en-GB;q=0.8, ru;q=0.5"));
+    /// assert_eq!(None, core.negotiate("de"));
+    /// ```
+    pub fn negotiate(&self, accept_language: &str) -> Option<String> {
+        parse_accept_language(accept_language)
+            .into_iter()
+            .find(|locale| truncations(locale).iter().any(|l| self.holders.contains_key(l)))
+    }
+
+    /// Description of the locale from the file structure. If the locale is not found or the description is not specified, you will get `None`.
+    pub fn locale_description(&self, locale: &str) -> Option<String> {
+        let holder = self.holders.get(&normalize(locale))?;
+        holder.load_lazy();
+        holder.description().map(|d| d.to_string())
+    }
+
+    /// Setting the fallback chain for locale. If a key is missing in `locale`, the lookup falls through the chain in order before returning the key itself.
+    /// Overrides the default chain (see [InternationalCore::set_default_fallback_chain]) for this locale.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let mut core = InternationalCore::new("locale");
+    /// core.set_fallback_chain("fr-CA", &["fr", "en"]);
+    /// ```
+    pub fn set_fallback_chain<S: AsRef<str>>(&mut self, locale: &str, chain: &[S]) {
+        self.fallbacks.insert(normalize(locale), chain.iter().map(|l| normalize(l.as_ref())).collect());
+    }
+
+    /// Setting the fallback chain for all locales without own chain.
+    pub fn set_default_fallback_chain<S: AsRef<str>>(&mut self, chain: &[S]) {
+        self.default_fallback = chain.iter().map(|l| normalize(l.as_ref())).collect();
+    }
+
+    /// Getting keys scoped by namespace. Files declare the namespace by `namespace` field, their keys are available only through the namespace,
+    /// so collisions across modules are impossible. Fallback chains and format options of the core are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let core = InternationalCore::new("locale");
+    /// // File with `namespace: checkout` and key `title`
+    /// let title = core.namespace("checkout").get_by_locale("EN").unwrap().get("title");
+    /// ```
+    pub fn namespace(&self, namespace: &str) -> Namespace<'_> {
+        Namespace::new(self, namespace, self.namespaces.get(namespace))
+    }
+
+    /// Loaded namespaces, sorted.
+    pub fn namespaces(&self) -> Vec<String> {
+        let mut namespaces = self.namespaces.keys().cloned().collect::<Vec<String>>();
+        namespaces.sort();
+        namespaces
+    }
+
+    /// Report of the keys present in one locale but missing in others, see [check::ConsistencyReport].
+    /// Own keys of the holders are compared, the fallback chains are not used. Namespaces are checked by [Namespace::check_consistency].
+    pub fn check_consistency(&self) -> ConsistencyReport {
+        check::consistency(&self.holders)
+    }
+
+    /// Translation progress of every locale against `base_locale`: percent of the translated keys and the missing keys, see [check::CoverageReport].
+    /// Own keys of the holders are compared, empty messages are not translated. If the base locale is not loaded, [Error::MissingLocale].
+    pub fn coverage(&self, base_locale: &str) -> Result<CoverageReport, Error> {
+        check::coverage(&self.holders, base_locale)
+    }
+
+    /// Check that the messages of every locale use the same placeholders as the messages of `base_locale`, e.g. `{name}` is not lost
+    /// or misspelled by the translator. Keys missing or empty in the locale are not checked, see [InternationalCore::coverage].
+    /// Returns [check::DiagnosticKind::PlaceholderMismatch] per key, sorted by locale and key. If the base locale is not loaded, [Error::MissingLocale].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::check::DiagnosticKind;
+    /// use sorrow_i18n::InternationalCore;
+    /// let core = InternationalCore::builder()
+    ///     .insert("EN", "data.greeting", "Hello, {name}!")
+    ///     .insert("RU", "data.greeting", "Привет, {nmae}!")
+    ///     .insert("DE", "data.greeting", "Hallo, {name}!")
+    ///     .build();
+    /// let diagnostics = core.check_placeholders("EN").unwrap();
+    /// assert_eq!(1, diagnostics.len());
+    /// assert_eq!("ru", diagnostics[0].locale);
+    /// assert_eq!(DiagnosticKind::PlaceholderMismatch {
+    ///     base: "en".to_string(),
+    ///     missing: vec!["name".to_string()],
+    ///     extra: vec!["nmae".to_string()],
+    /// }, diagnostics[0].kind);
+    /// ```
+    pub fn check_placeholders(&self, base_locale: &str) -> Result<Vec<Diagnostic>, Error> {
+        check::placeholders(&self.holders, base_locale)
+    }
+
+    /// Run the checks of all holders, including namespaces (see [Holder::validate]), and return all problems found, sorted by locale.
+    /// `max_len` of the key (see [messages::Metadata]) is checked for all locales, even if only the base locale declares it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let core = InternationalCore::new("locale/");
+    /// for diagnostic in core.validate() {
+    ///     eprintln!("{}", diagnostic);
+    /// }
+    /// ```
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        // `max_len` of the key declared by one locale applies to all locales of the namespace.
+        let groups = std::iter::once(&self.holders)
+            .chain(self.namespaces.values())
+            .map(|holders| (holders, check::limits(holders.values())))
+            .collect::<Vec<_>>();
+        let mut holders = groups.iter()
+            .flat_map(|(holders, limits)| holders.values().map(move |h| (h, limits)))
+            .collect::<Vec<(&Holder, &HashMap<String, usize>)>>();
+        holders.sort_by(|(a, _), (b, _)| (a.locale(), a.namespace()).cmp(&(b.locale(), b.namespace())));
+        holders.into_iter().flat_map(|(h, limits)| check::validate(h, limits)).collect()
+    }
+
+    /// Write all locales, including namespaces, to one document: the list of [FileStructure] (see [Holder::to_file_structure]),
+    /// sorted by namespace and locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::{BundleFormat, InternationalCore};
+    /// let core = InternationalCore::builder().insert("EN", "data.name", "Test").insert("RU", "data.name", "Тест").build();
+    /// let mut json = Vec::new();
+    /// core.export_bundle(&mut json, BundleFormat::Json).unwrap();
+    /// assert!(String::from_utf8(json).unwrap().contains("\"locale\": \"ru\""));
+    /// ```
+    pub fn export_bundle<W: Write>(&self, writer: W, format: BundleFormat) -> Result<(), Error> {
+        let mut holders = self.holders.values()
+            .chain(self.namespaces.values().flat_map(|holders| holders.values()))
+            .collect::<Vec<&Holder>>();
+        holders.sort_by(|a, b| (a.namespace(), a.locale()).cmp(&(b.namespace(), b.locale())));
+        let bundle = holders.into_iter().map(|h| h.to_file_structure()).collect::<Vec<FileStructure>>();
+        match format {
+            BundleFormat::Yaml => serde_yaml::to_writer(writer, &bundle)
+                .map_err(|e| Error::ExportError { format, cause: e.to_string() }),
+            BundleFormat::Json => serde_json::to_writer_pretty(writer, &bundle)
+                .map_err(|e| Error::ExportError { format, cause: e.to_string() }),
+        }
+    }
+
+    /// [Data] by locale in `holders`, see [InternationalCore::get_by_locale].
+    fn data_in(&self, holders: &HashMap<String, Holder>, locale: &str) -> Option<Data> {
+        let locale = normalize(locale);
+        let mut chain = self.chain(holders, &locale).into_iter();
+        let holder = chain.next()?;
+        let mut data = Data::new(&locale, Arc::clone(&holder.messages));
+        data.fallbacks = chain.map(|h| Arc::clone(&h.messages)).collect();
+        data.format = self.format.clone();
+        data.missing = self.missing.clone();
+        data.metrics = self.metrics.clone();
+        Some(data)
+    }
+
+    /// [UnWatchData] by locale in `holders`, see [InternationalCore::get_by_locale_state].
+    fn state_in(&self, holders: &HashMap<String, Holder>, locale: &str) -> Option<UnWatchData> {
+        let locale = normalize(locale);
+        let chain = self.chain(holders, &locale);
+        if chain.is_empty() {
+            return None;
+        }
+        // The first holder in the chain overrides the rest.
+        let mut state = HashMap::new();
+        for holder in chain.iter().rev() {
+            state.extend(holder.messages.to_map());
+        }
+        let mut data = UnWatchData::new(&locale, &state);
+        data.case_insensitive = self.case_insensitive;
+        data.format = self.format.clone();
+        data.missing = self.missing.clone();
+        data.metrics = self.metrics.clone();
+        Some(data)
+    }
+
+    /// Existing holders by locale and its fallback chain, in lookup order. Locale must be normalized.
+    /// Every locale is followed by its parents (see [Holder::extends]) and its truncated forms (`en-GB` -> `en`), see [locale::truncations].
+    fn chain<'a>(&self, holders: &'a HashMap<String, Holder>, locale: &str) -> Vec<&'a Holder> {
+        let fallback = self.fallbacks.get(locale).unwrap_or(&self.default_fallback);
+        let mut chain: Vec<&Holder> = vec![];
+        for l in std::iter::once(locale).chain(fallback.iter().map(|l| l.as_str())).flat_map(truncations) {
+            let mut next = holders.get(&l);
+            // The parents (see [Holder::extends]) follow the holder.
+            while let Some(holder) = next {
+                if chain.iter().any(|h| std::ptr::eq(*h, holder)) {
+                    break;
+                }
+                holder.load_lazy();
+                chain.push(holder);
+                next = holder.extends().and_then(|parent| holders.get(parent));
+            }
+        }
+        chain
+    }
+
+    /// Statistics of the loading and the reloads of the holders (and of the holders of the namespaces), sorted by namespace and locale.
+    /// Health endpoints can report whether the hot reload works, see [stats::ReloadStats].
+    /// The reloads by [FileProvider], [Holder::reload] and [InternationalCore::reload] are counted. The lazy holders have no statistics until loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::InternationalCore;
+    /// let core = InternationalCore::new("resources/en_ru");
+    /// let stats = core.stats();
+    /// assert_eq!(vec!["ee", "en", "ru"], stats.iter().map(|s| s.locale.as_str()).collect::<Vec<&str>>());
+    /// assert!(stats.iter().all(|s| s.reload.last_reload.is_some() && s.reload.last_error.is_none()));
+    /// ```
+    pub fn stats(&self) -> Vec<HolderStats> {
+        let namespaced = self.namespaces.values().flat_map(|holders| holders.values());
+        let mut stats = self.holders.values().chain(namespaced)
+            .map(|holder| HolderStats {
+                locale: holder.locale.clone(),
+                namespace: holder.namespace.clone(),
+                path: holder.path.clone(),
+                reload: holder.messages.stats(),
+            })
+            .collect::<Vec<HolderStats>>();
+        stats.sort_by(|a, b| (&a.namespace, &a.locale).cmp(&(&b.namespace, &b.locale)));
+        stats
+    }
+
+    /// Re-read the source files of all holders on demand, for example when file watching is disabled and the application receives a reload signal.
+    /// Holders without source file (embedded by `incl_dir`, added manually) are skipped.
+    /// All holders are reloaded, the first error is returned. The holder with error keeps the previous data.
+    pub fn reload(&self) -> Result<(), Error> {
+        let mut result = Ok(());
+        let namespaced = self.namespaces.values().flat_map(|holders| holders.values());
+        for holder in self.holders.values().chain(namespaced) {
+            if let Err(e) = self.reload_holder(holder) {
+                log::error!("Error while reloading {} locale. {}", &holder.locale, &e);
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+
+    /// Re-read the source file of the locale holder on demand, including the holders of the locale in namespaces.
+    /// If the locale is not found, return [Error::MissingLocale].
+    pub fn reload_locale(&self, locale: &str) -> Result<(), Error> {
+        let locale = normalize(locale);
+        let namespaced = self.namespaces.values().flat_map(|holders| holders.get(&locale));
+        let holders = self.holders.get(&locale).into_iter().chain(namespaced).collect::<Vec<&Holder>>();
+        if holders.is_empty() {
+            return Err(Error::MissingLocale { locale });
+        }
+        for holder in holders {
+            self.reload_holder(holder)?;
+        }
+        Ok(())
+    }
+
+    /// Reload the holder, see [InternationalCore::reloaded].
+    fn reload_holder(&self, holder: &Holder) -> Result<(), Error> {
+        self.reloaded(holder, holder.reload())
+    }
+
+    /// Pass the result of the reload of the holder to the instrumentation, shared with the async reload.
+    /// The failure is passed here, the success is passed by the change listener of the holder (see [InternationalCore::set_metrics]).
+    pub(crate) fn reloaded(&self, holder: &Holder, result: Result<(), Error>) -> Result<(), Error> {
+        result.inspect_err(|e| {
+            if let Some(metrics) = &self.metrics {
+                metrics.reload(&holder.locale, Some(e));
+            }
+        })
+    }
+
+    /// Merge the catalogs of `other` into this core, for example plugins can ship their own locale folders.
+    /// Locales that do not exist are added with their providers. For existing locales the keys are copied by `policy`,
+    /// the provider of the merged holder is dropped (its file is not watched anymore) and the merged keys are lost when the existing holder is reloaded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{InternationalCore, MergePolicy};
+    /// let mut core = InternationalCore::new("locale");
+    /// let plugin = InternationalCore::new("plugin/locale");
+    /// core.merge(plugin, MergePolicy::KeepExisting).unwrap();
+    /// ```
+    pub fn merge(&mut self, other: InternationalCore, policy: MergePolicy) -> Result<(), Error> {
+        if policy == MergePolicy::Error {
+            check_merge_conflict(&self.holders, &other.holders)?;
+            for (namespace, holders) in other.namespaces.iter() {
+                if let Some(existing) = self.namespaces.get(namespace) {
+                    check_merge_conflict(existing, holders)?;
+                }
+            }
+        }
+
+        merge_holders(&mut self.holders, other.holders, policy);
+        for (namespace, holders) in other.namespaces.into_iter() {
+            merge_holders(self.namespaces.entry(namespace).or_default(), holders, policy);
+        }
+        self.set_listeners();
+        Ok(())
+    }
+
+    /// Remove the locale holder, including the holders of the namespaces. The providers of the holders are dropped,
+    /// so the file watchers (see [Providers::FileProvider]) are stopped. The namespace without holders is removed.
+    /// Data received earlier by [InternationalCore::get_by_locale] keeps the last loaded values.
+    /// If the locale is not found, return [Error::MissingLocale].
+    pub fn remove_locale(&mut self, locale: &str) -> Result<(), Error> {
+        let locale = normalize(locale);
+        let mut removed = self.holders.remove(&locale).is_some();
+        for holders in self.namespaces.values_mut() {
+            removed |= holders.remove(&locale).is_some();
+        }
+        self.namespaces.retain(|_, holders| !holders.is_empty());
+        match removed {
+            false => {
+                Err(Error::MissingLocale { locale })
+            }
+            true => {
+                log::debug!("Locale {} has been removed.", &locale);
+                Ok(())
+            }
+        }
+    }
+
+    /// Add locale with custom locale holder. If the holder declares the namespace, it is added to the namespace.
+    /// If the file of the holder selects the registered provider (see [InternationalCore::register_provider_factory]), the provider is created and started.
+    pub fn add_locale(&mut self, locale: &str, mut locale_holder: Holder) -> Result<(), Error> {
+        let locale = normalize(locale);
+        let watcher = self.shared_watcher();
+        if let Some(lazy) = &mut locale_holder.lazy {
+            lazy.factories = Arc::clone(&self.provider_factories);
+            #[cfg(feature = "watch")]
+            {
+                lazy.watcher = self.watcher.clone();
+            }
+        }
+        let holders = match &locale_holder.namespace {
+            None => &mut self.holders,
+            Some(namespace) => self.namespaces.entry(namespace.clone()).or_default(),
+        };
+        let holder = holders.get(&locale);
+        if holder.is_some() {
+            Err(Error::DuplicateLocale { locale })
         } else {
-            self.holders.insert(locale.to_string(), locale_holder);
+            locale_holder.messages.set_case_insensitive(self.case_insensitive);
+            locale_holder.resolve_provider(&self.provider_factories, &watcher, locale_holder.provider_kind().as_ref())?;
+            holders.insert(locale, locale_holder);
+            self.set_listeners();
             Ok(())
-        };
+        }
     }
-}
 
-/// Getting data by holder's.
-pub trait GetData {
-    /// Getting locale message by key. If key does not exist, return [Option::None].
+    /// Set the listener of the errors while watching (reload of the file failed, watcher error...), so the application can alert or retry.
+    /// The listener is set to providers of all holders, including the providers added later.
+    /// The errors are logged as before.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let mut core = InternationalCore::new("locale/");
+    /// core.on_watch_error(|e| eprintln!("Locale has not been reloaded: {}", e));
+    /// ```
+    pub fn on_watch_error<F: Fn(&Error) + Send + Sync + 'static>(&mut self, listener: F) {
+        self.watch_error = Some(Arc::new(listener));
+        self.set_listeners();
+    }
+
+    /// Set the handler of the missing keys. The handler is called by [GetData::get_or_default] and other lookups returning the key
+    /// when the key is not found, so untranslated keys can be logged, collected or substituted centrally.
+    /// If the handler returns `None`, the key is returned as before. The handler is used by [Data] and [UnWatchData] created after the call.
     ///
     /// # Examples
     ///
     /// ```
     /// use sorrow_i18n::{GetData, InternationalCore};
-    /// let i18n = InternationalCore::new("locale");
-    /// let en = i18n.get_by_locale("en").unwrap();
-    /// let my_data = en.get("my_data");
+    /// let mut core = InternationalCore::builder().insert("EN", "data.name", "Test").build();
+    /// core.on_missing(|locale, key| Some(format!("[{}: {}]", locale, key)));
+    /// let en = core.get_by_locale("EN").unwrap();
+    /// assert_eq!("Test", en.get_or_default("data.name"));
+    /// assert_eq!("[en: data.title]", en.get_or_default("data.title"));
+    /// assert_eq!(None, en.get("data.title"));
+    /// ```
+    pub fn on_missing<F: Fn(&str, &str) -> Option<String> + Send + Sync + 'static>(&mut self, handler: F) {
+        self.missing_handler = Some(Arc::new(handler));
+        self.compose_missing();
+    }
+
+    /// Log every missing (locale, key) only once, so the misses on hot paths do not flood the logs.
+    /// The logged keys are kept until the mode is disabled. The handler (see [InternationalCore::on_missing]) is called on every miss as before.
+    /// Applies to [Data] and [UnWatchData] created after the call.
+    ///
+    /// # Examples
     ///
-    /// match my_data {
-    ///     None => {
-    ///         panic!("No found my_data key.")
-    ///     }
-    ///     Some(k) => {
-    ///         println!("Found key my_data, value: {}", &k)
-    ///     }
-    /// }
     /// ```
-    fn get<S: AsRef<str>>(&self, key: S) -> Option<String>;
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let mut core = InternationalCore::builder().insert("EN", "data.name", "Test").build();
+    /// core.log_missing_once(true);
+    /// let en = core.get_by_locale("EN").unwrap();
+    /// // Logged by `warn` level
+    /// assert_eq!("data.title", en.get_or_default("data.title"));
+    /// // Not logged
+    /// assert_eq!("data.title", en.get_or_default("data.title"));
+    /// ```
+    pub fn log_missing_once(&mut self, enabled: bool) {
+        self.missing_seen = match enabled {
+            true => Some(self.missing_seen.take().unwrap_or_default()),
+            false => None,
+        };
+        self.compose_missing();
+    }
+
+    /// Lowercase the keys at load and lookup time, so `Data.Name` and `data.name` resolve to the same message,
+    /// e.g. when the keys come from the sources with inconsistent casing. Applies to all holders, including the reloaded and added later.
+    /// The keys are returned lowercased (see [GetData::keys]), the lookups by [KeyId] must use the lowercased keys.
+    /// If two keys differ only by case, one of them is kept. Disabling restores the original case of the keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let mut core = InternationalCore::builder().insert("EN", "Data.Name", "Test").build();
+    /// assert_eq!(None, core.get_by_locale("EN").unwrap().get("data.name"));
+    ///
+    /// core.case_insensitive_keys(true);
+    /// let en = core.get_by_locale("EN").unwrap();
+    /// assert_eq!("Test", en.get_or_default("data.name"));
+    /// assert_eq!("Test", en.get_or_default("DATA.NAME"));
+    /// assert_eq!(vec!["data.name"], en.keys());
+    /// ```
+    pub fn case_insensitive_keys(&mut self, enabled: bool) {
+        self.case_insensitive = enabled;
+        let namespaced = self.namespaces.values().flat_map(|holders| holders.values());
+        for holder in self.holders.values().chain(namespaced) {
+            holder.messages.set_case_insensitive(enabled);
+        }
+    }
+
+    /// Set the resolver of the current locale, e.g. by the profile of the user, the claim of the token or the config of the app.
+    /// The thread-local locale (see [locale::with_locale]) takes precedence, if the resolver returns `None`, the locale of the system is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let mut core = InternationalCore::builder()
+    ///     .insert("EN", "data.name", "Test")
+    ///     .insert("RU", "data.name", "Тест")
+    ///     .build();
+    /// core.set_locale_resolver(|| Some("ru".to_string()));
+    /// assert_eq!("ru", core.current_locale());
+    /// assert_eq!("Тест", core.get_current_locale().unwrap().get_or_default("data.name"));
+    ///
+    /// let en = sorrow_i18n::locale::with_locale("en", || core.current_locale());
+    /// assert_eq!("en", en);
+    /// ```
+    pub fn set_locale_resolver<F: Fn() -> Option<String> + Send + Sync + 'static>(&mut self, resolver: F) {
+        self.locale_resolver = Some(Arc::new(resolver));
+    }
+
+    /// Remove the resolver of the current locale, see [InternationalCore::set_locale_resolver].
+    pub fn clear_locale_resolver(&mut self) {
+        self.locale_resolver = None;
+    }
+
+    /// Current locale of the core: the thread-local locale (see [locale::with_locale]), the locale of the resolver
+    /// (see [InternationalCore::set_locale_resolver]), the locale of the environment variables if preferred or the system locale.
+    pub fn current_locale(&self) -> String {
+        locale::scoped_locale()
+            .or_else(|| self.locale_resolver.as_ref().and_then(|resolver| resolver()))
+            .unwrap_or_else(get_current_locale_or_default)
+    }
+
+    /// Set the value of the missing keys, when the handler (see [InternationalCore::on_missing]) is not set or returns `None`.
+    /// The policy is used by [GetData::get_or_default] and other lookups returning the key, and by the macros of the static core.
+    /// Applies to [Data] and [UnWatchData] created after the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::{GetData, InternationalCore, MissingKeyPolicy};
+    /// let mut core = InternationalCore::builder()
+    ///     .insert("EN", "data.name", "Test")
+    ///     .insert("EN", "data.title", "Title")
+    ///     .insert("RU", "data.name", "Тест")
+    ///     .build();
+    /// core.set_missing_policy(MissingKeyPolicy::Empty);
+    /// assert_eq!("", core.get_by_locale("RU").unwrap().get_or_default("data.title"));
+    ///
+    /// core.set_missing_policy(MissingKeyPolicy::Fallback("EN".to_string()));
+    /// assert_eq!("Title", core.get_by_locale("RU").unwrap().get_or_default("data.title"));
+    ///
+    /// core.set_missing_policy(MissingKeyPolicy::Custom(|locale, key| format!("{}?{}", locale, key)));
+    /// assert_eq!("ru?data.subtitle", core.get_by_locale("RU").unwrap().get_or_default("data.subtitle"));
+    /// ```
+    pub fn set_missing_policy(&mut self, policy: MissingKeyPolicy) {
+        self.missing_policy = policy;
+        self.compose_missing();
+    }
+
+    /// Value of the missing key by the handler and the policy, see [InternationalCore::set_missing_policy].
+    pub(crate) fn missing_key(&self, locale: &str, key: &str) -> String {
+        missing_value(&self.missing, &normalize(locale), key)
+    }
+
+    /// Set the instrumentation of the lookups by [Data] and [UnWatchData] created after the call, and of the reloads of all holders.
+    /// See [metrics::Metrics], or `MetricsRecorder` with feature `metrics`.
+    pub fn set_metrics<M: Metrics + 'static>(&mut self, metrics: M) {
+        self.metrics = Some(Arc::new(metrics));
+        self.set_listeners();
+    }
+
+    /// Handler used by [Data]: the handler of [InternationalCore::on_missing] with logging of [InternationalCore::log_missing_once],
+    /// then [MissingKeyPolicy].
+    fn compose_missing(&mut self) {
+        let handler = self.policy_handler();
+        self.missing = match self.missing_seen.clone() {
+            None => handler,
+            Some(seen) => Some(Arc::new(move |locale: &str, key: &str| {
+                let mut seen = seen.lock().unwrap();
+                if !seen.get(locale).is_some_and(|keys| keys.contains(key)) {
+                    log::warn!("Key {:?} not found in {:?} locale.", key, locale);
+                    seen.entry(locale.to_string()).or_default().insert(key.to_string());
+                }
+                drop(seen);
+                handler.as_ref().and_then(|h| h(locale, key))
+            })),
+        };
+    }
+
+    /// The handler of [InternationalCore::on_missing], then [MissingKeyPolicy]. `None` if the key is returned.
+    fn policy_handler(&self) -> Option<MissingHandler> {
+        let handler = self.missing_handler.clone();
+        let policy: MissingHandler = match &self.missing_policy {
+            MissingKeyPolicy::ReturnKey => return handler,
+            MissingKeyPolicy::Empty => Arc::new(|_, _| Some(String::new())),
+            MissingKeyPolicy::Panic => Arc::new(|locale, key| panic!("Key {:?} not found in {:?} locale.", key, locale)),
+            MissingKeyPolicy::Fallback(locale) => {
+                let messages = self.holders.get(&normalize(locale)).map(|holder| {
+                    holder.load_lazy();
+                    Arc::clone(&holder.messages)
+                });
+                Arc::new(move |_, key| Some(messages.as_ref().and_then(|m| m.get(key)).unwrap_or_else(|| key.to_string())))
+            }
+            MissingKeyPolicy::Custom(f) => {
+                let f = *f;
+                Arc::new(move |locale, key| Some(f(locale, key)))
+            }
+        };
+        Some(Arc::new(move |locale: &str, key: &str| {
+            handler.as_ref().and_then(|h| h(locale, key)).or_else(|| policy(locale, key))
+        }))
+    }
+
+    /// Subscribe to the changes of the data. The event with locale and changed keys is sent whenever a provider refreshes the data
+    /// (or [InternationalCore::reload] re-reads the file), and only if the values have actually been changed.
+    /// The subscription keeps the last published map of the holder to find the changed keys.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let mut core = InternationalCore::new("locale/");
+    /// let events = core.subscribe();
+    /// std::thread::spawn(move || {
+    ///     for event in events {
+    ///         println!("{} has been changed: {:?}", event.locale, event.keys);
+    ///     }
+    /// });
+    /// ```
+    pub fn subscribe(&mut self) -> Receiver<ReloadEvent> {
+        let (sender, receiver) = channel();
+        self.on_reload(move |event| {
+            let _ = sender.send(event.clone());
+        });
+        receiver
+    }
+
+    /// Register the callback of the changes of the data, see [InternationalCore::subscribe].
+    pub fn on_reload<F: Fn(&ReloadEvent) + Send + Sync + 'static>(&mut self, callback: F) {
+        self.subscribers.write().unwrap().push(Box::new(callback));
+        self.set_listeners();
+    }
+
+    /// Set the error listener and the change listener of the core to all holders.
+    fn set_listeners(&self) {
+        let subscribed = !self.subscribers.read().unwrap().is_empty();
+        let namespaced = self.namespaces.values().flat_map(|holders| holders.values());
+        for holder in self.holders.values().chain(namespaced) {
+            match (&self.watch_error, &self.metrics) {
+                (None, None) => {}
+                (listener, metrics) => {
+                    let (listener, metrics, locale) = (listener.clone(), metrics.clone(), holder.locale.clone());
+                    holder.set_errors(Arc::new(move |e: &Error| {
+                        if let Some(metrics) = &metrics {
+                            metrics.reload(&locale, Some(e));
+                        }
+                        if let Some(listener) = &listener {
+                            listener(e);
+                        }
+                    }));
+                }
+            }
+            let events = subscribed.then(|| events::listener(holder, Arc::clone(&self.subscribers)));
+            match (events, &self.metrics) {
+                (None, None) => {}
+                (events, metrics) => {
+                    let (metrics, locale) = (metrics.clone(), holder.locale.clone());
+                    holder.changes.set(Arc::new(move || {
+                        if let Some(metrics) = &metrics {
+                            metrics.reload(&locale, None);
+                        }
+                        if let Some(events) = &events {
+                            events();
+                        }
+                    }));
+                }
+            }
+        }
+    }
+}
+
+/// Return [Error::MergeConflict] for the first key existing in both `holders` and `other` by the same locale.
+#[cfg(feature = "std")]
+fn check_merge_conflict(holders: &HashMap<String, Holder>, other: &HashMap<String, Holder>) -> Result<(), Error> {
+    for (locale, holder) in other.iter() {
+        if let Some(existing) = holders.get(locale) {
+            existing.load_lazy();
+            holder.load_lazy();
+            if let Some(key) = holder.messages.load().keys().find(|k| existing.messages.contains_key(k)) {
+                return Err(Error::MergeConflict { locale: locale.clone(), key: key.clone() });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Merge `other` into `holders` by [MergePolicy]. [MergePolicy::Error] must be checked before by [check_merge_conflict].
+#[cfg(feature = "std")]
+fn merge_holders(holders: &mut HashMap<String, Holder>, other: HashMap<String, Holder>, policy: MergePolicy) {
+    for (locale, holder) in other.into_iter() {
+        match holders.get(&locale) {
+            None => {
+                holders.insert(locale, holder);
+            }
+            Some(existing) => {
+                existing.load_lazy();
+                holder.load_lazy();
+                let merged = holder.messages.load();
+                existing.messages.update(|messages| {
+                    for (key, value) in merged.iter() {
+                        if policy == MergePolicy::Overwrite || !messages.contains_key(key) {
+                            messages.insert(key.clone(), value.clone());
+                        }
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// Getting data by holder's.
+#[cfg(feature = "std")]
+pub trait GetData {
+    /// Getting locale message by key. If key does not exist, return [Option::None].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("en").unwrap();
+    /// let my_data = en.get("my_data");
+    ///
+    /// match my_data {
+    ///     None => {
+    ///         panic!("No found my_data key.")
+    ///     }
+    ///     Some(k) => {
+    ///         println!("Found key my_data, value: {}", &k)
+    ///     }
+    /// }
+    /// ```
+    fn get<S: AsRef<str>>(&self, key: S) -> Option<String>;
+
+    /// Analogue [GetData::get], but the value is shared with the holder's storage, so it is not copied on hot paths.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// let name = en.get_arc("data.name");
+    /// ```
+    fn get_arc<S: AsRef<str>>(&self, key: S) -> Option<Arc<str>> {
+        self.get(key).map(Arc::from)
+    }
+
+    /// Analogue [GetData::get], which borrows the value if the data is not changed by providers (see [UnWatchData]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale_state("EN").unwrap();
+    /// let name = en.get_cow("data.name");
+    /// ```
+    fn get_cow<S: AsRef<str>>(&self, key: S) -> Option<Cow<'_, str>> {
+        self.get(key).map(Cow::Owned)
+    }
+
+    /// Compiled message by key, see [Template]. The messages of [Data] are compiled on loading, so placeholders are not searched on every call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// // greeting: "Hello, {name}!"
+    /// let greeting = en.get_template("greeting").unwrap();
+    /// assert_eq!(vec!["name"], greeting.placeholders().collect::<Vec<&str>>());
+    /// ```
+    fn get_template<S: AsRef<str>>(&self, key: S) -> Option<Arc<Template>> {
+        self.get_arc(key).map(|v| Arc::new(Template::new(v)))
+    }
+
+    /// Getting locale message by interned key (see [KeyId]), for hot paths. If key does not exist, return [Option::None].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore, KeyId};
+    /// let i18n = InternationalCore::new("locale");
+    /// let name = KeyId::new("data.name");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// let value = en.get_by_id(name);
+    /// ```
+    fn get_by_id(&self, id: KeyId) -> Option<Arc<str>> {
+        self.get_arc(&*id.as_str())
+    }
+
+    /// Call `f` with the value by key, without copying the value or the holder's data. If key does not exist, return [Option::None].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// let len = en.with("data.name", |name| name.len());
+    /// ```
+    fn with<S: AsRef<str>, R, F: FnOnce(&str) -> R>(&self, key: S, f: F) -> Option<R> {
+        self.get_cow(key).map(|v| f(&v))
+    }
+
+    /// Getting locale message by key. If key does not exist, return `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("en").unwrap();
+    /// // If data is not found ref my_data == "my_data"
+    /// // Else you getting data.
+    /// let my_data = en.get_or_default("my_data");
+    /// ```
+    fn get_or_default<S: AsRef<str>>(&self, key: S) -> String;
+
+    /// Getting locale message by key. If key does not exist, return [Error::MissingKey] instead of the key,
+    /// so the caller can fail fast. The handler of the missing keys (see [InternationalCore::on_missing]) is not called.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("en").unwrap();
+    /// let name = en.try_get("data.name")?;
+    /// # Ok::<(), sorrow_i18n::Error>(())
+    /// ```
+    fn try_get<S: AsRef<str>>(&self, key: S) -> Result<String, Error> {
+        self.get(key.as_ref())
+            .ok_or_else(|| Error::MissingKey { locale: self.locale().to_string(), key: key.as_ref().to_string() })
+    }
+
+    /// Getting all keys in holder's
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("en").unwrap();
+    /// let keys = en.keys();
+    /// // Print all key's in en locale holder.
+    /// keys.iter().for_each(|k| println!("{}", k));
+    /// ```
+    fn keys(&self) -> Vec<String>;
+
+    /// Keys starting with `prefix`, sorted, e.g. to build the menu by the section of the catalog.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let core = InternationalCore::builder()
+    ///     .locale("EN", [("data.currency.usd", "Dollar"), ("data.currency.eur", "Euro"), ("data.name", "Test")])
+    ///     .build();
+    /// let en = core.get_by_locale("EN").unwrap();
+    /// assert_eq!(vec!["data.currency.eur", "data.currency.usd"], en.keys_with_prefix("data.currency."));
+    /// ```
+    fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut keys = self.keys().into_iter().filter(|k| k.starts_with(prefix)).collect::<Vec<String>>();
+        keys.sort();
+        keys
+    }
+
+    /// All messages with the keys starting with `prefix`, e.g. all validation messages of the section at once.
+    /// The keys are not cut. [Data] takes the messages of the fallback chain too, from one version of the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let core = InternationalCore::builder()
+    ///     .locale("EN", [("errors.required", "Required"), ("errors.too_long", "Too long"), ("title", "Form")])
+    ///     .build();
+    /// let errors = core.get_by_locale("EN").unwrap().get_all("errors.");
+    /// assert_eq!(2, errors.len());
+    /// assert_eq!("Required", errors["errors.required"]);
+    /// ```
+    fn get_all(&self, prefix: &str) -> HashMap<String, String> {
+        self.keys_with_prefix(prefix).into_iter().filter_map(|k| self.get(&k).map(|v| (k, v))).collect()
+    }
+
+    /// Locale of the holder's.
+    fn locale(&self) -> &str;
+
+    /// Formatting options of the holder's.
+    fn format_options(&self) -> &FormatOptions;
+
+    /// Value returned instead of the missing key: the result of the handler (see [InternationalCore::on_missing]) or the key itself.
+    fn missing(&self, key: &str) -> String {
+        key.to_string()
+    }
+
+    /// Getting locale message by key and replace `{name}` placeholders by arguments. If key does not exist, return `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// // greeting: "Hello, {name}!"
+    /// assert_eq!("Hello, Bob!", en.get_with_args("greeting", &[("name", &"Bob")]));
+    /// ```
+    fn get_with_args<S: AsRef<str>>(&self, key: S, args: &[(&str, &dyn Display)]) -> String {
+        match self.get_template(key.as_ref()) {
+            None => {
+                self.missing(key.as_ref())
+            }
+            Some(template) => {
+                template.render(args, self.format_options())
+            }
+        }
+    }
+
+    /// Analogue [GetData::get_with_args], the arguments are rendered by the locale of the holder's, see [ToLocalizedString].
+    fn get_with_localized_args<S: AsRef<str>>(&self, key: S, args: &[(&str, &dyn ToLocalizedString)]) -> String {
+        let values = args.iter()
+            .map(|(name, value)| (*name, value.to_localized_string(self.locale())))
+            .collect::<Vec<(&str, String)>>();
+        let values = values.iter().map(|(name, value)| (*name, value as &dyn Display)).collect::<Vec<_>>();
+        self.get_with_args(key, &values)
+    }
+
+    /// Analogue [GetData::get_with_args] for embedding in HTML. Interpolated arguments are escaped (see [format::escape_html]), but the message itself is trusted, so markup in the catalog stays intact.
+    /// If key does not exist, return escaped `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// // greeting_html: "<b>Hello</b>, {name}!"
+    /// assert_eq!("<b>Hello</b>, &lt;script&gt;!", en.get_html("greeting_html", &[("name", &"<script>")]));
+    /// ```
+    fn get_html<S: AsRef<str>>(&self, key: S, args: &[(&str, &dyn Display)]) -> String {
+        match self.get_template(key.as_ref()) {
+            None => {
+                escape_html(&self.missing(key.as_ref()))
+            }
+            Some(template) => {
+                let options = FormatOptions { escape: EscapePolicy::Html, ..self.format_options().clone() };
+                template.render(args, &options)
+            }
+        }
+    }
+
+    /// Getting the ordinal form of the message for `n`. For example: `1st`, `2nd`, `3rd`, `4th`.
+    /// The form is selected by the holder's locale (see [plural::ordinal_category]) and the category is added to the key: `key.one`, `key.two`, `key.few`, `key.other`...
+    /// If the form for category does not exist, `key.other` is used. If it does not exist either, return `key`.
+    /// `{n}` in message is replaced by the number.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// // place.one: "{n}st", place.two: "{n}nd", place.few: "{n}rd", place.other: "{n}th"
+    /// assert_eq!("You finished 22nd", format!("You finished {}", en.get_ordinal("place", 22)));
+    /// ```
+    fn get_ordinal<S: AsRef<str>>(&self, key: S, n: u64) -> String {
+        let key = key.as_ref();
+        let category = ordinal_category(self.locale(), n);
+        self.get_template(format!("{}.{}", key, category.as_str()))
+            .or_else(|| self.get_template(format!("{}.{}", key, PluralCategory::Other.as_str())))
+            .map(|t| t.render(&[("n", &n)], self.format_options()))
+            .unwrap_or_else(|| self.missing(key))
+    }
+
+    /// Getting the message parsed to `T`, e.g. the numbers and booleans written without quotes (`max_items: 3`).
+    /// If key does not exist or the message is not parsed, return [Option::None].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// // max_items: 3
+    /// assert_eq!(Some(3), en.get_as::<u32, _>("data.max_items"));
+    /// ```
+    fn get_as<T: FromStr, S: AsRef<str>>(&self, key: S) -> Option<T> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// Getting the list by key. The items of the YAML sequence are stored by index (`key.0`, `key.1`...),
+    /// the items are collected until the first missing index. If `key.0` does not exist, return [Option::None].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// // weekdays: ["Monday", "Tuesday", ...]
+    /// let weekdays = en.get_vec("data.weekdays").unwrap();
+    /// assert_eq!(Some("Tuesday".to_string()), en.get("data.weekdays.1"));
+    /// ```
+    fn get_vec<S: AsRef<str>>(&self, key: S) -> Option<Vec<String>> {
+        let key = key.as_ref();
+        let mut items = vec![self.get(format!("{}.0", key))?];
+        while let Some(item) = self.get(format!("{}.{}", key, items.len())) {
+            items.push(item);
+        }
+        Some(items)
+    }
+
+    /// Getting message by key in the context, analogue of `msgctxt` in gettext. The same word can be translated differently,
+    /// the message in context is stored by the key `key@context` (see [context_key]). If it does not exist, the message of `key` is returned.
+    /// If both do not exist, return [Option::None].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    /// let i18n = InternationalCore::new("locale");
+    /// let de = i18n.get_by_locale("DE").unwrap();
+    /// // Open: "Öffnen", Open@menu: "Öffnen…"
+    /// assert_eq!(Some("Öffnen…".to_string()), de.get_ctx("Open", "menu"));
+    /// assert_eq!(Some("Öffnen".to_string()), de.get_ctx("Open", "door"));
+    /// ```
+    fn get_ctx<S: AsRef<str>, C: AsRef<str>>(&self, key: S, context: C) -> Option<String> {
+        self.get(context_key(key.as_ref(), context.as_ref())).or_else(|| self.get(key))
+    }
+
+    /// Analogue [GetData::get_ctx], if the message does not exist, return `key` (see [InternationalCore::on_missing]).
+    fn get_ctx_or_default<S: AsRef<str>, C: AsRef<str>>(&self, key: S, context: C) -> String {
+        self.get_ctx(key.as_ref(), context).unwrap_or_else(|| self.missing(key.as_ref()))
+    }
+}
+
+/// Separator of the key and the context, see [GetData::get_ctx].
+#[cfg(feature = "std")]
+pub const CONTEXT_SEPARATOR: char = '@';
+
+/// Key of the message in the context: `context_key("Open", "menu")` -> `Open@menu`.
+#[cfg(feature = "std")]
+pub fn context_key(key: &str, context: &str) -> String {
+    format!("{}{}{}", key, CONTEXT_SEPARATOR, context)
+}
+
+/// Works with an ordinary hash map, useful when the data never changes.
+/// It's simple wrapper.
+#[cfg(feature = "std")]
+pub struct UnWatchData {
+    holder: HashMap<String, String>,
+    locale: String,
+    namespace: Option<String>,
+    case_insensitive: bool,
+    format: FormatOptions,
+    missing: Option<MissingHandler>,
+    metrics: Option<Arc<dyn Metrics>>,
+}
+
+#[cfg(feature = "std")]
+impl UnWatchData {
+    /// Creating [UnWatchData] by reference for original data.
+    pub fn new(locale: &str, holder: &HashMap<String, String>) -> Self {
+        UnWatchData {
+            holder: holder.clone(),
+            locale: locale.to_string(),
+            namespace: None,
+            case_insensitive: false,
+            format: FormatOptions::default(),
+            missing: None,
+            metrics: None,
+        }
+    }
+
+    /// Key of the lookup, lowercased if the keys of the core are case-insensitive, see [InternationalCore::case_insensitive_keys].
+    fn lookup_key<'a>(&self, key: &'a str) -> Cow<'a, str> {
+        match self.case_insensitive {
+            true => Cow::Owned(key.to_lowercase()),
+            false => Cow::Borrowed(key),
+        }
+    }
+
+    /// Re-read the snapshot from the core (or its namespace), after the files has been reloaded.
+    /// Returns `false` and keeps the current values, if the locale is no longer in the core.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::{GetData, InternationalCore};
+    ///
+    /// let core = InternationalCore::new("locale/");
+    /// let mut en = core.get_by_locale_state("EN").unwrap();
+    /// // The file has been changed
+    /// core.reload_locale("EN").unwrap();
+    /// en.refresh(&core);
+    /// println!("{}", en.get_or_default("data.title"));
+    /// ```
+    pub fn refresh(&mut self, core: &InternationalCore) -> bool {
+        let fresh = match &self.namespace {
+            None => core.get_by_locale_state(&self.locale),
+            Some(namespace) => core.namespace(namespace).get_by_locale_state(&self.locale),
+        };
+        match fresh {
+            None => false,
+            Some(fresh) => {
+                *self = fresh;
+                true
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl GetData for UnWatchData {
+    fn get<S: AsRef<str>>(&self, key: S) -> Option<String> {
+        self.get_cow(key).map(|r| r.into_owned())
+    }
+
+    fn get_cow<S: AsRef<str>>(&self, key: S) -> Option<Cow<'_, str>> {
+        let value = self.holder.get(self.lookup_key(key.as_ref()).as_ref()).map(|r| Cow::Borrowed(r.as_str()));
+        record_lookup(&self.metrics, &self.locale, value.is_some());
+        value
+    }
+
+    fn get_or_default<S: AsRef<str>>(&self, key: S) -> String {
+        self.get(key.as_ref()).unwrap_or_else(|| self.missing(key.as_ref()))
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.holder.keys().map(|k| k.to_string()).collect::<Vec<String>>()
+    }
+
+    fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let prefix = self.lookup_key(prefix);
+        let mut keys = self.holder.keys().filter(|k| k.starts_with(prefix.as_ref())).cloned().collect::<Vec<String>>();
+        keys.sort();
+        keys
+    }
+
+    fn get_all(&self, prefix: &str) -> HashMap<String, String> {
+        let prefix = self.lookup_key(prefix);
+        self.holder.iter().filter(|(k, _)| k.starts_with(prefix.as_ref())).map(|(k, v)| (k.clone(), v.clone())).collect()
+    }
+
+    fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    fn format_options(&self) -> &FormatOptions {
+        &self.format
+    }
+
+    fn missing(&self, key: &str) -> String {
+        missing_value(&self.missing, &self.locale, key)
+    }
+}
+
+/// We work with a mutable data ref.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct Data {
+    holder: Arc<Messages>,
+    fallbacks: Vec<Arc<Messages>>,
+    locale: String,
+    format: FormatOptions,
+    missing: Option<MissingHandler>,
+    metrics: Option<Arc<dyn Metrics>>,
+}
+
+#[cfg(feature = "std")]
+impl Data {
+    /// Creating [Data] by reference for original data. (mutable)
+    pub fn new(locale: &str, holder: Arc<Messages>) -> Self {
+        Data {
+            holder: Arc::clone(&holder),
+            fallbacks: vec![],
+            locale: locale.to_string(),
+            format: FormatOptions::default(),
+            missing: None,
+            metrics: None,
+        }
+    }
+
+    /// Message with the notes for translators by key, searched by the fallback chain as [GetData::get].
+    /// If key does not exist, return [Option::None].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sorrow_i18n::InternationalCore;
+    /// let i18n = InternationalCore::new("locale");
+    /// let en = i18n.get_by_locale("EN").unwrap();
+    /// // save: {value: "Save", comment: "Button of the settings form"}
+    /// let entry = en.get_entry("data.save").unwrap();
+    /// assert_eq!(Some("Button of the settings form".to_string()), entry.comment);
+    /// ```
+    pub fn get_entry(&self, key: &str) -> Option<Entry> {
+        let entry = self.holder.get_entry(key)
+            .or_else(|| self.fallbacks.iter().find_map(|f| f.get_entry(key)));
+        record_lookup(&self.metrics, &self.locale, entry.is_some());
+        entry
+    }
+
+    /// Map of the holder or of the fallback chain with the key, and the key of the map.
+    fn find(&self, key: &str) -> Option<(Arc<Snapshot>, String)> {
+        std::iter::once(&self.holder).chain(self.fallbacks.iter()).find_map(|h| {
+            let key = h.lookup_key(key);
+            let map = h.load();
+            map.contains_key(key.as_ref()).then(|| (map, key.into_owned()))
+        })
+    }
+
+    /// Keys and values of the holder, as [GetData::keys] the fallback chain is not used.
+    /// The pairs are taken from one version of the data, so they are consistent while the file is reloaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::InternationalCore;
+    /// let core = InternationalCore::builder().insert("EN", "data.name", "Test").build();
+    /// for (key, value) in core.get_by_locale("EN").unwrap().iter() {
+    ///     assert_eq!(("data.name", "Test"), (key.as_str(), &*value));
+    /// }
+    /// ```
+    pub fn iter(&self) -> std::vec::IntoIter<(String, Arc<str>)> {
+        self.holder.load().iter().map(|(k, v)| (k.clone(), Arc::clone(v))).collect::<Vec<(String, Arc<str>)>>().into_iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl GetData for Data {
+    fn get<S: AsRef<str>>(&self, key: S) -> Option<String> {
+        self.get_arc(key).map(|v| v.to_string())
+    }
+
+    fn get_arc<S: AsRef<str>>(&self, key: S) -> Option<Arc<str>> {
+        let value = self.holder.get_arc(key.as_ref())
+            .or_else(|| self.fallbacks.iter().find_map(|f| f.get_arc(key.as_ref())));
+        record_lookup(&self.metrics, &self.locale, value.is_some());
+        value
+    }
+
+    fn get_by_id(&self, id: KeyId) -> Option<Arc<str>> {
+        let value = self.holder.get_by_id(id)
+            .or_else(|| self.fallbacks.iter().find_map(|f| f.get_by_id(id)));
+        record_lookup(&self.metrics, &self.locale, value.is_some());
+        value
+    }
+
+    fn get_template<S: AsRef<str>>(&self, key: S) -> Option<Arc<Template>> {
+        let found = self.find(key.as_ref());
+        record_lookup(&self.metrics, &self.locale, found.is_some());
+        let (map, key) = found?;
+        map.template(&key)
+    }
+
+    fn with<S: AsRef<str>, R, F: FnOnce(&str) -> R>(&self, key: S, f: F) -> Option<R> {
+        let found = self.find(key.as_ref());
+        record_lookup(&self.metrics, &self.locale, found.is_some());
+        let (map, key) = found?;
+        map.get(&key).map(|v| f(v))
+    }
+
+    fn get_or_default<S: AsRef<str>>(&self, key: S) -> String {
+        self.get(key.as_ref()).unwrap_or_else(|| self.missing(key.as_ref()))
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.holder.load().keys().map(|k| k.to_string()).collect::<Vec<String>>()
+    }
+
+    fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let prefix = self.holder.lookup_key(prefix);
+        let mut keys = self.holder.load().keys().filter(|k| k.starts_with(prefix.as_ref())).cloned().collect::<Vec<String>>();
+        keys.sort();
+        keys
+    }
+
+    fn get_all(&self, prefix: &str) -> HashMap<String, String> {
+        // The first holder in the chain overrides the rest.
+        let mut all = HashMap::new();
+        for messages in std::iter::once(&self.holder).chain(self.fallbacks.iter()).rev() {
+            all.extend(messages.get_all(prefix));
+        }
+        all
+    }
+
+    fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    fn format_options(&self) -> &FormatOptions {
+        &self.format
+    }
+
+    fn missing(&self, key: &str) -> String {
+        missing_value(&self.missing, &self.locale, key)
+    }
+}
+
+/// The simplest information keeper.
+/// Contains a link to the data itself that can be dynamically updated.
+/// The locale for determining what this state refers to.
+/// And also, the provider who is responsible for the volatility of the data.
+#[cfg(feature = "std")]
+pub struct Holder {
+    messages: Arc<Messages>,
+    locale: String,
+    description: Option<String>,
+    namespace: Option<String>,
+    path: Option<String>,
+    provider: Arc<Mutex<Box<dyn WatchProvider + Sync + Send>>>,
+    /// Provider from the file structure.
+    provider_kind: Option<Providers>,
+    changes: ChangeNotifier,
+    /// Parent locale from the file structure.
+    extends: Option<String>,
+    lazy: Option<LazySource>,
+}
+
+/// Source file of the holder created by [InternationalCore::lazy], parsed on first access.
+#[cfg(feature = "std")]
+struct LazySource {
+    path: String,
+    /// Description of the loaded file.
+    loaded: OnceLock<Option<String>>,
+    /// Provider of the loaded file.
+    provider_kind: OnceLock<Option<Providers>>,
+    /// Parent locale of the loaded file.
+    extends: OnceLock<Option<String>>,
+    /// Factories of the core, the provider of the file is resolved on loading.
+    factories: ProviderFactories,
+    /// Watcher of the core, the file is watched on loading.
+    #[cfg(feature = "watch")]
+    watcher: FolderWatcher,
+    errors: ErrorReporter,
+}
+
+#[cfg(feature = "std")]
+impl LazySource {
+    /// Watcher of the core for the provider created by the factory.
+    fn shared_watcher(&self) -> SharedWatcher {
+        #[cfg(feature = "watch")]
+        return self.watcher.clone();
+        #[cfg(not(feature = "watch"))]
+        SharedWatcher
+    }
+}
+
+#[cfg(feature = "std")]
+impl Holder {
+    /// Return [Holder]
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - folder by localization's.
+    ///
+    /// # Examples
+    /// ```
+    /// use sorrow_i18n::Holder;
+    /// let holder = Holder::new("my_locale_folder");
+    /// ```
+    pub fn new<S: Into<String>>(path: S) -> Result<Holder, Error> {
+        load_struct(path)
+    }
+
+    /// Dry run of [Holder::new]: the file is read and parsed (extension, YAML, kind, included files),
+    /// but the holder and the watcher are not created. Useful for pre-deploy checks, see [InternationalCore::check].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use sorrow_i18n::Holder;
+    /// if let Err(e) = Holder::check("locale/I18N_EN.yaml") {
+    ///     eprintln!("Invalid locale file: {}", e);
+    /// }
+    /// ```
+    pub fn check<S: Into<String>>(path: S) -> Result<(), Error> {
+        check_file(path).map(|_| ())
+    }
+
+    /// Return [Holder] by locale and messages, without file. Keys are used as is, the data is not watched.
+    /// The holder can be added to the core by [InternationalCore::add_locale], see also [Holder::from_pairs].
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use sorrow_i18n::{GetData, Holder, InternationalCore};
+    /// let holder = Holder::from_map("EN", HashMap::from([("data.name".to_string(), "Test".to_string())]));
+    /// assert_eq!("en", holder.locale());
+    ///
+    /// let mut core = InternationalCore::builder().build();
+    /// core.add_locale("EN", holder).unwrap();
+    /// assert_eq!("Test", core.get_by_locale("EN").unwrap().get_or_default("data.name"));
+    /// ```
+    pub fn from_map(locale: &str, messages: HashMap<String, String>) -> Holder {
+        Holder {
+            messages: Arc::new(Messages::new(messages)),
+            locale: normalize(locale),
+            description: None,
+            namespace: None,
+            path: None,
+            provider: Arc::new(Mutex::new(Box::new(StaticFileProvider {}))),
+            provider_kind: None,
+            changes: ChangeNotifier::default(),
+            extends: None,
+            lazy: None,
+        }
+    }
+
+    /// Empty holder of the file, see [InternationalCore::lazy].
+    fn lazy(locale: &str, path: String) -> Holder {
+        let mut holder = Holder::from_map(locale, HashMap::new());
+        holder.path = Some(path.clone());
+        holder.lazy = Some(LazySource { path, loaded: OnceLock::new(), provider_kind: OnceLock::new(),
+            extends: OnceLock::new(),
+            factories: ProviderFactories::default(),
+            #[cfg(feature = "watch")]
+            watcher: FolderWatcher::default(),
+            errors: ErrorReporter::default(),
+        });
+        holder
+    }
+
+    /// Parse the source file of the lazy holder once, then watch it by the provider of the file.
+    fn load_lazy(&self) {
+        if let Some(lazy) = &self.lazy {
+            lazy.loaded.get_or_init(|| {
+                match self.load_source(lazy) {
+                    Ok(description) => description,
+                    Err(e) => {
+                        lazy.errors.report(e);
+                        None
+                    }
+                }
+            });
+        }
+    }
+
+    fn load_source(&self, lazy: &LazySource) -> Result<Option<String>, Error> {
+        log::debug!("Loading {} locale from {}.", &self.locale, &lazy.path);
+        let loaded = load_struct(lazy.path.as_str())?;
+        if loaded.namespace.is_some() {
+            log::warn!("Namespace of {} is ignored, the file is loaded lazily.", &lazy.path);
+        }
+        self.messages.store_loaded(&loaded.messages);
+        let stats = loaded.messages.stats();
+        self.messages.record(|current| *current = stats);
+        // The provider is moved, so it is not stopped when the loaded holder is dropped.
+        let provider = std::mem::replace(&mut *loaded.provider.lock().unwrap(), Box::new(StaticFileProvider {}));
+        let mut current = self.provider.lock().unwrap();
+        *current = provider;
+        if let Some(listener) = lazy.errors.listener() {
+            current.set_error_listener(listener);
+        }
+        current.set_listener(self.changes.as_listener());
+        current.set_data(Arc::clone(&self.messages))?;
+        #[cfg(feature = "watch")]
+        current.set_folder_watcher(lazy.watcher.clone());
+        current.watch()?;
+        drop(current);
+        self.resolve_provider(&lazy.factories, &lazy.shared_watcher(), loaded.provider_kind.as_ref())?;
+        self.changes.notify();
+        let _ = lazy.provider_kind.set(loaded.provider_kind.clone());
+        let _ = lazy.extends.set(loaded.extends.clone());
+        Ok(loaded.description.clone())
+    }
+
+    /// Provider from the file structure, `None` if the lazy holder is not loaded yet.
+    fn provider_kind(&self) -> Option<Providers> {
+        match &self.lazy {
+            None => self.provider_kind.clone(),
+            Some(lazy) => lazy.provider_kind.get().cloned().flatten(),
+        }
+    }
+
+    /// Create the provider by the factory registered by the name of the file's provider, see [InternationalCore::register_provider_factory].
+    fn resolve_provider(&self, factories: &ProviderFactories, watcher: &SharedWatcher, kind: Option<&Providers>) -> Result<(), Error> {
+        let name = match kind {
+            Some(Providers::Custom(name)) => name,
+            _ => return Ok(()),
+        };
+        let factory = factories.read().unwrap().get(name).cloned();
+        match factory {
+            None => {
+                log::debug!("Provider {} of {} locale is not registered, the file is not watched.", name, &self.locale);
+                Ok(())
+            }
+            Some(factory) => self.install_provider(&factory, watcher),
+        }
+    }
 
-    /// Getting locale message by key. If key does not exist, return `key`.
+    /// Replace the provider by the provider of the factory and start it. The files are watched by the shared `watcher` of the core.
+    fn install_provider(&self, factory: &ProviderFactory, watcher: &SharedWatcher) -> Result<(), Error> {
+        #[cfg_attr(not(feature = "watch"), allow(unused_mut))]
+        let mut provider = factory(&self.locale, self.path.as_deref())?;
+        #[cfg(feature = "watch")]
+        provider.set_folder_watcher(watcher.clone());
+        #[cfg(not(feature = "watch"))]
+        let _ = watcher;
+        let mut current = self.provider.lock().unwrap();
+        if let Err(e) = current.unwatch() {
+            log::error!("Error while stopping the replaced provider of {}. Message: {}", &self.locale, e);
+        }
+        *current = provider;
+        if let Some(listener) = self.lazy.as_ref().and_then(|lazy| lazy.errors.listener()) {
+            current.set_error_listener(listener);
+        }
+        current.set_listener(self.changes.as_listener());
+        current.set_data(Arc::clone(&self.messages))?;
+        current.watch()
+    }
+
+    /// Setter for the listener of the errors of the provider and of the lazy loading.
+    fn set_errors(&self, listener: ErrorListener) {
+        if let Some(lazy) = &self.lazy {
+            lazy.errors.set(Arc::clone(&listener));
+        }
+        self.provider.lock().unwrap().set_error_listener(listener);
+    }
+
+    /// Return [Holder] by locale and (key, value) pairs, see [Holder::from_map].
     ///
     /// # Examples
-    ///
     /// ```
-    /// use sorrow_i18n::{GetData, InternationalCore};
-    /// let i18n = InternationalCore::new("locale");
-    /// let en = i18n.get_by_locale("en").unwrap();
-    /// // If data is not found ref my_data == "my_data"
-    /// // Else you getting data.
-    /// let my_data = en.get_or_default("my_data");
+    /// use sorrow_i18n::Holder;
+    /// let holder = Holder::from_pairs("EN", [("data.name", "Test")]);
     /// ```
-    fn get_or_default<S: AsRef<str>>(&self, key: S) -> String;
+    pub fn from_pairs<K: Into<String>, V: Into<String>, I: IntoIterator<Item = (K, V)>>(locale: &str, pairs: I) -> Holder {
+        Holder::from_map(locale, pairs.into_iter().map(|(k, v)| (k.into(), v.into())).collect())
+    }
 
-    /// Getting all keys in holder's
+    /// Locale of the holder.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Description from the file structure, if it is specified.
+    pub fn description(&self) -> Option<&str> {
+        match &self.lazy {
+            None => self.description.as_deref(),
+            Some(lazy) => lazy.loaded.get()?.as_deref(),
+        }
+    }
+
+    /// Parent locale from the file structure (normalized), if it is specified. Keys missing in the holder are looked up in the holder
+    /// of the parent locale (and in its parent) before the language holder and the fallback chain, see [InternationalCore::get_by_locale].
+    /// The parent is resolved in the same namespace. If the holder is lazy and not loaded yet, `None`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sorrow_i18n::{GetData, InternationalCore};
-    /// let i18n = InternationalCore::new("locale");
-    /// let en = i18n.get_by_locale("en").unwrap();
-    /// let keys = en.keys();
-    /// // Print all key's in en locale holder.
-    /// keys.iter().for_each(|k| println!("{}", k));
+    /// use sorrow_i18n::{GetData, Holder, InternationalCore};
+    /// let en = Holder::parse("kind: I18N\nlocale: en-GB\ndata:\n  colour: Colour\n  name: Test\n").unwrap();
+    /// let au = Holder::parse("kind: I18N\nlocale: en-AU\nextends: en_GB\ndata:\n  name: G'day\n").unwrap();
+    /// assert_eq!(Some("en-GB"), au.extends());
+    ///
+    /// let core = InternationalCore::from_holders(vec![en, au]).unwrap();
+    /// let au = core.get_by_locale("en-AU").unwrap();
+    /// assert_eq!("G'day", au.get_or_default("data.name"));
+    /// assert_eq!("Colour", au.get_or_default("data.colour"));
     /// ```
-    fn keys(&self) -> Vec<String>;
-}
+    pub fn extends(&self) -> Option<&str> {
+        match &self.lazy {
+            None => self.extends.as_deref(),
+            Some(lazy) => lazy.extends.get()?.as_deref(),
+        }
+    }
 
-/// Works with an ordinary hash map, useful when the data never changes.
-/// It's simple wrapper.
-pub struct UnWatchData {
-    holder: HashMap<String, String>,
-}
+    /// Namespace from the file structure, if it is specified.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
 
-impl UnWatchData {
-    /// Creating [UnWatchData] by reference for original data.
-    pub fn new(holder: &HashMap<String, String>) -> Self {
-        UnWatchData {
-            holder: holder.clone()
-        }
+    /// Messages of the holder. Changes are visible to all [Data] of the holder.
+    pub fn messages(&self) -> Arc<Messages> {
+        self.load_lazy();
+        Arc::clone(&self.messages)
     }
-}
 
-impl GetData for UnWatchData {
-    fn get<S: AsRef<str>>(&self, key: S) -> Option<String> {
-        return self.holder.get(key.as_ref()).map(|r| r.to_string());
+    /// Message with the notes for translators by key, see [messages::Metadata].
+    pub fn get_entry(&self, key: &str) -> Option<Entry> {
+        self.load_lazy();
+        self.messages.get_entry(key)
     }
 
-    fn get_or_default<S: AsRef<str>>(&self, key: S) -> String {
-        return self.get(key.as_ref()).unwrap_or_else(|| key.as_ref().to_string());
+    /// [FileStructure] of the holder, so the catalog can be written back to the file (see [Holder::write_yaml]).
+    /// Keys are nested by `.`, a part of the key conflicting with the message is kept as is, so the file is loaded to the same keys.
+    ///
+    /// # Examples
+    /// ```
+    /// use sorrow_i18n::Holder;
+    /// let holder = Holder::from_pairs("EN", [("data.name", "Test"), ("data.name.short", "T")]);
+    /// let yaml = holder.to_yaml().unwrap();
+    /// assert_eq!("kind: I18N\nlocale: en\ndata:\n  name: Test\n  name.short: T\n", yaml.trim_start_matches("---\n"));
+    /// ```
+    pub fn to_file_structure(&self) -> FileStructure {
+        self.load_lazy();
+        let provider = self.provider_kind();
+        let data = self.messages.load();
+        let metadata = self.messages.metadata();
+        let mut keys = data.keys().collect::<Vec<&String>>();
+        keys.sort();
+        let mut root = Mapping::new();
+        for key in keys {
+            // Messages with notes are written as `{value, comment, context, max_len}`.
+            let value = match metadata.get(key) {
+                None => Value::String(data[key].to_string()),
+                Some(notes) => {
+                    let mut entry = Mapping::new();
+                    entry.insert(Value::String("value".to_string()), Value::String(data[key].to_string()));
+                    for (name, note) in [("comment", &notes.comment), ("context", &notes.context)] {
+                        if let Some(note) = note {
+                            entry.insert(Value::String(name.to_string()), Value::String(note.clone()));
+                        }
+                    }
+                    if let Some(max_len) = notes.max_len {
+                        entry.insert(Value::String("max_len".to_string()), Value::Number(max_len.into()));
+                    }
+                    Value::Mapping(entry)
+                }
+            };
+            insert_nested(&mut root, &key.split('.').collect::<Vec<&str>>(), value);
+        }
+        FileStructure {
+            kind: "I18N".to_string(),
+            locale: self.locale.clone(),
+            description: self.description().map(|d| d.to_string()),
+            namespace: self.namespace.clone(),
+            provider,
+            extends: self.extends().map(|e| e.to_string()),
+            // The included keys are written as the keys of the file.
+            includes: vec![],
+            data: if root.is_empty() { None } else { Some(to_sequences(Value::Mapping(root))) },
+        }
     }
 
-    fn keys(&self) -> Vec<String> {
-        self.holder.keys().map(|k| k.to_string()).collect::<Vec<String>>()
+    /// Serialize [Holder::to_file_structure] to YAML.
+    pub fn to_yaml(&self) -> Result<String, Error> {
+        let path = self.path.clone().unwrap_or_default();
+        serde_yaml::to_string(&self.to_file_structure()).map_err(|e| Error::InvalidStructure { path, cause: e })
     }
-}
 
-/// We work with a mutable data ref.
-pub struct Data {
-    holder: Arc<RwLock<HashMap<String, String>>>,
-}
+    /// Write the holder to the YAML file, which can be loaded by [Holder::new].
+    pub fn write_yaml<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let yaml = self.to_yaml()?;
+        std::fs::write(path.as_ref(), yaml).map_err(|e| Error::IoError { path: path.as_ref().display().to_string(), cause: e })
+    }
 
-impl Data {
-    /// Creating [Data] by reference for original data. (mutable)
-    pub fn new(holder: Arc<RwLock<HashMap<String, String>>>) -> Self {
-        Data {
-            holder: Arc::clone(&holder)
-        }
+    /// Serialize [Holder::to_file_structure] to JSON, which can be loaded by [Holder::parse].
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(&self.to_file_structure())
+            .map_err(|e| Error::ExportError { format: BundleFormat::Json, cause: e.to_string() })
     }
-}
 
-impl GetData for Data {
-    fn get<S: AsRef<str>>(&self, key: S) -> Option<String> {
-        let state = self.holder.read().unwrap();
-        return state.get(key.as_ref()).map(|r| r.to_string());
+    /// Return [Holder] by the text of the file structure, without file. JSON is accepted too, as it is valid YAML.
+    /// The provider of the structure is kept for [Holder::to_file_structure], but the data is not watched.
+    ///
+    /// # Examples
+    /// ```
+    /// use sorrow_i18n::Holder;
+    /// let holder = Holder::parse(r#"{"kind": "I18N", "locale": "EN", "data": {"name": "Test"}}"#).unwrap();
+    /// assert_eq!(Some("Test".to_string()), holder.messages().get("data.name"));
+    /// assert_eq!("kind: I18N\nlocale: en\ndata:\n  name: Test\n", holder.to_yaml().unwrap().trim_start_matches("---\n"));
+    /// ```
+    pub fn parse(data: &str) -> Result<Holder, Error> {
+        let mut holder = load_struct_from_str(data, None)?;
+        holder.provider = Arc::new(Mutex::new(Box::new(StaticFileProvider {})));
+        Ok(holder)
     }
 
-    fn get_or_default<S: AsRef<str>>(&self, key: S) -> String {
-        return match self.holder.read().unwrap().get(key.as_ref()) {
-            None => {
-                key.as_ref().to_string().clone()
-            }
-            Some(v) => {
-                v.clone()
-            }
+    /// Holder with the keys of `over` overriding the keys of `base`, both providers keep refreshing their layers.
+    fn layered(base: Holder, over: Holder) -> Result<Holder, Error> {
+        base.load_lazy();
+        let mut merged = base.messages.to_map();
+        merged.extend(over.messages.to_map());
+        let messages = Arc::new(Messages::new(merged));
+        let mut metadata = HashMap::clone(&base.messages.metadata());
+        metadata.extend(over.messages.metadata().iter().map(|(k, v)| (k.clone(), v.clone())));
+        messages.store_metadata(metadata);
+        // The merged map is the initial data only, the reloads rebuild the data from the layers.
+        let mut provider = CompositeProvider::new().without_base();
+        for layer in [&base, &over] {
+            // The provider is moved, so it is not stopped when the layer is dropped.
+            let current = std::mem::replace(&mut *layer.provider.lock().unwrap(), Box::new(StaticFileProvider {}));
+            provider = provider.layer_with_data(current, Arc::clone(&layer.messages));
+        }
+        provider.set_data(Arc::clone(&messages))?;
+        let mut holder = Holder {
+            messages,
+            locale: base.locale.clone(),
+            description: over.description.clone().or_else(|| base.description.clone()),
+            namespace: base.namespace.clone(),
+            // Several files, reloading one of them loses the keys of the others.
+            path: None,
+            provider: Arc::new(Mutex::new(Box::new(provider))),
+            provider_kind: None,
+            changes: ChangeNotifier::default(),
+            extends: over.extends.clone().or_else(|| base.extends.clone()),
+            lazy: None,
         };
+        holder.watch()?;
+        Ok(holder)
     }
 
-    fn keys(&self) -> Vec<String> {
-        self.holder.read().unwrap().keys().map(|k| k.to_string()).collect::<Vec<String>>()
+    /// Used by [include_i18n!], the path is kept only for the error message.
+    #[doc(hidden)]
+    pub fn from_included(path: &str, data: &str) -> Holder {
+        let mut holder = load_struct_from_str(data, Some(path.to_string())).unwrap_or_else(|e| panic!("Error while loading file {}. {:?}", path, e));
+        // Included files can not be re-read.
+        holder.path = None;
+        holder.provider = Arc::new(Mutex::new(Box::new(StaticFileProvider {})));
+        holder
     }
-}
-
-/// The simplest information keeper.
-/// Contains a link to the data itself that can be dynamically updated.
-/// The locale for determining what this state refers to.
-/// And also, the provider who is responsible for the volatility of the data.
-pub struct Holder {
-    messages: Arc<RwLock<HashMap<String, String>>>,
-    locale: String,
-    provider: Arc<Mutex<Box<dyn WatchProvider + Sync + Send>>>,
-}
 
-impl Holder {
-    /// Return [Holder]
-    ///
-    /// # Arguments
-    ///
-    /// * `path` - folder by localization's.
+    /// Check the catalog: empty messages, leading or trailing whitespace, invalid placeholders (see [format::Template])
+    /// and the kind of the source file, which may have been changed after loading. All problems are returned, sorted by key.
     ///
     /// # Examples
     /// ```
+    /// use sorrow_i18n::check::DiagnosticKind;
     /// use sorrow_i18n::Holder;
-    /// let holder = Holder::new("my_locale_folder");
+    /// let holder = Holder::from_pairs("EN", [("data.name", "Test "), ("data.greeting", "Hello, {name!"), ("data.title", "")]);
+    /// let kinds = holder.validate().into_iter().map(|d| d.kind).collect::<Vec<DiagnosticKind>>();
+    /// assert_eq!(vec![
+    ///     DiagnosticKind::InvalidPlaceholder { position: 7 },
+    ///     DiagnosticKind::SuspiciousWhitespace,
+    ///     DiagnosticKind::EmptyValue,
+    /// ], kinds);
     /// ```
-    pub fn new<S: Into<String>>(path: S) -> Result<Holder, Error> {
-        load_struct(path)
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        check::validate(self, &check::limits([self]))
+    }
+
+    /// Re-read the source file on demand, e.g. if the file is not watched. The file is validated by [Holder::new],
+    /// and the data is swapped atomically, so readers see either the old or the new messages.
+    /// If the holder has no source file (embedded, built in code), nothing happens. On error the previous data is kept.
+    /// See [InternationalCore::reload_locale] to reload the holder of the core.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use sorrow_i18n::Holder;
+    /// let holder = Holder::new("locale/I18N_EN.yaml").unwrap();
+    /// // The file is changed
+    /// holder.reload().unwrap();
+    /// ```
+    pub fn reload(&self) -> Result<(), Error> {
+        self.load_lazy();
+        match &self.path {
+            None => {
+                Ok(())
+            }
+            Some(path) => {
+                reload_messages(&self.messages, path)?;
+                self.changes.notify();
+                Ok(())
+            }
+        }
     }
 }
 
+#[cfg(feature = "std")]
 impl WatchProvider for Holder {
     fn watch(&mut self) -> Result<(), Error> {
-        self.provider.lock().unwrap().watch()
+        let mut provider = self.provider.lock().unwrap();
+        provider.set_listener(self.changes.as_listener());
+        provider.watch()
     }
 
-    fn set_data(&mut self, data: Arc<RwLock<HashMap<String, String>>>) -> Result<(), Error> {
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
         self.messages = data;
         Ok(())
     }
+
+    fn set_listener(&mut self, listener: ChangeListener) {
+        self.changes.set(listener)
+    }
+
+    fn set_error_listener(&mut self, listener: ErrorListener) {
+        self.set_errors(listener)
+    }
+
+    #[cfg(feature = "watch")]
+    fn set_folder_watcher(&mut self, watcher: FolderWatcher) {
+        if let Some(lazy) = &mut self.lazy {
+            lazy.watcher = watcher.clone();
+        }
+        self.provider.lock().unwrap().set_folder_watcher(watcher)
+    }
+
+    fn unwatch(&mut self) -> Result<(), Error> {
+        self.provider.lock().unwrap().unwatch()
+    }
 }
 
+#[cfg(feature = "std")]
+impl Drop for Holder {
+    fn drop(&mut self) {
+        if let Ok(mut provider) = self.provider.lock() {
+            if let Err(e) = provider.unwatch() {
+                log::error!("Error while stopping the provider of {}. Message: {}", &self.locale, e);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 enum FileData {
     Map(HashMap<String, FileData>),
     String(String),
+    /// Message with the notes for translators, `{value, comment, context, max_len}`.
+    Entry(String, Metadata),
 }
 
 /// Default structure by file localization.
@@ -614,6 +3331,18 @@ enum FileData {
 /// data:
 ///   name: "Helly belly"
 /// ```
+///
+/// Message with the notes for translators (see [messages::Metadata]):
+///
+/// ```yaml
+/// data:
+///   save:
+///     value: "Save"
+///     comment: "Button of the settings form"
+///     context: "verb"
+///     max_len: 12
+/// ```
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FileStructure {
     /// Kind - I18N. It is necessary to understand if the file does not belong to the localization category.
@@ -623,85 +3352,295 @@ pub struct FileStructure {
     locale: String,
 
     /// Description - for user, optional parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<String>,
 
+    /// Namespace - optional parameter, the keys of the file are available only through [InternationalCore::namespace].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    namespace: Option<String>,
+
     /// Provider - optional parameter, if is None, [StaticFileProvider]. For additional information see [Providers].
+    #[serde(skip_serializing_if = "Option::is_none")]
     provider: Option<Providers>,
 
+    /// Extends - optional parameter, the parent locale. Keys missing in the file are looked up in the parent holder, see [Holder::extends].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extends: Option<String>,
+
+    /// Includes - optional parameter, files with the keys shared by the locales (`kind: I18N_INCLUDE`), paths are relative to the file.
+    /// Keys of the later files override the keys of the earlier ones, the keys of the file itself override all of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    includes: Vec<String>,
+
     /// Data - localization information. Format key-value, optional.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    data: Option<Value>,
+}
+
+/// Kind of the files included by the locale files, see [FileStructure].
+#[cfg(feature = "std")]
+const INCLUDE_KIND: &str = "I18N_INCLUDE";
+
+/// Shared keys included by the locale files, see [FileStructure].
+///
+/// ```yaml
+/// kind: I18N_INCLUDE
+/// data:
+///   brand: "Sorrow"
+/// ```
+#[cfg(feature = "std")]
+#[derive(serde::Deserialize)]
+struct IncludeStructure {
+    kind: String,
     #[serde(flatten)]
     data: Option<Value>,
 }
 
+/// Reader of the included files by path.
+#[cfg(feature = "std")]
+type IncludeReader<'a> = &'a dyn Fn(&str) -> Result<String, Error>;
+
+/// Parsed file: the structure without the data, the flatten messages and their notes, see [parse_struct].
+#[cfg(feature = "std")]
+type ParsedFile = (FileStructure, HashMap<String, String>, HashMap<String, Metadata>);
+
 /// Loading [FileStructure], and creating [Holder].
 /// If structure is invalid [Error::InvalidStructure]
 /// If structure is valid, but kind is not valid, return: [Error::InvalidHeader]
+/// If the file is included by other files, return: [Error::IncludeFile]
 /// Path - optional if use static provider with [incl_dir] `features`.
+#[cfg(feature = "std")]
 fn load_struct_from_str(data: &str, path: Option<String>) -> Result<Holder, Error> {
-    let messages = Arc::new(RwLock::new(HashMap::new()));
-    let path = path.unwrap_or_default();
-    let structure: FileStructure = serde_yaml::from_str(data).map_err(|e| Error::InvalidStructure { path: path.clone(), cause: e.to_string() })?;
-
-    if structure.kind.ne("I18N") {
-        log::error!("Invalid header for file: {}. Expected: I18N.", &path);
-        return Err(Error::InvalidHeader { path: path.clone() });
-    };
-
-    log::trace!("Loading structure by path: {}.\nDescription: {:?}\nLocale: {}", &path, &structure.description, &structure.locale);
-
-    let locale = structure.locale.clone();
-
-    match structure.data {
-        None => {
-            log::warn!("Empty data for {} locale. File path: {}.", &structure.locale, &*path);
-        }
-        Some(kv) => {
-            messages
-                .write()
-                .and_then(|mut m| {
-                    m.extend(to_flatten(String::default(), FileData::from(kv)));
-                    Ok(())
-                }).unwrap();
-        }
-    };
+    load_struct_with_includes(data, path, &read_file)
+}
 
-    return match structure.provider {
+/// Analogue [load_struct_from_str], the included files are read by `read`.
+#[cfg(feature = "std")]
+fn load_struct_with_includes(data: &str, path: Option<String>, read: IncludeReader) -> Result<Holder, Error> {
+    let source = path.clone();
+    let path = path.unwrap_or_default();
+    let (structure, map, metadata) = parse_struct(data, &path, read)?;
+    let locale = normalize(&structure.locale);
+    let description = structure.description.clone();
+    let extends = structure.extends.as_deref().map(normalize);
+    let namespace = structure.namespace.clone();
+    let messages = Arc::new(Messages::new(map));
+    messages.store_metadata(metadata);
+
+    match structure.provider {
         None => {
             // Unwatch if provider is not exists
             Ok(Holder {
                 messages,
                 locale,
+                description,
+                namespace,
+                path: source,
                 provider: Arc::new(Mutex::new(Box::new(StaticFileProvider {}))),
+                provider_kind: None,
+                changes: ChangeNotifier::default(),
+                extends,
+                lazy: None,
             })
         }
         Some(p) => {
-            match p {
+            match p.clone() {
+                #[cfg(feature = "watch")]
                 Providers::FileProvider => {
                     let provider = FileProvider::new(Arc::clone(&messages), path.clone());
                     Ok(Holder {
                         messages,
                         locale,
+                        description,
+                        namespace,
+                        path: source,
                         provider: Arc::new(Mutex::new(Box::new(provider))),
+                        provider_kind: Some(p),
+                        changes: ChangeNotifier::default(),
+                        extends,
+                        lazy: None,
                     })
                 }
-                Providers::StaticFileProvider => {
+                #[cfg(not(feature = "watch"))]
+                Providers::FileProvider => {
+                    log::warn!("File {} is not watched, feature `watch` is disabled.", &path);
+                    Ok(Holder {
+                        messages,
+                        locale,
+                        description,
+                        namespace,
+                        path: source,
+                        provider: Arc::new(Mutex::new(Box::new(StaticFileProvider {}))),
+                        provider_kind: Some(p),
+                        changes: ChangeNotifier::default(),
+                        extends,
+                        lazy: None,
+                    })
+                }
+                Providers::StaticFileProvider | Providers::Custom(_) => {
                     Ok(Holder {
                         messages,
                         locale,
+                        description,
+                        namespace,
+                        path: source,
                         provider: Arc::new(Mutex::new(Box::new(StaticFileProvider {}))),
+                        provider_kind: Some(p),
+                        changes: ChangeNotifier::default(),
+                        extends,
+                        lazy: None,
                     })
                 }
             }
         }
+    }
+}
+
+/// Parse the file and its included files, without creating the holder. The data of the returned structure is taken.
+#[cfg(feature = "std")]
+fn parse_struct(data: &str, path: &str, read: IncludeReader) -> Result<ParsedFile, Error> {
+    let mut structure: FileStructure = match serde_yaml::from_str(data) {
+        Ok(structure) => structure,
+        Err(_) if is_include(data) => return Err(Error::IncludeFile { path: path.to_string() }),
+        Err(e) => return Err(Error::InvalidStructure { path: path.to_string(), cause: e }),
+    };
+
+    if structure.kind == INCLUDE_KIND {
+        return Err(Error::IncludeFile { path: path.to_string() });
+    }
+    if structure.kind.ne("I18N") {
+        log::error!("Invalid header for file: {}. Expected: I18N.", &path);
+        return Err(Error::InvalidHeader { path: path.to_string() });
+    };
+
+    log::trace!("Loading structure by path: {}.\nDescription: {:?}\nLocale: {}", &path, &structure.description, &structure.locale);
+
+    let mut map = HashMap::new();
+    let mut metadata = HashMap::new();
+    for include in structure.includes.iter() {
+        let include = include_path(path, include);
+        map.extend(load_include(&read(&include)?, &include, &mut metadata)?);
+    }
+    match structure.data.take() {
+        None if !structure.includes.is_empty() => {}
+        None => {
+            log::warn!("Empty data for {} locale. File path: {}.", &structure.locale, path);
+        }
+        Some(kv) => {
+            map.extend(to_flatten(String::default(), FileData::from(kv), &mut metadata));
+        }
     };
+    Ok((structure, map, metadata))
+}
+
+/// Load the files on scoped threads and watch them by `watch`. The results are in order of `paths`.
+#[cfg(feature = "std")]
+fn load_files(paths: &[String], watch: &(dyn Fn(&mut Holder) -> Result<(), Error> + Sync)) -> Vec<Result<Holder, Error>> {
+    let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let chunk = paths.len().div_ceil(threads).max(1);
+    std::thread::scope(|scope| {
+        let handles = paths.chunks(chunk)
+            .map(|chunk| scope.spawn(move || {
+                chunk.iter()
+                    .map(|path| Holder::new(path.as_str()).and_then(|mut holder| watch(&mut holder).map(|_| holder)))
+                    .collect::<Vec<Result<Holder, Error>>>()
+            }))
+            .collect::<Vec<_>>();
+        handles.into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+            .collect()
+    })
+}
+
+/// Entries of the folder ordered by path, `true` for subfolders.
+#[cfg(feature = "std")]
+fn read_folder(folder: &str) -> Result<Vec<(String, bool)>, Error> {
+    let dir = std::fs::read_dir(folder)
+        .map_err(|e| {
+            log::error!("{}", &e);
+            Error::IoError { path: folder.to_string(), cause: e }
+        })?;
+    let mut paths = vec![];
+    for path in dir {
+        let path = path.map_err(|e| Error::IoError { path: folder.to_string(), cause: e })?.path();
+        let full_path = path.to_str()
+            .ok_or_else(|| Error::IoError {
+                path: path.to_string_lossy().to_string(),
+                cause: std::io::Error::new(std::io::ErrorKind::InvalidData, "path is not valid unicode."),
+            })?
+            .to_string();
+        paths.push((full_path, path.is_dir()));
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Check the kind of the file is [INCLUDE_KIND].
+#[cfg(feature = "std")]
+fn is_include(data: &str) -> bool {
+    serde_yaml::from_str::<IncludeStructure>(data).is_ok_and(|structure| structure.kind == INCLUDE_KIND)
+}
+
+/// Path of the included file relative to the folder of the file.
+#[cfg(feature = "std")]
+fn include_path(path: &str, include: &str) -> String {
+    match Path::new(path).parent() {
+        Some(folder) => folder.join(include).to_string_lossy().to_string(),
+        None => include.to_string(),
+    }
+}
+
+/// Keys of the included file. The included files can not include other files.
+#[cfg(feature = "std")]
+fn load_include(data: &str, path: &str, metadata: &mut HashMap<String, Metadata>) -> Result<HashMap<String, String>, Error> {
+    let structure: IncludeStructure = serde_yaml::from_str(data).map_err(|e| Error::InvalidStructure { path: path.to_string(), cause: e })?;
+    if structure.kind != INCLUDE_KIND {
+        log::error!("Invalid header for included file: {}. Expected: {}.", path, INCLUDE_KIND);
+        return Err(Error::InvalidHeader { path: path.to_string() });
+    }
+    Ok(structure.data.map(|kv| to_flatten(String::default(), FileData::from(kv), metadata)).unwrap_or_default())
+}
+
+/// Read the file to string.
+#[cfg(feature = "std")]
+fn read_file(path: &str) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|e| {
+        log::error!("Error while reading file {}. Additional information: {}", path, &e);
+        Error::IoError { path: path.to_string(), cause: e }
+    })
 }
 
 /// Load file ant trigger loading [FileStructure] by [load_struct_from_str()]
 /// If file extension is not .yaml or .yml, the error is hit [Error::NotSupportedFileExtension]
 /// Another error, if IO operation has been failed. [Error::IoError]
+#[cfg(feature = "std")]
 fn load_struct<S: Into<String>>(path: S) -> Result<Holder, Error> {
-    let mut data = String::new();
     let path = path.into().trim_end().to_string();
+    #[cfg(feature = "tracing")]
+    let _span = tracing::info_span!("i18n.load", path = %path).entered();
+    let start = Instant::now();
+    let holder = read_locale_file(&path).and_then(|data| load_struct_from_str(&data, Some(path)));
+    if let Ok(holder) = &holder {
+        holder.messages.record(|stats| stats.loaded(start.elapsed()));
+    }
+    #[cfg(feature = "tracing")]
+    trace_result(&holder);
+    holder
+}
+
+/// Read and parse the file by `path` without creating the holder, see [Holder::check]. Returns the structure of the file.
+#[cfg(feature = "std")]
+fn check_file<S: Into<String>>(path: S) -> Result<FileStructure, Error> {
+    let path = path.into().trim_end().to_string();
+    let data = read_locale_file(&path)?;
+    parse_struct(&data, &path, &read_file).map(|(structure, _, _)| structure)
+}
+
+/// Read the locale file by `path`. If the extension is not .yaml/.yml, return [Error::NotSupportedFileExtension].
+#[cfg(feature = "std")]
+fn read_locale_file(path: &str) -> Result<String, Error> {
+    let mut data = String::new();
+    let path = path.to_string();
 
     if !path.ends_with(".yaml") && !path.ends_with(".yml") {
         return Err(Error::NotSupportedFileExtension { path: path.clone() });
@@ -712,41 +3651,183 @@ fn load_struct<S: Into<String>>(path: S) -> Result<Holder, Error> {
             log::error!("Error while open file {}. Additional information: {}", &path, &e);
             Error::IoError {
                 path: path.clone(),
-                cause: e.to_string(),
+                cause: e,
             }
         })?;
-    file.read_to_string(&mut data).unwrap();
-    load_struct_from_str(&*data, Some(path))
+    file.read_to_string(&mut data).map_err(|e| Error::IoError { path: path.clone(), cause: e })?;
+    Ok(data)
+}
+
+/// Load file by `path` and replace `messages` by the file data.
+/// The file is parsed and validated into the new map before the data is replaced, so the invalid or half-written file does not clear the data.
+#[cfg(feature = "std")]
+fn reload_messages(messages: &Messages, path: &str) -> Result<(), Error> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::info_span!("i18n.reload", path).entered();
+    // Validation file
+    store_reloaded(messages, path, load_struct(path))
+}
+
+/// Replace `messages` by the `loaded` file `path`, shared by the reloads of the files (see [reload_messages]) and the async reloads.
+/// The file without data does not replace the current data, the result is recorded to the statistics of the holder.
+#[cfg(feature = "std")]
+pub(crate) fn store_reloaded(messages: &Messages, path: &str, loaded: Result<Holder, Error>) -> Result<(), Error> {
+    let result = loaded.and_then(|structure| {
+        // The file truncated while it is being written may be valid, but without the data.
+        if structure.messages.load().is_empty() && !messages.load().is_empty() {
+            return Err(Error::EmptyReload { path: path.to_string() });
+        }
+        // Publish the new map, readers keep the old one until they load again.
+        messages.store_loaded(&structure.messages);
+        Ok(structure.messages.stats().parse_duration.unwrap_or_default())
+    });
+    let result = match result {
+        Ok(duration) => {
+            messages.record(|stats| stats.reloaded(duration));
+            Ok(())
+        }
+        Err(e) => {
+            messages.record(|stats| stats.failed(e.to_string()));
+            Err(e)
+        }
+    };
+    #[cfg(feature = "tracing")]
+    trace_result(&result);
+    result
+}
+
+/// Event of the result of the load or the reload in the current span, feature `tracing`.
+/// The skipped files (not supported extension, included files) are not traced.
+#[cfg(feature = "tracing")]
+fn trace_result<T>(result: &Result<T, Error>) {
+    match result {
+        Ok(_) => tracing::info!("done"),
+        Err(Error::NotSupportedFileExtension { .. } | Error::IncludeFile { .. }) => {}
+        Err(e) => tracing::warn!(error = %e, "failed"),
+    }
+}
+
+/// Pass the lookup to the instrumentation, see [InternationalCore::set_metrics].
+#[cfg(feature = "std")]
+fn record_lookup(metrics: &Option<Arc<dyn Metrics>>, locale: &str, found: bool) {
+    if let Some(metrics) = metrics {
+        metrics.lookup(locale, found);
+    }
+}
+
+/// Result of the missing key handler or the key itself.
+#[cfg(feature = "std")]
+fn missing_value(handler: &Option<MissingHandler>, locale: &str, key: &str) -> String {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(locale, key, "missing key");
+    handler.as_ref().and_then(|h| h(locale, key)).unwrap_or_else(|| key.to_string())
 }
 
 /// Getting locale or default by `locale` parameter with `sys-locale` library.
+#[cfg(feature = "std")]
 fn get_locale_or_default(locale: &str) -> String {
     get_locale().unwrap_or(String::from(locale))
 }
 
-/// Get current system locale or return default `en-US`
-fn get_current_locale_or_default() -> String {
-    get_locale_or_default("en-US")
+/// Get current thread-local locale (see [locale::LocaleGuard]), the locale of the environment variables if preferred (see [locale::prefer_env_locale]),
+/// system locale or return default `en-US`
+#[cfg(feature = "std")]
+pub(crate) fn get_current_locale_or_default() -> String {
+    locale::scoped_locale()
+        .or_else(|| locale::is_env_locale_preferred().then(locale::env_locale).flatten())
+        .unwrap_or_else(|| get_locale_or_default("en-US"))
 }
 
+#[cfg(feature = "std")]
 impl From<serde_yaml::Value> for FileData {
     fn from(value: serde_yaml::Value) -> Self {
         match value {
+            serde_yaml::Value::Mapping(obj) if is_entry(&obj) => {
+                let field = |name: &str| obj.get(&Value::String(name.to_string())).and_then(scalar);
+                FileData::Entry(field("value").unwrap_or_default(), Metadata {
+                    comment: field("comment"),
+                    context: field("context"),
+                    max_len: field("max_len").and_then(|l| l.parse().ok()),
+                })
+            }
             serde_yaml::Value::Mapping(obj) => FileData::Map(
                 obj.into_iter()
-                    .filter_map(|(k, v)| match k {
-                        serde_yaml::Value::String(s) => Some((s, FileData::from(v))),
-                        _ => None,
-                    })
+                    .filter_map(|(k, v)| scalar(&k).map(|k| (k, FileData::from(v))))
                     .collect(),
             ),
             serde_yaml::Value::String(s) => FileData::String(s),
+            // Numbers and booleans are stored as text, see [GetData::get_as].
+            serde_yaml::Value::Number(n) => FileData::String(n.to_string()),
+            serde_yaml::Value::Bool(b) => FileData::String(b.to_string()),
+            // Items of the list are available by index: `key.0`, `key.1`...
+            serde_yaml::Value::Sequence(items) => FileData::Map(
+                items.into_iter().enumerate().map(|(i, v)| (i.to_string(), FileData::from(v))).collect(),
+            ),
             _ => FileData::Map(Default::default()),
         }
     }
 }
 
-fn to_flatten(name: String, val: FileData) -> HashMap<String, String> {
+/// Nested maps with the keys `0`, `1`... are written back as the lists, opposite to the indexed keys of [FileData].
+#[cfg(feature = "std")]
+fn to_sequences(value: Value) -> Value {
+    match value {
+        Value::Mapping(map) => {
+            let is_list = !map.is_empty() && (0..map.len()).all(|i| map.contains_key(&Value::String(i.to_string())));
+            if is_list {
+                let mut map = map;
+                let items = (0..map.len()).map(|i| to_sequences(map.remove(&Value::String(i.to_string())).unwrap_or_default())).collect();
+                Value::Sequence(items)
+            } else {
+                Value::Mapping(map.into_iter().map(|(k, v)| (k, to_sequences(v))).collect())
+            }
+        }
+        value => value,
+    }
+}
+
+/// The mapping is the message with notes, if it has the string `value`, at least one of the fields `comment`, `context`, `max_len`
+/// and no other fields. The mapping with `value` only is the ordinary nested message, `foo: {value: "x"}` is the key `foo.value`.
+#[cfg(feature = "std")]
+fn is_entry(obj: &Mapping) -> bool {
+    obj.get(&Value::String("value".to_string())).and_then(scalar).is_some()
+        && obj.iter().any(|(k, _)| matches!(k.as_str(), Some("comment" | "context" | "max_len")))
+        && obj.iter().all(|(k, v)| matches!(k.as_str(), Some("value" | "comment" | "context" | "max_len")) && scalar(v).is_some())
+}
+
+/// Text of the string, number or boolean, unquoted values of YAML (`count: 3`, `enabled: true`) are accepted.
+#[cfg(feature = "std")]
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Insert the value by the key split by `.` to the nested maps, opposite to [to_flatten].
+/// If a part of the key is already a message, the rest of the key is inserted as is.
+#[cfg(feature = "std")]
+fn insert_nested(map: &mut Mapping, parts: &[&str], value: Value) {
+    let key = Value::String(parts[0].to_string());
+    if parts.len() == 1 {
+        map.insert(key, value);
+        return;
+    }
+    if !map.contains_key(&key) {
+        map.insert(key.clone(), Value::Mapping(Mapping::new()));
+    }
+    match map.get_mut(&key) {
+        Some(Value::Mapping(nested)) => insert_nested(nested, &parts[1..], value),
+        _ => {
+            map.insert(Value::String(parts.join(".")), value);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn to_flatten(name: String, val: FileData, metadata: &mut HashMap<String, Metadata>) -> HashMap<String, String> {
     let mut map = HashMap::new();
     match val {
         FileData::Map(array) => {
@@ -758,12 +3839,19 @@ fn to_flatten(name: String, val: FileData) -> HashMap<String, String> {
                         format!("{}.{}", name, name2)
                     },
                     v,
+                    metadata,
                 ));
             }
         }
         FileData::String(s) => {
             map.insert(name, s.clone());
         }
+        FileData::Entry(s, notes) => {
+            if !notes.is_empty() {
+                metadata.insert(name.clone(), notes);
+            }
+            map.insert(name, s);
+        }
     };
     map
 }
\ No newline at end of file
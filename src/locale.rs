@@ -0,0 +1,213 @@
+//! Locale identifiers.
+//! Locales are normalized to the BCP-47 form when loading files and when looking up,
+//! so `en-US`, `en_US` and `EN-us` refer to the same holder.
+//! Also contains the thread-local locale scope, see [LocaleGuard].
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Normalize the locale identifier to the BCP-47 form: subtags are separated by `-`,
+/// language and variants are in lower case, script is in title case, region is in upper case.
+/// POSIX suffixes (`.UTF-8`, `@euro`) are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::locale::normalize;
+/// assert_eq!("en", normalize("EN"));
+/// assert_eq!("en-US", normalize("en_us"));
+/// assert_eq!("zh-Hant-TW", normalize("ZH_hant_tw"));
+/// assert_eq!("es-419", normalize("es-419"));
+/// assert_eq!("de-DE", normalize("de_DE.UTF-8"));
+/// ```
+pub fn normalize(locale: &str) -> String {
+    let locale = locale.trim();
+    let locale = locale.split(['.', '@']).next().unwrap_or_default();
+    locale
+        .split(['-', '_'])
+        .filter(|subtag| !subtag.is_empty())
+        .enumerate()
+        .map(|(i, subtag)| {
+            if i == 0 {
+                subtag.to_lowercase()
+            } else if subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic()) {
+                let mut script = subtag[..1].to_uppercase();
+                script.push_str(&subtag[1..].to_lowercase());
+                script
+            } else if (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit())) {
+                subtag.to_uppercase()
+            } else {
+                subtag.to_lowercase()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+/// Language subtag of the locale in lower case. `en-US`, `en_US` and `EN` -> `en`.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::locale::language;
+/// assert_eq!("en", language("EN_us"));
+/// ```
+pub fn language(locale: &str) -> String {
+    normalize(locale).split('-').next().unwrap_or_default().to_string()
+}
+
+/// The locale and its truncated forms, from the most specific to the language.
+/// Used for region-to-language fallback: `en-GB` falls back to `en`.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::locale::truncations;
+/// assert_eq!(vec!["zh-Hant-TW", "zh-Hant", "zh"], truncations("zh_hant_tw"));
+/// assert_eq!(vec!["en"], truncations("EN"));
+/// ```
+pub fn truncations(locale: &str) -> Vec<String> {
+    let locale = normalize(locale);
+    let subtags = locale.split('-').collect::<Vec<&str>>();
+    (1..=subtags.len()).rev().map(|n| subtags[..n].join("-")).collect()
+}
+
+/// Locales of the `Accept-Language` header, normalized and ordered by quality. Wildcard `*` and locales with quality 0 are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::locale::parse_accept_language;
+/// assert_eq!(vec!["ru-RU", "ru", "en"], parse_accept_language("en;q=0.5, ru-RU, ru;q=0.9, *;q=0.1"));
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut locales = header
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+            let locale = parts.next()?.trim();
+            let quality = parts
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if locale.is_empty() || locale == "*" || quality <= 0.0 {
+                return None;
+            }
+            Some((normalize(locale), quality))
+        })
+        .collect::<Vec<(String, f32)>>();
+    // Stable, so the locales with the same quality keep the order of the header.
+    locales.sort_by(|a, b| b.1.total_cmp(&a.1));
+    locales.into_iter().map(|(locale, _)| locale).collect()
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static SCOPED_LOCALE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Sets the thread-local locale until the guard is dropped, then restores the previous one.
+/// While the guard is alive, the scoped locale is used as the current locale
+/// ([crate::InternationalCore::get_current_locale] and `i18n!` without locale).
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::locale::{scoped_locale, LocaleGuard};
+/// {
+///     let _guard = LocaleGuard::new("ru");
+///     assert_eq!(Some("ru".to_string()), scoped_locale());
+/// }
+/// assert_eq!(None, scoped_locale());
+/// ```
+#[cfg(feature = "std")]
+pub struct LocaleGuard {
+    previous: Option<String>,
+}
+
+#[cfg(feature = "std")]
+impl LocaleGuard {
+    /// Setting the thread-local locale.
+    pub fn new(locale: &str) -> Self {
+        let previous = SCOPED_LOCALE.with(|l| l.replace(Some(locale.to_string())));
+        LocaleGuard { previous }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for LocaleGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        SCOPED_LOCALE.with(|l| *l.borrow_mut() = previous);
+    }
+}
+
+/// Run `f` with the thread-local locale, see [LocaleGuard].
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::locale::{scoped_locale, with_locale};
+/// let locale = with_locale("ru", || scoped_locale());
+/// assert_eq!(Some("ru".to_string()), locale);
+/// ```
+#[cfg(feature = "std")]
+pub fn with_locale<R, F: FnOnce() -> R>(locale: &str, f: F) -> R {
+    let _guard = LocaleGuard::new(locale);
+    f()
+}
+
+/// Get the thread-local locale, if it has been set by [LocaleGuard] or [with_locale].
+#[cfg(feature = "std")]
+pub fn scoped_locale() -> Option<String> {
+    SCOPED_LOCALE.with(|l| l.borrow().clone())
+}
+
+#[cfg(feature = "std")]
+static PREFER_ENV: AtomicBool = AtomicBool::new(false);
+
+/// Prefer the POSIX environment variables (see [env_locale]) to the OS API for the current locale,
+/// what CLI users on Linux expect. The thread-local locale (see [LocaleGuard]) still takes precedence.
+///
+/// # Examples
+///
+/// ```no_run
+/// // LANG=ru_RU.UTF-8 app
+/// sorrow_i18n::locale::prefer_env_locale(true);
+/// ```
+#[cfg(feature = "std")]
+pub fn prefer_env_locale(enabled: bool) {
+    PREFER_ENV.store(enabled, Ordering::Relaxed);
+}
+
+/// The environment variables are preferred to the OS API, see [prefer_env_locale].
+#[cfg(feature = "std")]
+pub fn is_env_locale_preferred() -> bool {
+    PREFER_ENV.load(Ordering::Relaxed)
+}
+
+/// Locale of the POSIX environment variables, normalized. The first non-empty of `LC_ALL`, `LC_MESSAGES` and `LANG` is used.
+/// `None` if the variables are not set, or the locale is `C` or `POSIX` (no language).
+///
+/// # Examples
+///
+/// ```no_run
+/// // LC_ALL= LC_MESSAGES=de_DE.UTF-8 LANG=en_US.UTF-8 app
+/// assert_eq!(Some("de-DE".to_string()), sorrow_i18n::locale::env_locale());
+/// ```
+#[cfg(feature = "std")]
+pub fn env_locale() -> Option<String> {
+    let value = ["LC_ALL", "LC_MESSAGES", "LANG"].iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())?;
+    let locale = normalize(&value);
+    match locale.as_str() {
+        "" | "c" | "posix" => None,
+        _ => Some(locale),
+    }
+}
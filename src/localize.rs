@@ -0,0 +1,251 @@
+//! Localized display of enums (error kinds, statuses...) without match blocks in UI code.
+//! With `derive` feature, [Localize] can be derived, every variant is mapped to the catalog key.
+//!
+//! ```ignore
+//! use sorrow_i18n::Localize;
+//!
+//! #[derive(Localize)]
+//! #[localize(prefix = "data.errors")]
+//! enum ErrorKind {
+//!     // data.errors.not_found
+//!     NotFound,
+//!     #[localize(key = "data.errors.denied")]
+//!     PermissionDenied,
+//! }
+//!
+//! let message = ErrorKind::NotFound.display(&core, "EN");
+//! ```
+//!
+//! Without the attributes the prefix is `data.{enum}` in snake case, so `ErrorKind::NotFound` is `data.error_kind.not_found`.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{get_current_locale_or_default, GetData, InternationalCore};
+
+/// Value mapped to the catalog key.
+pub trait Localize {
+    /// Catalog key of the value.
+    fn key(&self) -> &'static str;
+
+    /// Localized value by locale. If the locale or the key is not found, the value by [InternationalCore::set_missing_policy]
+    /// (the key itself by default).
+    fn display(&self, core: &InternationalCore, locale: &str) -> String {
+        match core.get_by_locale(locale) {
+            None => core.missing_key(locale, self.key()),
+            Some(data) => data.get_or_default(self.key()),
+        }
+    }
+
+    /// Localized value from the holder's data. If the key is not found, return the key.
+    fn display_by<D: GetData>(&self, data: &D) -> String {
+        data.get_or_default(self.key())
+    }
+}
+
+/// Argument of the message rendered by the target locale, e.g. enums, units or durations, see [GetData::get_with_localized_args].
+/// Implemented for strings, numbers, `bool` and `char` (rendered as is) and [LocalizedString].
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::{GetData, InternationalCore, ToLocalizedString};
+///
+/// struct Minutes(u32);
+///
+/// impl ToLocalizedString for Minutes {
+///     fn to_localized_string(&self, locale: &str) -> String {
+///         match locale {
+///             "ru" => format!("{} мин", self.0),
+///             _ => format!("{} min", self.0),
+///         }
+///     }
+/// }
+///
+/// let core = InternationalCore::builder()
+///     .insert("EN", "data.eta", "{name} arrives in {eta}")
+///     .insert("RU", "data.eta", "{name} прибудет через {eta}")
+///     .build();
+/// let ru = core.get_by_locale("RU").unwrap();
+/// assert_eq!("Боб прибудет через 5 мин", ru.get_with_localized_args("data.eta", &[("name", &"Боб"), ("eta", &Minutes(5))]));
+/// ```
+pub trait ToLocalizedString {
+    /// Text of the value in the locale, the locale is normalized (see [crate::locale::normalize]).
+    fn to_localized_string(&self, locale: &str) -> String;
+}
+
+macro_rules! to_localized_string_as_is {
+    ($($t:ty),*) => {
+        $(
+            impl ToLocalizedString for $t {
+                fn to_localized_string(&self, _: &str) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+to_localized_string_as_is!(str, &str, String, Cow<'_, str>, char, bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl ToLocalizedString for LocalizedString {
+    fn to_localized_string(&self, locale: &str) -> String {
+        self.in_locale(locale)
+    }
+}
+
+/// Key of the message, the value is looked up at formatting time in the current locale (see [crate::locale::with_locale]).
+/// So the message can be stored in structs and rendered later, after the language has been chosen.
+/// Created by [InternationalCore::localize], or with feature `macro` by [crate::feature_macro::localize] for the static core.
+/// If the locale or the key is not found, the value by [crate::MissingKeyPolicy] is displayed (the key by default).
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use sorrow_i18n::{locale, InternationalCore};
+///
+/// let core = Arc::new(InternationalCore::builder().insert("EN", "menu.open", "Open").insert("RU", "menu.open", "Открыть").build());
+///
+/// let title = core.localize("menu.open");
+/// assert_eq!("Открыть", locale::with_locale("RU", || title.to_string()));
+/// assert_eq!("Open", locale::with_locale("EN", || title.to_string()));
+/// assert_eq!("Open", title.in_locale("EN"));
+/// ```
+#[derive(Clone)]
+pub struct LocalizedString {
+    key: Cow<'static, str>,
+    core: Option<Arc<InternationalCore>>,
+}
+
+impl LocalizedString {
+    /// Message of the core by key.
+    pub fn new<K: Into<Cow<'static, str>>>(core: Arc<InternationalCore>, key: K) -> Self {
+        LocalizedString { key: key.into(), core: Some(core) }
+    }
+
+    /// Message of the static core (see [crate::init_i18n]).
+    #[cfg(feature = "macro")]
+    pub(crate) fn from_static<K: Into<Cow<'static, str>>>(key: K) -> Self {
+        LocalizedString { key: key.into(), core: None }
+    }
+
+    /// Key of the message.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Value in the locale. If the locale or the key is not found, the value by [crate::MissingKeyPolicy] (the key by default).
+    pub fn in_locale(&self, locale: &str) -> String {
+        match &self.core {
+            Some(core) => match core.get_by_locale(locale) {
+                None => core.missing_key(locale, &self.key),
+                Some(data) => data.get_or_default(self.key.as_ref()),
+            },
+            #[cfg(feature = "macro")]
+            None => crate::feature_macro::get_param(locale, &self.key),
+            #[cfg(not(feature = "macro"))]
+            None => self.key.to_string(),
+        }
+    }
+
+    fn current_locale(&self) -> String {
+        #[cfg(feature = "macro")]
+        if self.core.is_none() {
+            return crate::feature_macro::get_current_locale();
+        }
+        match &self.core {
+            Some(core) => core.current_locale(),
+            None => get_current_locale_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for LocalizedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.in_locale(&self.current_locale()))
+    }
+}
+
+impl fmt::Debug for LocalizedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalizedString").field("key", &self.key).finish()
+    }
+}
+
+impl InternationalCore {
+    /// Message by key, the value is looked up at formatting time, see [LocalizedString].
+    pub fn localize<K: Into<Cow<'static, str>>>(self: &Arc<Self>, key: K) -> LocalizedString {
+        LocalizedString::new(Arc::clone(self), key)
+    }
+}
+
+/// Catalog key defined as constant, resolved against the core and the locale at call time.
+/// So libraries can define their messages without the instance of the core.
+/// In debug builds the key is checked on every lookup: panic if no locale of the core has the key.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::{InternationalCore, MessageKey};
+///
+/// const NAME: MessageKey = MessageKey::new("data.name");
+///
+/// let core = InternationalCore::builder().insert("EN", "data.name", "Test").insert("RU", "data.name", "Тест").build();
+/// assert_eq!("Тест", NAME.get(&core, "RU"));
+/// assert_eq!("data.name", NAME.get(&core, "DE"));
+/// assert!(NAME.exists(&core));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MessageKey(&'static str);
+
+impl MessageKey {
+    /// Key of the message.
+    pub const fn new(key: &'static str) -> Self {
+        MessageKey(key)
+    }
+
+    /// Key of the message.
+    pub const fn key(&self) -> &'static str {
+        self.0
+    }
+
+    /// Is the key present in any locale of the core.
+    pub fn exists(&self, core: &InternationalCore) -> bool {
+        core.holders.values().any(|holder| {
+            holder.load_lazy();
+            holder.messages.contains_key(self.0)
+        })
+    }
+
+    /// Value in the locale. If the locale or the key is not found, the value by [crate::MissingKeyPolicy] (the key by default).
+    pub fn get(&self, core: &InternationalCore, locale: &str) -> String {
+        self.validate(core);
+        match core.get_by_locale(locale) {
+            None => core.missing_key(locale, self.0),
+            Some(data) => data.get_or_default(self.0),
+        }
+    }
+
+    /// Value in the current locale of the core (see [InternationalCore::current_locale]).
+    pub fn get_current(&self, core: &InternationalCore) -> String {
+        self.get(core, &core.current_locale())
+    }
+
+    /// Message resolved at formatting time, see [LocalizedString].
+    pub fn localize(&self, core: &Arc<InternationalCore>) -> LocalizedString {
+        self.validate(core);
+        LocalizedString::new(Arc::clone(core), self.0)
+    }
+
+    fn validate(&self, core: &InternationalCore) {
+        debug_assert!(self.exists(core), "Key {} is not found in any locale", self.0);
+    }
+}
+
+impl fmt::Display for MessageKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
@@ -0,0 +1,333 @@
+//! Storage of the holder's data.
+//! Readers load the current map by atomic pointer load, without locks. Writers never block readers,
+//! every change publishes a fresh immutable map.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use arc_swap::ArcSwap;
+
+use crate::format::Template;
+use crate::intern::KeyId;
+use crate::stats::ReloadStats;
+
+/// Immutable map of the messages, published by [Messages]. The values of the interned keys (see [KeyId]) are indexed by id,
+/// the loaded keys are not interned. Messages with placeholders are compiled to [Template].
+#[derive(Debug, Default, Clone)]
+pub struct Snapshot {
+    map: HashMap<String, Arc<str>>,
+    index: HashMap<KeyId, Arc<str>>,
+    templates: HashMap<String, Arc<Template>>,
+}
+
+impl Snapshot {
+    fn new(map: HashMap<String, Arc<str>>) -> Self {
+        let mut templates = HashMap::new();
+        for (key, value) in map.iter() {
+            if value.contains('{') {
+                templates.insert(key.clone(), Arc::new(Template::new(Arc::clone(value))));
+            }
+        }
+        let index = KeyId::find_each(map.iter().map(|(key, value)| (key.as_str(), Arc::clone(value)))).into_iter().collect();
+        Snapshot { map, index, templates }
+    }
+
+    /// Compiled message by key.
+    pub fn template(&self, key: &str) -> Option<Arc<Template>> {
+        match self.templates.get(key) {
+            Some(template) => Some(Arc::clone(template)),
+            None => self.map.get(key).map(|v| Arc::new(Template::new(Arc::clone(v)))),
+        }
+    }
+
+    /// Value by interned key. The key interned after the snapshot has been built is looked up by the key.
+    pub fn get_by_id(&self, id: KeyId) -> Option<&Arc<str>> {
+        self.index.get(&id).or_else(|| self.map.get(&*id.as_str()))
+    }
+}
+
+impl Deref for Snapshot {
+    type Target = HashMap<String, Arc<str>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+/// Notes of the message for translators, see [Messages::get_entry].
+///
+/// ```yaml
+/// data:
+///   save:
+///     value: "Save"
+///     comment: "Button of the settings form"
+///     context: "verb"
+///     max_len: 12
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    /// Comment for translators.
+    pub comment: Option<String>,
+    /// Context of the message, e.g. to translate the same word differently.
+    pub context: Option<String>,
+    /// Max count of the characters of the message, e.g. for buttons and badges, see [crate::InternationalCore::validate].
+    pub max_len: Option<usize>,
+}
+
+impl Metadata {
+    /// Check the comment, context and max length are absent.
+    pub fn is_empty(&self) -> bool {
+        self.comment.is_none() && self.context.is_none() && self.max_len.is_none()
+    }
+}
+
+/// Message with the notes for translators.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Message.
+    pub value: String,
+    /// Comment for translators.
+    pub comment: Option<String>,
+    /// Context of the message.
+    pub context: Option<String>,
+    /// Max count of the characters of the message.
+    pub max_len: Option<usize>,
+}
+
+/// Messages of the holder, key -> value. Values are shared (`Arc<str>`), so the lookups can return them without copying.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use sorrow_i18n::Messages;
+/// let messages = Messages::default();
+/// messages.insert("data.name", "Test");
+/// assert_eq!(Some("Test".to_string()), messages.get("data.name"));
+/// assert_eq!(Some("Test".into()), messages.get_arc("data.name"));
+/// messages.store(HashMap::<String, String>::new());
+/// assert!(messages.load().is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct Messages {
+    map: ArcSwap<Snapshot>,
+    metadata: ArcSwap<HashMap<String, Metadata>>,
+    case_insensitive: AtomicBool,
+    /// Messages and notes with the original case of the keys, kept while the keys are case-insensitive.
+    /// The lock is held by the writers, so the change of the case is not mixed with the stores.
+    original: Mutex<Option<Original>>,
+    stats: Mutex<ReloadStats>,
+}
+
+/// Messages and notes with the original case of the keys, see [Messages::set_case_insensitive].
+type Original = (HashMap<String, Arc<str>>, HashMap<String, Metadata>);
+
+impl Messages {
+    /// Messages with the map.
+    pub fn new<V: Into<Arc<str>>>(map: HashMap<String, V>) -> Self {
+        Messages {
+            map: ArcSwap::from_pointee(Snapshot::new(shared(map))),
+            metadata: ArcSwap::default(),
+            case_insensitive: AtomicBool::new(false),
+            original: Mutex::default(),
+            stats: Mutex::default(),
+        }
+    }
+
+    /// Lowercase the keys of the messages and of the lookups, so `Data.Name` and `data.name` are the same key.
+    /// The current messages are lowercased at once, the stored later (e.g. by the reload) on store.
+    /// Lookups by [KeyId] use the lowercased keys.
+    /// The messages with the original case of the keys are kept, and restored when the keys are case-sensitive again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::Messages;
+    /// let messages = Messages::default();
+    /// messages.insert("Data.Name", "Test");
+    /// messages.set_case_insensitive(true);
+    /// assert_eq!(Some("Test".to_string()), messages.get("data.NAME"));
+    /// assert!(messages.contains_key("data.name"));
+    ///
+    /// messages.set_case_insensitive(false);
+    /// assert_eq!(Some("Test".to_string()), messages.get("Data.Name"));
+    /// assert_eq!(None, messages.get("data.name"));
+    /// ```
+    pub fn set_case_insensitive(&self, enabled: bool) {
+        let mut original = self.original.lock().unwrap();
+        if self.case_insensitive.swap(enabled, Ordering::SeqCst) == enabled {
+            return;
+        }
+        if enabled {
+            *original = Some((self.map.load().map.clone(), HashMap::clone(&self.metadata.load())));
+            self.map.rcu(|current| Snapshot::new(self.fold_map(current.map.clone())));
+            self.metadata.rcu(|current| self.fold_map(HashMap::clone(current)));
+        } else if let Some((map, metadata)) = original.take() {
+            self.map.store(Arc::new(Snapshot::new(map)));
+            self.metadata.store(Arc::new(metadata));
+        }
+    }
+
+    /// Keys are lowercased, see [Messages::set_case_insensitive].
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive.load(Ordering::Relaxed)
+    }
+
+    /// Key of the lookup, lowercased if the keys are case-insensitive.
+    pub fn lookup_key<'a>(&self, key: &'a str) -> Cow<'a, str> {
+        match self.is_case_insensitive() {
+            true => Cow::Owned(key.to_lowercase()),
+            false => Cow::Borrowed(key),
+        }
+    }
+
+    fn fold_map<V>(&self, map: HashMap<String, V>) -> HashMap<String, V> {
+        match self.is_case_insensitive() {
+            true => map.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect(),
+            false => map,
+        }
+    }
+
+    /// Current map. The map is immutable, changes are published as a new map.
+    pub fn load(&self) -> Arc<Snapshot> {
+        self.map.load_full()
+    }
+
+    /// Value by key.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.map.load().get(self.lookup_key(key).as_ref()).map(|v| v.to_string())
+    }
+
+    /// Shared value by key, without copying the string.
+    pub fn get_arc(&self, key: &str) -> Option<Arc<str>> {
+        self.map.load().get(self.lookup_key(key).as_ref()).cloned()
+    }
+
+    /// Shared value by interned key.
+    pub fn get_by_id(&self, id: KeyId) -> Option<Arc<str>> {
+        self.map.load().get_by_id(id).cloned()
+    }
+
+    /// Compiled message by key, see [Snapshot::template].
+    pub fn get_template(&self, key: &str) -> Option<Arc<Template>> {
+        self.map.load().template(&self.lookup_key(key))
+    }
+
+    /// Message with the notes for translators by key, see [Metadata].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use sorrow_i18n::Messages;
+    /// use sorrow_i18n::messages::Metadata;
+    /// let messages = Messages::default();
+    /// messages.insert("data.save", "Save");
+    /// let metadata = Metadata { comment: Some("Button".to_string()), ..Metadata::default() };
+    /// messages.store_metadata(HashMap::from([("data.save".to_string(), metadata)]));
+    /// assert_eq!(Some("Button".to_string()), messages.get_entry("data.save").unwrap().comment);
+    /// ```
+    pub fn get_entry(&self, key: &str) -> Option<Entry> {
+        let value = self.get(key)?;
+        let metadata = self.metadata.load().get(self.lookup_key(key).as_ref()).cloned().unwrap_or_default();
+        Some(Entry { value, comment: metadata.comment, context: metadata.context, max_len: metadata.max_len })
+    }
+
+    /// Notes for translators by key, only keys with notes are present.
+    pub fn metadata(&self) -> Arc<HashMap<String, Metadata>> {
+        self.metadata.load_full()
+    }
+
+    /// Replace the notes for translators, the messages are not changed.
+    pub fn store_metadata(&self, metadata: HashMap<String, Metadata>) {
+        let mut original = self.original.lock().unwrap();
+        if let Some((_, original)) = original.as_mut() {
+            *original = metadata.clone();
+        }
+        self.metadata.store(Arc::new(self.fold_map(metadata)));
+    }
+
+    /// Check the key exists.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.map.load().contains_key(self.lookup_key(key).as_ref())
+    }
+
+    /// Messages with the keys starting with `prefix`, from one version of the map.
+    pub fn get_all(&self, prefix: &str) -> HashMap<String, String> {
+        let prefix = self.lookup_key(prefix);
+        let prefix = prefix.as_ref();
+        self.map.load().iter().filter(|(k, _)| k.starts_with(prefix)).map(|(k, v)| (k.clone(), v.to_string())).collect()
+    }
+
+    /// Copy of the current map.
+    pub fn to_map(&self) -> HashMap<String, String> {
+        self.map.load().iter().map(|(k, v)| (k.clone(), v.to_string())).collect()
+    }
+
+    /// Replace all messages, readers see either the old or the new map.
+    /// It's the bulk path: the map is indexed once, use it instead of [Messages::insert] in a loop.
+    pub fn store<V: Into<Arc<str>>>(&self, map: HashMap<String, V>) {
+        let map = shared(map);
+        let mut original = self.original.lock().unwrap();
+        if let Some((original, _)) = original.as_mut() {
+            *original = map.clone();
+        }
+        self.map.store(Arc::new(Snapshot::new(self.fold_map(map))));
+    }
+
+    /// Replace the messages and the notes by the loaded ones, e.g. after reading the file again.
+    pub(crate) fn store_loaded(&self, loaded: &Messages) {
+        let mut original = self.original.lock().unwrap();
+        match original.as_mut() {
+            Some(original) => {
+                *original = (loaded.load().map.clone(), HashMap::clone(&loaded.metadata()));
+                self.map.store(Arc::new(Snapshot::new(self.fold_map(loaded.load().map.clone()))));
+                self.metadata.store(Arc::new(self.fold_map(HashMap::clone(&loaded.metadata()))));
+            }
+            None => {
+                self.map.store(loaded.load());
+                self.metadata.store(loaded.metadata());
+            }
+        }
+    }
+
+    /// Statistics of the loading and the reloads of the file, see [crate::InternationalCore::stats].
+    pub fn stats(&self) -> ReloadStats {
+        self.stats.lock().unwrap().clone()
+    }
+
+    /// Change the statistics, e.g. after the reload of the file.
+    pub(crate) fn record<F: FnOnce(&mut ReloadStats)>(&self, f: F) {
+        f(&mut self.stats.lock().unwrap())
+    }
+
+    /// Change a copy of the current map and publish it. `f` may be called again if the map has been changed concurrently.
+    /// Every call copies and indexes the whole map, so change many keys by one call, or by [Messages::store].
+    /// While the keys are case-insensitive, `f` changes the messages with the original case of the keys too.
+    pub fn update<F: FnMut(&mut HashMap<String, Arc<str>>)>(&self, mut f: F) {
+        let mut original = self.original.lock().unwrap();
+        if let Some((original, _)) = original.as_mut() {
+            f(original);
+        }
+        self.map.rcu(|current| {
+            let mut map = current.map.clone();
+            f(&mut map);
+            Snapshot::new(self.fold_map(map))
+        });
+    }
+
+    /// Insert the message, see [Messages::update]. The cost is the copy of the whole map, for many messages use [Messages::store].
+    pub fn insert<K: Into<String>, V: Into<Arc<str>>>(&self, key: K, value: V) {
+        let (key, value) = (key.into(), value.into());
+        self.update(|map| {
+            map.insert(key.clone(), value.clone());
+        });
+    }
+}
+
+fn shared<V: Into<Arc<str>>>(map: HashMap<String, V>) -> HashMap<String, Arc<str>> {
+    map.into_iter().map(|(k, v)| (k, v.into())).collect()
+}
@@ -0,0 +1,81 @@
+//! Instrumentation of the lookups and reloads, see [crate::InternationalCore::set_metrics].
+//! Implement [Metrics] to count by your own system, or enable feature `metrics` to record by the `metrics` crate.
+//!
+//! ```
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::sync::Arc;
+//! use sorrow_i18n::metrics::Metrics;
+//! use sorrow_i18n::{Error, GetData, InternationalCore};
+//!
+//! #[derive(Default)]
+//! struct Misses(AtomicUsize);
+//!
+//! impl Metrics for Misses {
+//!     fn lookup(&self, _locale: &str, found: bool) {
+//!         if !found {
+//!             self.0.fetch_add(1, Ordering::Relaxed);
+//!         }
+//!     }
+//!
+//!     fn reload(&self, _locale: &str, _error: Option<&Error>) {}
+//! }
+//!
+//! let mut core = InternationalCore::builder().insert("EN", "data.name", "Test").build();
+//! let misses = Arc::new(Misses::default());
+//! core.set_metrics(Arc::clone(&misses));
+//! let en = core.get_by_locale("EN").unwrap();
+//! en.get_or_default("data.name");
+//! en.get_or_default("data.title");
+//! assert_eq!(1, misses.0.load(Ordering::Relaxed));
+//! ```
+
+use std::sync::Arc;
+
+use crate::Error;
+
+/// Receiver of the lookups and reloads of the core. Called on the hot paths, so the implementation should be cheap.
+pub trait Metrics: Send + Sync {
+    /// Lookup of the key in the locale (including its fallback chain), `found` is `false` if the key is missing.
+    fn lookup(&self, locale: &str, found: bool);
+
+    /// Reload of the locale by the provider or [crate::InternationalCore::reload]: `None` if the data has been reloaded, or the error.
+    fn reload(&self, locale: &str, error: Option<&Error>);
+}
+
+impl<M: Metrics + ?Sized> Metrics for Arc<M> {
+    fn lookup(&self, locale: &str, found: bool) {
+        (**self).lookup(locale, found)
+    }
+
+    fn reload(&self, locale: &str, error: Option<&Error>) {
+        (**self).reload(locale, error)
+    }
+}
+
+/// [Metrics] recorded by the `metrics` crate, feature `metrics`. Counters labeled by `locale`:
+/// `i18n_lookups_total`, `i18n_misses_total` and `i18n_reloads_total` (with label `result`: `ok` or `error`).
+///
+/// ```no_run
+/// use sorrow_i18n::InternationalCore;
+/// use sorrow_i18n::metrics::MetricsRecorder;
+/// let mut core = InternationalCore::new("locale/");
+/// core.set_metrics(MetricsRecorder);
+/// ```
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsRecorder;
+
+#[cfg(feature = "metrics")]
+impl Metrics for MetricsRecorder {
+    fn lookup(&self, locale: &str, found: bool) {
+        ::metrics::counter!("i18n_lookups_total", "locale" => locale.to_string()).increment(1);
+        if !found {
+            ::metrics::counter!("i18n_misses_total", "locale" => locale.to_string()).increment(1);
+        }
+    }
+
+    fn reload(&self, locale: &str, error: Option<&Error>) {
+        let result = if error.is_none() { "ok" } else { "error" };
+        ::metrics::counter!("i18n_reloads_total", "locale" => locale.to_string(), "result" => result).increment(1);
+    }
+}
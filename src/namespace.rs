@@ -0,0 +1,91 @@
+//! Namespaces of keys.
+//! Files can declare the namespace, then their keys are available only through [Namespace],
+//! so the same key in different modules never collides.
+//!
+//! ```yaml
+//! kind: I18N
+//! locale: EN
+//! namespace: checkout
+//! data:
+//!   title: "Checkout"
+//! ```
+
+use std::collections::HashMap;
+
+use crate::check::{self, ConsistencyReport, CoverageReport, Diagnostic};
+use crate::locale::normalize;
+use crate::{Data, Error, GetData, Holder, InternationalCore, UnWatchData};
+
+/// View of the core scoped by namespace, see [InternationalCore::namespace].
+/// If the namespace is not loaded, all locales are missing.
+pub struct Namespace<'a> {
+    core: &'a InternationalCore,
+    name: String,
+    holders: Option<&'a HashMap<String, Holder>>,
+}
+
+impl<'a> Namespace<'a> {
+    pub(crate) fn new(core: &'a InternationalCore, name: &str, holders: Option<&'a HashMap<String, Holder>>) -> Self {
+        Namespace { core, name: name.to_string(), holders }
+    }
+
+    /// Getting [Data] of the namespace by locale, see [InternationalCore::get_by_locale].
+    pub fn get_by_locale(&self, locale: &str) -> Option<Data> {
+        self.core.data_in(self.holders?, locale)
+    }
+
+    /// Getting [UnWatchData] of the namespace by locale, see [InternationalCore::get_by_locale_state].
+    pub fn get_by_locale_state(&self, locale: &str) -> Option<UnWatchData> {
+        let mut data = self.core.state_in(self.holders?, locale)?;
+        data.namespace = Some(self.name.clone());
+        Some(data)
+    }
+
+    /// Getting [Data] of the namespace by current locale.
+    pub fn get_current_locale(&self) -> Option<Data> {
+        self.get_by_locale(&self.core.current_locale())
+    }
+
+    /// Getting value by key in current locale. If the key or locale is not found, return None.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.get_current_locale()?.get(key)
+    }
+
+    /// Getting value by key in current locale. If the key or locale is not found, the value by [InternationalCore::set_missing_policy]
+    /// (the key itself by default, see [InternationalCore::on_missing]).
+    pub fn get_or_default(&self, key: &str) -> String {
+        let locale = self.core.current_locale();
+        match self.get_by_locale(&locale) {
+            None => self.core.missing_key(&locale, key),
+            Some(data) => data.get_or_default(key),
+        }
+    }
+
+    /// Report of the keys present in one locale of the namespace but missing in others, see [InternationalCore::check_consistency].
+    pub fn check_consistency(&self) -> ConsistencyReport {
+        self.holders.map(check::consistency).unwrap_or_default()
+    }
+
+    /// Placeholders of the locales of the namespace against `base_locale`, see [InternationalCore::check_placeholders].
+    pub fn check_placeholders(&self, base_locale: &str) -> Result<Vec<Diagnostic>, Error> {
+        match self.holders {
+            None => Err(Error::MissingLocale { locale: normalize(base_locale) }),
+            Some(holders) => check::placeholders(holders, base_locale),
+        }
+    }
+
+    /// Translation progress of the locales of the namespace, see [InternationalCore::coverage].
+    pub fn coverage(&self, base_locale: &str) -> Result<CoverageReport, Error> {
+        match self.holders {
+            None => Err(Error::MissingLocale { locale: normalize(base_locale) }),
+            Some(holders) => check::coverage(holders, base_locale),
+        }
+    }
+
+    /// Locales of the namespace, sorted.
+    pub fn locales(&self) -> Vec<String> {
+        let mut locales = self.holders.map(|h| h.keys().cloned().collect::<Vec<String>>()).unwrap_or_default();
+        locales.sort();
+        locales
+    }
+}
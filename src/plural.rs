@@ -0,0 +1,106 @@
+//! Plural rules by locale.
+//! Only ordinal rules are implemented, see [ordinal_category].
+//! The rules follow CLDR, for languages without special rules [PluralCategory::Other] is returned.
+
+use crate::locale::language;
+
+/// Plural category by CLDR.
+/// In files, the category is the last part of the key.
+///
+/// # Examples
+///
+/// ```yaml
+/// kind: I18N
+/// locale: EN
+/// data:
+///   place:
+///     one: "{n}st"
+///     two: "{n}nd"
+///     few: "{n}rd"
+///     other: "{n}th"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    /// `zero` form.
+    Zero,
+    /// `one` form.
+    One,
+    /// `two` form.
+    Two,
+    /// `few` form.
+    Few,
+    /// `many` form.
+    Many,
+    /// `other` form. Required, used when the category form is not found.
+    Other,
+}
+
+impl PluralCategory {
+    /// Key suffix for the category.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// Getting the ordinal category (1st, 2nd, 3rd...) for `n` by locale.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::plural::{ordinal_category, PluralCategory};
+/// assert_eq!(PluralCategory::One, ordinal_category("EN", 21));
+/// assert_eq!(PluralCategory::Other, ordinal_category("en-US", 11));
+/// assert_eq!(PluralCategory::Other, ordinal_category("RU", 3));
+/// ```
+pub fn ordinal_category(locale: &str, n: u64) -> PluralCategory {
+    let n10 = n % 10;
+    let n100 = n % 100;
+    match language(locale).as_str() {
+        "en" => {
+            match (n10, n100) {
+                (1, r) if r != 11 => PluralCategory::One,
+                (2, r) if r != 12 => PluralCategory::Two,
+                (3, r) if r != 13 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            }
+        }
+        "fr" | "ga" | "hy" | "ms" | "ro" | "vi" => {
+            if n == 1 { PluralCategory::One } else { PluralCategory::Other }
+        }
+        "hu" => {
+            if n == 1 || n == 5 { PluralCategory::One } else { PluralCategory::Other }
+        }
+        "sv" => {
+            if (n10 == 1 || n10 == 2) && n100 != 11 && n100 != 12 { PluralCategory::One } else { PluralCategory::Other }
+        }
+        "it" => {
+            if matches!(n, 8 | 11 | 80 | 800) { PluralCategory::Many } else { PluralCategory::Other }
+        }
+        "ca" => {
+            match n {
+                1 | 3 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            }
+        }
+        "cy" => {
+            match n {
+                0 | 7 | 8 | 9 => PluralCategory::Zero,
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                3 | 4 => PluralCategory::Few,
+                5 | 6 => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            }
+        }
+        _ => PluralCategory::Other,
+    }
+}
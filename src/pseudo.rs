@@ -0,0 +1,80 @@
+//! Pseudo-localization: the messages are accented, expanded by ~30% and wrapped by brackets,
+//! so truncated texts and hard-coded strings are visible in UI before real translations exist.
+//! Placeholders (`{name}`) and HTML tags are kept as is.
+
+use std::collections::HashMap;
+
+use crate::format::Template;
+use crate::Holder;
+
+/// Default locale of the pseudo catalog, the pseudo-locale of the accented English.
+pub const PSEUDO_LOCALE: &str = "en-XA";
+
+/// Pseudo-localize the message.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::pseudo::pseudo_localize;
+/// assert_eq!("[Ĥéļļö, {name}! ~~]", pseudo_localize("Hello, {name}!"));
+/// assert_eq!("[<b>Ýéš</b> ~]", pseudo_localize("<b>Yes</b>"));
+/// ```
+pub fn pseudo_localize(message: &str) -> String {
+    let mut letters: usize = 0;
+    let mut in_tag = false;
+    let accented = Template::new(message).map_text(|text| {
+        text.chars()
+            .map(|c| {
+                match c {
+                    '<' => in_tag = true,
+                    '>' => in_tag = false,
+                    _ if in_tag => {}
+                    c if c.is_alphanumeric() => {
+                        letters += 1;
+                        return accent(c);
+                    }
+                    _ => {}
+                }
+                c
+            })
+            .collect()
+    });
+    let expansion = (letters * 3).div_ceil(10);
+    if expansion == 0 {
+        format!("[{}]", accented)
+    } else {
+        format!("[{} {}]", accented, "~".repeat(expansion))
+    }
+}
+
+/// [Holder] of `locale` with the pseudo-localized messages of `holder`, keys are the same.
+///
+/// # Examples
+///
+/// ```
+/// use sorrow_i18n::pseudo::{pseudo_holder, PSEUDO_LOCALE};
+/// use sorrow_i18n::{GetData, Holder, InternationalCore};
+/// let en = Holder::from_pairs("EN", [("data.name", "Test")]);
+/// let core = InternationalCore::from_holders([pseudo_holder(&en, PSEUDO_LOCALE)]).unwrap();
+/// assert_eq!(Some("[Ţéšţ ~~]".to_string()), core.get_by_locale("en-XA").unwrap().get("data.name"));
+/// ```
+pub fn pseudo_holder(holder: &Holder, locale: &str) -> Holder {
+    let messages = holder.messages().to_map()
+        .into_iter()
+        .map(|(key, value)| (key, pseudo_localize(&value)))
+        .collect::<HashMap<String, String>>();
+    Holder::from_map(locale, messages)
+}
+
+/// Accented variant of the ASCII letter.
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'á', 'b' => 'ƀ', 'c' => 'ç', 'd' => 'ð', 'e' => 'é', 'f' => 'ƒ', 'g' => 'ĝ', 'h' => 'ĥ', 'i' => 'î',
+        'j' => 'ĵ', 'k' => 'ķ', 'l' => 'ļ', 'm' => 'ɱ', 'n' => 'ñ', 'o' => 'ö', 'p' => 'þ', 'q' => 'ǫ', 'r' => 'ŕ',
+        's' => 'š', 't' => 'ţ', 'u' => 'û', 'v' => 'ṽ', 'w' => 'ŵ', 'x' => 'ẋ', 'y' => 'ý', 'z' => 'ž',
+        'A' => 'Á', 'B' => 'Ɓ', 'C' => 'Ç', 'D' => 'Ð', 'E' => 'É', 'F' => 'Ƒ', 'G' => 'Ĝ', 'H' => 'Ĥ', 'I' => 'Î',
+        'J' => 'Ĵ', 'K' => 'Ķ', 'L' => 'Ļ', 'M' => 'Ṁ', 'N' => 'Ñ', 'O' => 'Ö', 'P' => 'Þ', 'Q' => 'Ǫ', 'R' => 'Ŕ',
+        'S' => 'Š', 'T' => 'Ţ', 'U' => 'Û', 'V' => 'Ṽ', 'W' => 'Ŵ', 'X' => 'Ẋ', 'Y' => 'Ý', 'Z' => 'Ž',
+        c => c,
+    }
+}
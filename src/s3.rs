@@ -0,0 +1,153 @@
+//! Provider for locale files stored in S3-compatible storage.
+//! All instances of a horizontally-scaled service share one catalog source,
+//! the file is downloaded periodically and the holder's data is replaced when the object has been changed.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
+use std::thread::sleep;
+use std::time::Duration;
+
+pub use s3::creds::Credentials;
+pub use s3::{Bucket, Region};
+
+use crate::{load_struct_from_str, ChangeListener, Error, ErrorListener, ErrorReporter, Messages, WatchProvider};
+
+/// Loads the locale file (the same structure as local files) from the bucket and refreshes it.
+/// The refresh thread stops when the holder is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sorrow_i18n::InternationalCore;
+/// use sorrow_i18n::s3::{Bucket, Credentials, Region, S3Provider};
+/// let region = Region::Custom { region: "us-east-1".to_string(), endpoint: "http://localhost:9000".to_string() };
+/// let bucket = Bucket::new("locales", region, Credentials::from_env().unwrap()).unwrap().with_path_style();
+/// let mut core = InternationalCore::new("locale/");
+/// core.add_provider("EN", Box::new(S3Provider::new(bucket, "I18N_EN.yaml"))).unwrap();
+/// ```
+pub struct S3Provider {
+    bucket: Box<Bucket>,
+    key: String,
+    interval: Duration,
+    data: Arc<Messages>,
+    listener: Option<ChangeListener>,
+    stopped: Arc<AtomicBool>,
+    errors: ErrorReporter,
+}
+
+impl S3Provider {
+    /// Provider for the object `key` in the bucket. Refresh interval 60 seconds.
+    pub fn new(bucket: Box<Bucket>, key: &str) -> Self {
+        S3Provider {
+            bucket,
+            key: key.to_string(),
+            interval: Duration::from_secs(60),
+            data: Arc::new(Messages::default()),
+            listener: None,
+            stopped: Arc::new(AtomicBool::new(false)),
+            errors: ErrorReporter::default(),
+        }
+    }
+
+    /// Refresh interval.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+/// Downloaded object.
+#[derive(Default)]
+struct Loaded {
+    etag: Option<String>,
+    messages: HashMap<String, String>,
+}
+
+/// Object source, moved to the refresh thread.
+struct Object {
+    bucket: Box<Bucket>,
+    key: String,
+}
+
+impl Object {
+    fn name(&self) -> String {
+        format!("S3Provider({}/{})", self.bucket.name(), &self.key)
+    }
+
+    /// Download and parse the object, if its ETag is not `etag`.
+    fn load(&self, etag: Option<&str>) -> Result<Option<Loaded>, Error> {
+        let error = |cause: String| Error::ProviderError { provider: self.name(), cause };
+        let response = self.bucket.get_object(&self.key).map_err(|e| error(e.to_string()))?;
+        if !(200..300).contains(&response.status_code()) {
+            return Err(error(format!("status code {}", response.status_code())));
+        }
+        let current = response.headers().get("etag").cloned();
+        if current.is_some() && current.as_deref() == etag {
+            return Ok(None);
+        }
+        let data = response.as_str().map_err(|e| error(e.to_string()))?;
+        let holder = load_struct_from_str(data, None)?;
+        let messages = holder.messages.to_map();
+        Ok(Some(Loaded { etag: current, messages }))
+    }
+}
+
+impl WatchProvider for S3Provider {
+    fn watch(&mut self) -> Result<(), Error> {
+        let object = Object { bucket: self.bucket.clone(), key: self.key.clone() };
+        let Loaded { mut etag, messages } = object.load(None)?.unwrap_or_default();
+        self.data.store(messages);
+
+        let holder: Weak<Messages> = Arc::downgrade(&self.data);
+        let interval = self.interval;
+        let listener = self.listener.clone();
+        let stopped = Arc::clone(&self.stopped);
+        let errors = self.errors.clone();
+        std::thread::spawn(move || {
+            loop {
+                sleep(interval);
+                if stopped.load(Ordering::Relaxed) {
+                    break;
+                }
+                let holder = match holder.upgrade() {
+                    None => break,
+                    Some(holder) => holder,
+                };
+                match object.load(etag.as_deref()) {
+                    Ok(Some(loaded)) => {
+                        log::debug!("{} has been changed. Reloading data.", object.name());
+                        etag = loaded.etag;
+                        holder.store(loaded.messages);
+                        if let Some(listener) = &listener {
+                            listener();
+                        }
+                    }
+                    Ok(None) => {}
+                    // The current data is kept.
+                    Err(e) => errors.report(e),
+                }
+            }
+            log::trace!("Refreshing {} is stopped.", object.name());
+        });
+        Ok(())
+    }
+
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
+        self.data = data;
+        Ok(())
+    }
+
+    fn set_listener(&mut self, listener: ChangeListener) {
+        self.listener = Some(listener);
+    }
+
+    fn set_error_listener(&mut self, listener: ErrorListener) {
+        self.errors.set(listener);
+    }
+
+    fn unwatch(&mut self) -> Result<(), Error> {
+        self.stopped.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+//! Search of the messages by key or value, e.g. for the translation debugging tools and admin screens,
+//! so the editor can find the message seen on the screen.
+//!
+//! ```
+//! use sorrow_i18n::InternationalCore;
+//! use sorrow_i18n::search::Query;
+//!
+//! let core = InternationalCore::builder()
+//!     .locale("EN", [("data.save", "Save"), ("data.saved", "Saved!"), ("data.cancel", "Cancel")])
+//!     .build();
+//! let found = core.search(&Query::text("save"));
+//! assert_eq!(vec!["data.save", "data.saved"], found.iter().map(|m| m.key.as_str()).collect::<Vec<&str>>());
+//! ```
+
+use crate::{Holder, InternationalCore};
+
+/// Where the text is searched.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Scope {
+    /// Keys and values.
+    All,
+    /// Keys only.
+    Keys,
+    /// Values only.
+    Values,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    /// Lowercase text.
+    Text(String),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+/// Search query, the text is matched as a case-insensitive substring of the keys and the values.
+#[derive(Debug, Clone)]
+pub struct Query {
+    matcher: Matcher,
+    scope: Scope,
+}
+
+impl Query {
+    /// Case-insensitive substring.
+    pub fn text(text: &str) -> Self {
+        Query { matcher: Matcher::Text(text.to_lowercase()), scope: Scope::All }
+    }
+
+    /// Regular expression, feature `regex`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::Holder;
+    /// use sorrow_i18n::search::{Query, Scope};
+    ///
+    /// let holder = Holder::from_pairs("EN", [("data.total", "Total: {sum}"), ("data.name", "Name")]);
+    /// let query = Query::regex(r"\{\w+\}").unwrap().within(Scope::Values);
+    /// assert_eq!("data.total", holder.search(&query)[0].key);
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<Self, crate::Error> {
+        let regex = regex::Regex::new(pattern).map_err(|e| crate::Error::InvalidPattern { pattern: pattern.to_string(), cause: e })?;
+        Ok(Query { matcher: Matcher::Regex(regex), scope: Scope::All })
+    }
+
+    /// Search only keys or only values.
+    pub fn within(mut self, scope: Scope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Check the key and the value.
+    pub fn matches(&self, key: &str, value: &str) -> bool {
+        match self.scope {
+            Scope::All => self.is_match(key) || self.is_match(value),
+            Scope::Keys => self.is_match(key),
+            Scope::Values => self.is_match(value),
+        }
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        match &self.matcher {
+            Matcher::Text(t) => text.to_lowercase().contains(t.as_str()),
+            #[cfg(feature = "regex")]
+            Matcher::Regex(r) => r.is_match(text),
+        }
+    }
+}
+
+/// Found message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Locale of the holder.
+    pub locale: String,
+    /// Namespace of the holder.
+    pub namespace: Option<String>,
+    /// Key of the message.
+    pub key: String,
+    /// Message.
+    pub value: String,
+}
+
+impl Holder {
+    /// Messages matching the query, sorted by key. See [crate::search].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sorrow_i18n::Holder;
+    /// use sorrow_i18n::search::{Query, Scope};
+    ///
+    /// let holder = Holder::from_pairs("EN", [("data.save", "Save"), ("data.store", "Save to disk")]);
+    /// assert_eq!(2, holder.search(&Query::text("SAVE")).len());
+    /// assert_eq!(1, holder.search(&Query::text("save").within(Scope::Keys)).len());
+    /// ```
+    pub fn search(&self, query: &Query) -> Vec<SearchMatch> {
+        self.load_lazy();
+        let mut found = self.messages.load().iter()
+            .filter(|(k, v)| query.matches(k, v))
+            .map(|(k, v)| SearchMatch { locale: self.locale.clone(), namespace: self.namespace.clone(), key: k.clone(), value: v.to_string() })
+            .collect::<Vec<SearchMatch>>();
+        found.sort_by(|a, b| a.key.cmp(&b.key));
+        found
+    }
+}
+
+impl InternationalCore {
+    /// Messages of all locales and namespaces matching the query, sorted by namespace, locale and key. See [crate::search].
+    pub fn search(&self, query: &Query) -> Vec<SearchMatch> {
+        let namespaced = self.namespaces.values().flat_map(|holders| holders.values());
+        let mut found = self.holders.values().chain(namespaced).flat_map(|h| h.search(query)).collect::<Vec<SearchMatch>>();
+        found.sort_by(|a, b| (&a.namespace, &a.locale, &a.key).cmp(&(&b.namespace, &b.locale, &b.key)));
+        found
+    }
+}
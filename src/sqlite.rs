@@ -0,0 +1,165 @@
+//! Provider for locale data stored in SQLite table `(locale, key, value)`.
+//! The rows of the holder's locale replace the holder's data, the table is polled for changes.
+//!
+//! ```sql
+//! CREATE TABLE i18n (locale TEXT NOT NULL, key TEXT NOT NULL, value TEXT NOT NULL);
+//! INSERT INTO i18n VALUES ('EN', 'data.name', 'Test');
+//! ```
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
+use std::thread::sleep;
+use std::time::Duration;
+
+use rusqlite::{Connection, OpenFlags};
+
+use crate::locale::normalize;
+use crate::{ChangeListener, Error, ErrorListener, ErrorReporter, Messages, WatchProvider};
+
+/// Loads the key/value rows of the locale from SQLite table and polls for changes.
+/// The polling thread stops when the holder is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sorrow_i18n::InternationalCore;
+/// use sorrow_i18n::sqlite::SqliteProvider;
+/// use std::time::Duration;
+/// let mut core = InternationalCore::new("locale/");
+/// let provider = SqliteProvider::new("app.db", "EN").table("translations").interval(Duration::from_secs(1));
+/// core.add_provider("EN", Box::new(provider)).unwrap();
+/// ```
+pub struct SqliteProvider {
+    path: String,
+    table: String,
+    locale: String,
+    interval: Duration,
+    data: Arc<Messages>,
+    listener: Option<ChangeListener>,
+    stopped: Arc<AtomicBool>,
+    errors: ErrorReporter,
+}
+
+impl SqliteProvider {
+    /// Provider for the database file `path` and locale. Table `i18n`, polling interval 5 seconds.
+    pub fn new(path: &str, locale: &str) -> Self {
+        SqliteProvider {
+            path: path.to_string(),
+            table: "i18n".to_string(),
+            locale: normalize(locale),
+            interval: Duration::from_secs(5),
+            data: Arc::new(Messages::default()),
+            listener: None,
+            stopped: Arc::new(AtomicBool::new(false)),
+            errors: ErrorReporter::default(),
+        }
+    }
+
+    /// Table with columns `locale`, `key` and `value`.
+    pub fn table(mut self, table: &str) -> Self {
+        self.table = table.to_string();
+        self
+    }
+
+    /// Polling interval.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    fn error(&self, e: rusqlite::Error) -> Error {
+        log::error!("Error while loading locale {} from {}. Message: {}", &self.locale, &self.path, e);
+        Error::ProviderError { provider: format!("SqliteProvider({})", &self.path), cause: e.to_string() }
+    }
+}
+
+/// Rows of the table by locale. Locales in the table are normalized before comparing.
+fn load(connection: &Connection, table: &str, locale: &str) -> Result<HashMap<String, String>, rusqlite::Error> {
+    let query = format!("SELECT locale, key, value FROM \"{}\"", table.replace('"', "\"\""));
+    let mut statement = connection.prepare(&query)?;
+    let rows = statement.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?)))?;
+    let mut messages = HashMap::new();
+    for row in rows {
+        let (row_locale, key, value) = row?;
+        if normalize(&row_locale) == locale {
+            messages.insert(key, value);
+        }
+    }
+    Ok(messages)
+}
+
+/// Version of the database, changed on commit by other connections.
+fn data_version(connection: &Connection) -> Result<i64, rusqlite::Error> {
+    connection.query_row("PRAGMA data_version", [], |row| row.get(0))
+}
+
+/// Rows of the table by locale, if the database has been changed since `version`.
+fn poll(connection: &Connection, version: &mut i64, table: &str, locale: &str) -> Result<Option<HashMap<String, String>>, rusqlite::Error> {
+    let current = data_version(connection)?;
+    if current == *version {
+        return Ok(None);
+    }
+    let messages = load(connection, table, locale)?;
+    *version = current;
+    Ok(Some(messages))
+}
+
+impl WatchProvider for SqliteProvider {
+    fn watch(&mut self) -> Result<(), Error> {
+        let connection = Connection::open_with_flags(&self.path, OpenFlags::SQLITE_OPEN_READ_ONLY).map_err(|e| self.error(e))?;
+        let mut version = data_version(&connection).map_err(|e| self.error(e))?;
+        self.data.store(load(&connection, &self.table, &self.locale).map_err(|e| self.error(e))?);
+
+        let holder: Weak<Messages> = Arc::downgrade(&self.data);
+        let (path, table, locale, interval) = (self.path.clone(), self.table.clone(), self.locale.clone(), self.interval);
+        let listener = self.listener.clone();
+        let stopped = Arc::clone(&self.stopped);
+        let errors = self.errors.clone();
+        std::thread::spawn(move || {
+            loop {
+                sleep(interval);
+                if stopped.load(Ordering::Relaxed) {
+                    break;
+                }
+                let holder = match holder.upgrade() {
+                    None => break,
+                    Some(holder) => holder,
+                };
+                match poll(&connection, &mut version, &table, &locale) {
+                    Ok(Some(messages)) => {
+                        log::debug!("Table {} in {} has been changed. Reloading data.", &table, &path);
+                        holder.store(messages);
+                        if let Some(listener) = &listener {
+                            listener();
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        errors.report(Error::ProviderError { provider: format!("SqliteProvider({})", &path), cause: e.to_string() });
+                    }
+                }
+            }
+            log::trace!("Polling {} for {} is stopped.", &path, &locale);
+        });
+        Ok(())
+    }
+
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
+        self.data = data;
+        Ok(())
+    }
+
+    fn set_listener(&mut self, listener: ChangeListener) {
+        self.listener = Some(listener);
+    }
+
+    fn set_error_listener(&mut self, listener: ErrorListener) {
+        self.errors.set(listener);
+    }
+
+    fn unwatch(&mut self) -> Result<(), Error> {
+        self.stopped.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+}
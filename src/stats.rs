@@ -0,0 +1,78 @@
+//! Statistics of the reloads, so health endpoints can report whether the hot reload works, see [crate::InternationalCore::stats].
+//!
+//! ```no_run
+//! use sorrow_i18n::InternationalCore;
+//! let core = InternationalCore::new("locale/");
+//! for stats in core.stats() {
+//!     if let Some(error) = &stats.reload.last_error {
+//!         eprintln!("Locale {} is not reloaded: {}", stats.locale, error);
+//!     }
+//! }
+//! ```
+
+use std::time::{Duration, SystemTime};
+
+/// Statistics of the loading and the reloads of the data, see [crate::Messages::stats].
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct ReloadStats {
+    /// Time of the last successful load or reload of the file.
+    pub last_reload: Option<SystemTime>,
+    /// Count of the successful reloads, the first load is not counted.
+    pub reloads: u64,
+    /// Count of the failed reloads.
+    pub errors: u64,
+    /// Error of the last reload, `None` if the last reload has succeeded.
+    pub last_error: Option<String>,
+    /// Duration of reading and parsing of the file by the last successful load or reload.
+    pub parse_duration: Option<Duration>,
+}
+
+impl ReloadStats {
+    /// The file has been loaded for the first time.
+    pub(crate) fn loaded(&mut self, duration: Duration) {
+        self.last_reload = Some(SystemTime::now());
+        self.parse_duration = Some(duration);
+    }
+
+    /// The file has been reloaded.
+    pub(crate) fn reloaded(&mut self, duration: Duration) {
+        self.loaded(duration);
+        self.reloads += 1;
+        self.last_error = None;
+    }
+
+    /// The reload of the file has failed, the data is not changed.
+    pub(crate) fn failed(&mut self, error: String) {
+        self.errors += 1;
+        self.last_error = Some(error);
+    }
+
+    /// Statistics of the holder merged from the layers (see [crate::composite::CompositeProvider]): the counts are summed,
+    /// the last reload is the latest one, the error is the error of the last layer with error.
+    pub(crate) fn merged<I: IntoIterator<Item = ReloadStats>>(layers: I) -> ReloadStats {
+        layers.into_iter().fold(ReloadStats::default(), |merged, layer| ReloadStats {
+            last_reload: merged.last_reload.max(layer.last_reload),
+            reloads: merged.reloads + layer.reloads,
+            errors: merged.errors + layer.errors,
+            last_error: layer.last_error.or(merged.last_error),
+            parse_duration: match (merged.parse_duration, layer.parse_duration) {
+                (Some(a), Some(b)) => Some(a + b),
+                (a, b) => a.or(b),
+            },
+        })
+    }
+}
+
+/// Statistics of the holder of the core.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct HolderStats {
+    /// Locale of the holder
+    pub locale: String,
+    /// Namespace of the holder, see [crate::InternationalCore::namespace]
+    pub namespace: Option<String>,
+    /// Source file of the holder, `None` for the holders without file
+    pub path: Option<String>,
+    /// Statistics of the reloads
+    #[serde(flatten)]
+    pub reload: ReloadStats,
+}
@@ -0,0 +1,78 @@
+//! Tauri plugin. Desktop frontends consume the same catalogs as the Rust side:
+//! the plugin exposes commands to read the messages and emits `i18n://reload` event (see [ReloadEvent]) when the data has been changed.
+//!
+//! ```ignore
+//! use sorrow_i18n::InternationalCore;
+//! let core = InternationalCore::new("locale/");
+//! tauri::Builder::default()
+//!     .plugin(sorrow_i18n::tauri::init(core))
+//!     .run(tauri::generate_context!())
+//!     .expect("error while running tauri application");
+//! ```
+//!
+//! Frontend:
+//!
+//! ```js
+//! import { invoke } from "@tauri-apps/api/core";
+//! import { listen } from "@tauri-apps/api/event";
+//! const name = await invoke("plugin:i18n|i18n_get", { locale: "en", key: "data.name" });
+//! await listen("i18n://reload", (event) => console.log(event.payload.locale, event.payload.keys));
+//! ```
+
+use std::sync::{Arc, OnceLock};
+
+use tauri::plugin::{Builder, TauriPlugin};
+use tauri::{AppHandle, Emitter, Manager, Runtime, State};
+
+use crate::events::ReloadEvent;
+use crate::{GetData, InternationalCore};
+
+/// Event emitted to the frontend when the data has been changed.
+pub const RELOAD_EVENT: &str = "i18n://reload";
+
+/// Core managed by the plugin, handlers of the application can use it as `State<I18n>`.
+pub struct I18n(pub Arc<InternationalCore>);
+
+/// Message by locale and key. If the locale or the key is not found, return `null`.
+#[tauri::command]
+fn i18n_get(state: State<'_, I18n>, locale: String, key: String) -> Option<String> {
+    state.0.get_by_locale(&locale)?.get(&key)
+}
+
+/// Message by locale and key, or the key itself.
+#[tauri::command]
+fn i18n_get_or_default(state: State<'_, I18n>, locale: String, key: String) -> String {
+    match state.0.get_by_locale(&locale) {
+        None => key,
+        Some(data) => data.get_or_default(&key),
+    }
+}
+
+/// Loaded locales, see [InternationalCore::locales].
+#[tauri::command]
+fn i18n_locales(state: State<'_, I18n>) -> Vec<String> {
+    state.0.locales()
+}
+
+/// Plugin `i18n` with commands `i18n_get`, `i18n_get_or_default` and `i18n_locales`.
+/// The commands must be allowed by the capabilities of the application.
+pub fn init<R: Runtime>(mut core: InternationalCore) -> TauriPlugin<R> {
+    let handle: Arc<OnceLock<AppHandle<R>>> = Arc::new(OnceLock::new());
+    let emitter = Arc::clone(&handle);
+    core.on_reload(move |event: &ReloadEvent| {
+        if let Some(app) = emitter.get() {
+            if let Err(e) = app.emit(RELOAD_EVENT, event.clone()) {
+                log::error!("Error while emitting reload event of {}. Message: {}", &event.locale, e);
+            }
+        }
+    });
+    let core = Arc::new(core);
+    Builder::new("i18n")
+        .invoke_handler(tauri::generate_handler![i18n_get, i18n_get_or_default, i18n_locales])
+        .setup(move |app, _api| {
+            let _ = handle.set(app.clone());
+            app.manage(I18n(Arc::clone(&core)));
+            Ok(())
+        })
+        .build()
+}
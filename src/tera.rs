@@ -0,0 +1,71 @@
+//! Function `t` for Tera templates, bound to the core by [register]. The message is formatted by the rest of the arguments
+//! (see [GetData::get_with_args]), the locale is the argument `locale` or the current locale (see [crate::locale::LocaleGuard]).
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use sorrow_i18n::InternationalCore;
+//!
+//! let mut tera = tera::Tera::default();
+//! sorrow_i18n::tera::register(&mut tera, Arc::new(InternationalCore::new("locale/")));
+//! tera.add_raw_template("hello", r#"<h1>{{ t(key="data.greeting", name=user, locale=locale) }}</h1>"#).unwrap();
+//!
+//! let mut context = tera::Context::new();
+//! context.insert("user", "Bob");
+//! context.insert("locale", "en");
+//! let html = tera.render("hello", &context).unwrap();
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use tera::{Function, Tera, Value};
+
+use crate::{GetData, InternationalCore};
+
+/// Function `t`, see [register].
+pub struct Translate {
+    core: Arc<InternationalCore>,
+}
+
+impl Translate {
+    /// Function bound to the core.
+    pub fn new(core: Arc<InternationalCore>) -> Self {
+        Translate { core }
+    }
+}
+
+impl Function for Translate {
+    fn call(&self, args: &HashMap<String, Value>) -> tera::Result<Value> {
+        let key = args.get("key")
+            .and_then(Value::as_str)
+            .ok_or_else(|| tera::Error::msg("Function `t` requires the string argument `key`."))?;
+        let locale = args.get("locale")
+            .and_then(Value::as_str)
+            .map(|l| l.to_string())
+            .unwrap_or_else(|| self.core.current_locale());
+        let values = args.iter()
+            .filter(|(name, _)| name.as_str() != "key" && name.as_str() != "locale")
+            .map(|(name, value)| (name.as_str(), text(value)))
+            .collect::<Vec<(&str, String)>>();
+        let values = values.iter().map(|(name, value)| (*name, value as &dyn Display)).collect::<Vec<_>>();
+        let message = match self.core.get_by_locale(&locale) {
+            None => key.to_string(),
+            Some(data) => data.get_with_args(key, &values),
+        };
+        Ok(Value::String(message))
+    }
+}
+
+/// Register the function `t` in Tera: `{{ t(key="data.name") }}`, `{{ t(key="data.greeting", name=user, locale="ru") }}`.
+pub fn register(tera: &mut Tera, core: Arc<InternationalCore>) {
+    tera.register_function("t", Translate::new(core));
+}
+
+/// Strings without quotes, other values as JSON.
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
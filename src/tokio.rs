@@ -0,0 +1,247 @@
+//! Integration with tokio, feature `tokio`. The files are watched by the tasks of the runtime
+//! instead of the threads of the file watcher, and reloaded by the async file IO,
+//! so server applications keep everything on one runtime.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use sorrow_i18n::InternationalCore;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let mut core = InternationalCore::new("locale/");
+//!     core.watch_on_runtime(tokio::runtime::Handle::current(), Duration::from_secs(1)).unwrap();
+//!     // On the reload signal
+//!     core.reload_async().await.unwrap();
+//! }
+//! ```
+
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant, SystemTime};
+
+use ::tokio::runtime::Handle;
+use ::tokio::task::JoinHandle;
+use ::tokio::time::MissedTickBehavior;
+
+use crate::{load_struct_from_str, store_reloaded, ChangeListener, Error, ErrorListener, ErrorReporter, InternationalCore, Messages, Providers, WatchProvider};
+
+/// Watches the file by polling its modification time on the tokio runtime, the changed file is read by [tokio::fs].
+/// The task stops when the provider is unwatched or dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use sorrow_i18n::InternationalCore;
+/// use sorrow_i18n::tokio::AsyncFileProvider;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut core = InternationalCore::new("locale/");
+///     let provider = AsyncFileProvider::from_path("locale/I18N_EN.yaml").with_interval(Duration::from_millis(500));
+///     core.add_provider("EN", Box::new(provider)).unwrap();
+/// }
+/// ```
+pub struct AsyncFileProvider {
+    path: String,
+    interval: Duration,
+    handle: Option<Handle>,
+    messages: Arc<Messages>,
+    listener: Option<ChangeListener>,
+    errors: ErrorReporter,
+    task: Option<JoinHandle<()>>,
+}
+
+impl AsyncFileProvider {
+    /// Provider for the file, polling interval 1 second. The task is spawned on the current runtime, see [AsyncFileProvider::with_handle].
+    pub fn from_path(path: &str) -> Self {
+        AsyncFileProvider {
+            path: path.to_string(),
+            interval: Duration::from_secs(1),
+            handle: None,
+            messages: Arc::new(Messages::default()),
+            listener: None,
+            errors: ErrorReporter::default(),
+            task: None,
+        }
+    }
+
+    /// Polling interval.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Runtime of the task, if the provider is watched outside of the runtime.
+    pub fn with_handle(mut self, handle: Handle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    fn error(&self, cause: String) -> Error {
+        Error::ProviderError { provider: format!("AsyncFileProvider({})", &self.path), cause }
+    }
+}
+
+impl WatchProvider for AsyncFileProvider {
+    fn watch(&mut self) -> Result<(), Error> {
+        let handle = match self.handle.clone() {
+            Some(handle) => handle,
+            None => Handle::try_current().map_err(|e| self.error(e.to_string()))?,
+        };
+        let modified = modified(&self.path)?;
+        let task = Task {
+            path: self.path.clone(),
+            interval: self.interval,
+            messages: Arc::downgrade(&self.messages),
+            listener: self.listener.clone(),
+            errors: self.errors.clone(),
+        };
+        if let Some(previous) = self.task.replace(handle.spawn(task.run(modified))) {
+            previous.abort();
+        }
+        Ok(())
+    }
+
+    fn set_data(&mut self, data: Arc<Messages>) -> Result<(), Error> {
+        self.messages = data;
+        Ok(())
+    }
+
+    fn set_listener(&mut self, listener: ChangeListener) {
+        self.listener = Some(listener);
+    }
+
+    fn set_error_listener(&mut self, listener: ErrorListener) {
+        self.errors.set(listener);
+    }
+
+    fn unwatch(&mut self) -> Result<(), Error> {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+        Ok(())
+    }
+}
+
+impl Drop for AsyncFileProvider {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Provider settings moved to the task.
+struct Task {
+    path: String,
+    interval: Duration,
+    messages: Weak<Messages>,
+    listener: Option<ChangeListener>,
+    errors: ErrorReporter,
+}
+
+impl Task {
+    /// Poll the modification time while the holder exists.
+    async fn run(self, mut last: SystemTime) {
+        let mut ticker = ::tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        // The first tick is immediate.
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let messages = match self.messages.upgrade() {
+                None => break,
+                Some(messages) => messages,
+            };
+            let modified = match ::tokio::fs::metadata(&self.path).await.and_then(|m| m.modified()) {
+                Ok(modified) => modified,
+                Err(e) => {
+                    self.errors.report(Error::IoError { path: self.path.clone(), cause: e });
+                    continue;
+                }
+            };
+            if modified == last {
+                continue;
+            }
+            last = modified;
+            log::debug!("Modify {}. Reloading data.", &self.path);
+            match reload(&messages, &self.path).await {
+                Ok(()) => {
+                    if let Some(listener) = &self.listener {
+                        listener();
+                    }
+                }
+                Err(e) => self.errors.report(e),
+            }
+        }
+    }
+}
+
+fn modified(path: &str) -> Result<SystemTime, Error> {
+    std::fs::metadata(path).and_then(|m| m.modified()).map_err(|e| Error::IoError { path: path.to_string(), cause: e })
+}
+
+/// Read the file by the async file IO and replace `messages` by the file data, like the reload of [crate::Holder::reload].
+async fn reload(messages: &Messages, path: &str) -> Result<(), Error> {
+    let start = Instant::now();
+    let data = ::tokio::fs::read_to_string(path).await.map_err(|e| Error::IoError { path: path.to_string(), cause: e });
+    store(messages, path, data, start)
+}
+
+/// Parse and validate the read data, the span is not held across the awaits.
+fn store(messages: &Messages, path: &str, data: Result<String, Error>, start: Instant) -> Result<(), Error> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::info_span!("i18n.reload", path).entered();
+    let loaded = data.and_then(|data| load_struct_from_str(&data, Some(path.to_string())));
+    if let Ok(holder) = &loaded {
+        holder.messages.record(|stats| stats.loaded(start.elapsed()));
+    }
+    store_reloaded(messages, path, loaded)
+}
+
+impl InternationalCore {
+    /// Watch the files with `provider: FileProvider` by [AsyncFileProvider] on the runtime of `handle`, the previous watchers are stopped.
+    /// Lazily loaded holders (see [InternationalCore::lazy]) keep their providers.
+    pub fn watch_on_runtime(&mut self, handle: Handle, interval: Duration) -> Result<(), Error> {
+        let namespaced = self.namespaces.values().flat_map(|holders| holders.values());
+        for holder in self.holders.values().chain(namespaced) {
+            let path = match (&holder.path, &holder.provider_kind, &holder.lazy) {
+                (Some(path), Some(Providers::FileProvider), None) => path,
+                _ => continue,
+            };
+            let mut provider = AsyncFileProvider::from_path(path).with_interval(interval).with_handle(handle.clone());
+            provider.set_listener(holder.changes.as_listener());
+            provider.set_data(Arc::clone(&holder.messages))?;
+            provider.watch()?;
+            let mut current = holder.provider.lock().unwrap();
+            if let Err(e) = current.unwatch() {
+                log::error!("Error while stopping the replaced provider of {}. Message: {}", &holder.locale, e);
+            }
+            *current = Box::new(provider);
+        }
+        // Error listeners of the new providers.
+        self.set_listeners();
+        Ok(())
+    }
+
+    /// Analogue [InternationalCore::reload], the files are read by the async file IO.
+    /// All holders are reloaded, the first error is returned. The holder with error keeps the previous data.
+    pub async fn reload_async(&self) -> Result<(), Error> {
+        let mut result = Ok(());
+        let namespaced = self.namespaces.values().flat_map(|holders| holders.values());
+        for holder in self.holders.values().chain(namespaced) {
+            let path = match &holder.path {
+                None => continue,
+                Some(path) => path,
+            };
+            let reloaded = reload(&holder.messages, path).await.map(|_| holder.changes.notify());
+            if let Err(e) = self.reloaded(holder, reloaded) {
+                log::error!("Error while reloading {} locale. {}", &holder.locale, &e);
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+}
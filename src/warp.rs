@@ -0,0 +1,52 @@
+//! Integration with warp. Filters negotiate the locale of the request from `Accept-Language` against the loaded locales
+//! and pass [Data] of the locale to the handlers.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use warp::Filter;
+//! use sorrow_i18n::{Data, GetData, InternationalCore};
+//!
+//! let core = Arc::new(InternationalCore::new("locale/"));
+//! let welcome = warp::path("welcome")
+//!     .and(sorrow_i18n::warp::localized(core))
+//!     .map(|msgs: Data| msgs.get_or_default("welcome"));
+//! ```
+
+use std::sync::Arc;
+
+use warp::reject::Reject;
+use warp::{Filter, Rejection};
+
+use crate::{Data, InternationalCore};
+
+/// Locale of the request, see [locale].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale(pub String);
+
+/// Rejection of [localized], the locale of the request is not loaded.
+#[derive(Debug)]
+pub struct LocaleNotFound {
+    /// Locale of the request
+    pub locale: String,
+}
+
+impl Reject for LocaleNotFound {}
+
+/// Filter of the locale, negotiated from `Accept-Language` (see [InternationalCore::negotiate]).
+/// If no locale of the header is loaded, the current locale (see [InternationalCore::current_locale]) is used.
+pub fn locale(core: Arc<InternationalCore>) -> impl Filter<Extract = (Locale,), Error = Rejection> + Clone {
+    warp::header::optional::<String>("accept-language").map(move |header: Option<String>| {
+        let locale = header
+            .and_then(|h| core.negotiate(&h))
+            .unwrap_or_else(|| core.current_locale());
+        Locale(locale)
+    })
+}
+
+/// Filter of [Data] by the locale of the request, see [locale]. If the locale is not loaded, the request is rejected with [LocaleNotFound].
+pub fn localized(core: Arc<InternationalCore>) -> impl Filter<Extract = (Data,), Error = Rejection> + Clone {
+    locale(Arc::clone(&core)).and_then(move |Locale(locale): Locale| {
+        let data = core.get_by_locale(&locale).ok_or_else(|| warp::reject::custom(LocaleNotFound { locale }));
+        async move { data }
+    })
+}
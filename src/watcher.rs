@@ -0,0 +1,175 @@
+//! One watcher of the folders shared by the file providers of the core. Every provider used to start own OS watcher,
+//! so many locales hit the limits of the system (e.g. `max_user_instances` of inotify).
+//! The folder is watched once, and the events are dispatched to the providers by the path of the file.
+//!
+//! ```no_run
+//! use sorrow_i18n::InternationalCore;
+//! let core = InternationalCore::new("locale/");
+//! // All files of the folder are watched by one watcher.
+//! assert_eq!(1, core.folder_watcher().folders().len());
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex, RwLock};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::{watch_error, Error, ErrorReporter};
+
+/// Providers by the path of the watched file.
+type Targets = Arc<RwLock<HashMap<PathBuf, Vec<Target>>>>;
+
+/// Provider of the file, receives the events of the file and the errors of the watcher.
+struct Target {
+    id: usize,
+    events: Sender<()>,
+    errors: ErrorReporter,
+}
+
+#[derive(Default)]
+struct Shared {
+    /// Created on the first watched folder.
+    watcher: Mutex<Option<RecommendedWatcher>>,
+    /// Watched folders with the count of the watched files.
+    folders: Mutex<HashMap<PathBuf, usize>>,
+    targets: Targets,
+    next: AtomicUsize,
+}
+
+/// Watcher of the folders, shared by the [crate::FileProvider]s of the core, see [crate::InternationalCore::folder_watcher].
+/// The clones share the same OS watcher. The folder is unwatched when the last file of the folder is unwatched.
+#[derive(Clone, Default)]
+pub struct FolderWatcher {
+    shared: Arc<Shared>,
+}
+
+impl FolderWatcher {
+    /// Watched folders, sorted.
+    pub fn folders(&self) -> Vec<PathBuf> {
+        let mut folders = self.shared.folders.lock().unwrap().keys().cloned().collect::<Vec<PathBuf>>();
+        folders.sort();
+        folders
+    }
+
+    /// Send the events of the file `path` (modify, create, remove and rename, e.g. atomic save of the editors) to `events`,
+    /// the errors of the watcher are reported to `errors`. The file is watched until the registration is dropped.
+    pub(crate) fn register(&self, path: &str, events: Sender<()>, errors: ErrorReporter) -> Result<Registration, Error> {
+        let folder = watch_folder(path).canonicalize().map_err(|e| Error::IoError { path: path.to_string(), cause: e })?;
+        let file = match Path::new(path).file_name() {
+            None => return Err(Error::IoError { path: path.to_string(), cause: std::io::Error::from(std::io::ErrorKind::NotFound) }),
+            Some(name) => folder.join(name),
+        };
+        let id = self.shared.next.fetch_add(1, Ordering::Relaxed);
+        self.shared.targets.write().unwrap().entry(file.clone()).or_default().push(Target { id, events, errors });
+        // The target is removed on error by the drop of the registration.
+        let mut registration = Registration { watcher: self.clone(), folder: None, file, id };
+        registration.folder = self.watch(&folder, path)?;
+        Ok(registration)
+    }
+
+    /// Watch the folder once, return the folder if it is watched by the registration.
+    fn watch(&self, folder: &Path, path: &str) -> Result<Option<PathBuf>, Error> {
+        let mut folders = self.shared.folders.lock().unwrap();
+        if let Some(count) = folders.get_mut(folder) {
+            *count += 1;
+            return Ok(Some(folder.to_path_buf()));
+        }
+        let mut watcher = self.shared.watcher.lock().unwrap();
+        if watcher.is_none() {
+            match notify::recommended_watcher(dispatcher(Arc::clone(&self.shared.targets))) {
+                Ok(w) => *watcher = Some(w),
+                Err(e) => return watch_error(path, e).map(|_| None),
+            }
+        }
+        match watcher.as_mut().map(|w| w.watch(folder, RecursiveMode::NonRecursive)) {
+            Some(Err(e)) => watch_error(path, e).map(|_| None),
+            _ => {
+                log::debug!("Watching folder {}.", folder.display());
+                folders.insert(folder.to_path_buf(), 1);
+                Ok(Some(folder.to_path_buf()))
+            }
+        }
+    }
+
+    fn unregister(&self, registration: &Registration) {
+        let mut targets = self.shared.targets.write().unwrap();
+        if let Some(files) = targets.get_mut(&registration.file) {
+            files.retain(|target| target.id != registration.id);
+            if files.is_empty() {
+                targets.remove(&registration.file);
+            }
+        }
+        // The watcher may wait for the dispatching of the events.
+        drop(targets);
+        let folder = match &registration.folder {
+            None => return,
+            Some(folder) => folder,
+        };
+        let mut folders = self.shared.folders.lock().unwrap();
+        if let Some(count) = folders.get_mut(folder) {
+            *count -= 1;
+            if *count == 0 {
+                folders.remove(folder);
+                if let Some(Err(e)) = self.shared.watcher.lock().unwrap().as_mut().map(|w| w.unwatch(folder)) {
+                    log::debug!("Error while stopping watching of folder {}. Message: {}", folder.display(), e);
+                }
+            }
+        }
+    }
+}
+
+/// File watched by [FolderWatcher], the file is unwatched on drop.
+pub(crate) struct Registration {
+    watcher: FolderWatcher,
+    /// `None` if the folder is not watched, see [crate::watch_error].
+    folder: Option<PathBuf>,
+    file: PathBuf,
+    id: usize,
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.watcher.unregister(self);
+    }
+}
+
+/// Handler of the OS watcher, the events are sent to the providers of the file. The errors with the paths are reported
+/// to the providers of these files, other errors to all providers.
+fn dispatcher(targets: Targets) -> impl Fn(Result<notify::Event, notify::Error>) + Send + 'static {
+    move |result: Result<notify::Event, notify::Error>| {
+        let targets = targets.read().unwrap();
+        match result {
+            Ok(event) => {
+                // Editors with the atomic save write the temporary file and rename it to the target.
+                if !event.kind.is_modify() && !event.kind.is_create() && !event.kind.is_remove() {
+                    return;
+                }
+                #[cfg(feature = "tracing")]
+                tracing::trace!(kind = ?event.kind, paths = ?event.paths, "watch event");
+                for target in event.paths.iter().filter_map(|path| targets.get(path)).flatten() {
+                    let _ = target.events.send(());
+                }
+            }
+            Err(e) => {
+                let message = e.to_string();
+                for (path, files) in targets.iter().filter(|(path, _)| e.paths.is_empty() || e.paths.contains(path)) {
+                    for target in files {
+                        let path = path.display().to_string();
+                        target.errors.report(Error::WatchError { path, cause: notify::Error::generic(&message) });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parent folder of the file `path`, the current folder for the file name only.
+fn watch_folder(path: &str) -> &Path {
+    match Path::new(path).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    }
+}
@@ -0,0 +1,124 @@
+//! Integration with Yew. [I18nProvider] shares the core and the active locale with the components,
+//! [use_i18n] returns the context, components are re-rendered when the active locale is switched.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use yew::prelude::*;
+//! use sorrow_i18n::yew::{use_i18n, I18nProvider};
+//! use sorrow_i18n::InternationalCore;
+//!
+//! #[function_component]
+//! fn Greeting() -> Html {
+//!     let i18n = use_i18n();
+//!     let switch = { let i18n = i18n.clone(); Callback::from(move |_| i18n.set_locale("ru")) };
+//!     html! { <button onclick={switch}>{ i18n.t("data.name") }</button> }
+//! }
+//!
+//! #[function_component]
+//! fn App() -> Html {
+//!     let core = use_memo((), |_| Arc::new(InternationalCore::builder().insert("EN", "data.name", "Test").build()));
+//!     html! {
+//!         <I18nProvider core={Arc::clone(&core)} locale="en">
+//!             <Greeting />
+//!         </I18nProvider>
+//!     }
+//! }
+//! ```
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use yew::prelude::*;
+
+use crate::{Data, GetData, InternationalCore};
+
+/// Context of [I18nProvider].
+#[derive(Clone)]
+pub struct I18nContext {
+    core: Arc<InternationalCore>,
+    locale: Rc<String>,
+    set_locale: Callback<String>,
+}
+
+impl PartialEq for I18nContext {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.core, &other.core) && self.locale == other.locale
+    }
+}
+
+impl I18nContext {
+    /// The core.
+    pub fn core(&self) -> &InternationalCore {
+        &self.core
+    }
+
+    /// Active locale.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Switch the active locale, the components using the context are re-rendered.
+    pub fn set_locale(&self, locale: &str) {
+        self.set_locale.emit(locale.to_string())
+    }
+
+    /// [Data] by the active locale.
+    pub fn data(&self) -> Option<Data> {
+        self.core.get_by_locale(&self.locale)
+    }
+
+    /// Message by key in the active locale, or the key itself.
+    pub fn t(&self, key: &str) -> String {
+        match self.data() {
+            None => key.to_string(),
+            Some(data) => data.get_or_default(key),
+        }
+    }
+}
+
+/// Properties of [I18nProvider].
+#[derive(Properties)]
+pub struct I18nProviderProps {
+    /// The core
+    pub core: Arc<InternationalCore>,
+    /// Initial active locale
+    pub locale: AttrValue,
+    /// Components using [use_i18n]
+    #[prop_or_default]
+    pub children: Html,
+}
+
+impl PartialEq for I18nProviderProps {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.core, &other.core) && self.locale == other.locale && self.children == other.children
+    }
+}
+
+/// Provider of [I18nContext] for the children.
+#[function_component]
+pub fn I18nProvider(props: &I18nProviderProps) -> Html {
+    let locale = use_state(|| Rc::new(props.locale.to_string()));
+    let context = I18nContext {
+        core: Arc::clone(&props.core),
+        locale: Rc::clone(&locale),
+        set_locale: {
+            let locale = locale.clone();
+            Callback::from(move |l: String| locale.set(Rc::new(l)))
+        },
+    };
+    html! {
+        <ContextProvider<I18nContext> {context}>
+            { props.children.clone() }
+        </ContextProvider<I18nContext>>
+    }
+}
+
+/// Context of the closest [I18nProvider].
+///
+/// # Panics
+///
+/// If the component is not a child of [I18nProvider].
+#[hook]
+pub fn use_i18n() -> I18nContext {
+    use_context::<I18nContext>().expect("use_i18n must be used inside I18nProvider.")
+}